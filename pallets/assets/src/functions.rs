@@ -528,6 +528,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			return Ok((amount, None))
 		}
 
+		// Check the destination is allowed to receive the asset.
+		ensure!(
+			source == dest || T::Compliance::can_receive(id, dest),
+			Error::<T, I>::NotCompliant
+		);
+
 		// Figure out the debit and credit, together with side-effects.
 		let debit = Self::prep_debit(id, source, amount, f.into())?;
 		let (credit, maybe_burn) = Self::prep_credit(id, dest, amount, debit, f.burn_dust)?;
@@ -236,6 +236,10 @@ pub mod pallet {
 		/// respected in all permissionless operations.
 		type Freezer: FrozenBalance<Self::AssetId, Self::AccountId, Self::Balance>;
 
+		/// A hook to allow a per-asset restriction of the accounts that can receive a transfer.
+		/// This is enforced in all transfers, including forced and approved transfers.
+		type Compliance: TransferCompliance<Self::AssetId, Self::AccountId>;
+
 		/// Additional data to be stored with an account's asset balance.
 		type Extra: Member + Parameter + Default + MaxEncodedLen;
 
@@ -473,6 +477,8 @@ pub mod pallet {
 		NoDeposit,
 		/// The operation would result in funds being burned.
 		WouldBurn,
+		/// The destination account is not allowed to receive the asset.
+		NotCompliant,
 	}
 
 	#[pallet::call]
@@ -679,7 +685,9 @@ pub mod pallet {
 		/// Weight: `O(1)`
 		/// Modes: Pre-existence of `target`; Post-existence of sender; Account pre-existence of
 		/// `target`.
-		#[pallet::weight(T::WeightInfo::transfer())]
+		#[pallet::weight(
+			T::WeightInfo::transfer().saturating_add(T::Compliance::can_receive_weight())
+		)]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			#[pallet::compact] id: T::AssetId,
@@ -711,7 +719,9 @@ pub mod pallet {
 		/// Weight: `O(1)`
 		/// Modes: Pre-existence of `target`; Post-existence of sender; Account pre-existence of
 		/// `target`.
-		#[pallet::weight(T::WeightInfo::transfer_keep_alive())]
+		#[pallet::weight(
+			T::WeightInfo::transfer_keep_alive().saturating_add(T::Compliance::can_receive_weight())
+		)]
 		pub fn transfer_keep_alive(
 			origin: OriginFor<T>,
 			#[pallet::compact] id: T::AssetId,
@@ -744,7 +754,9 @@ pub mod pallet {
 		/// Weight: `O(1)`
 		/// Modes: Pre-existence of `dest`; Post-existence of `source`; Account pre-existence of
 		/// `dest`.
-		#[pallet::weight(T::WeightInfo::force_transfer())]
+		#[pallet::weight(
+			T::WeightInfo::force_transfer().saturating_add(T::Compliance::can_receive_weight())
+		)]
 		pub fn force_transfer(
 			origin: OriginFor<T>,
 			#[pallet::compact] id: T::AssetId,
@@ -1267,7 +1279,9 @@ pub mod pallet {
 		/// Emits `TransferredApproved` on success.
 		///
 		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::transfer_approved())]
+		#[pallet::weight(
+			T::WeightInfo::transfer_approved().saturating_add(T::Compliance::can_receive_weight())
+		)]
 		pub fn transfer_approved(
 			origin: OriginFor<T>,
 			#[pallet::compact] id: T::AssetId,
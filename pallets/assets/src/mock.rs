@@ -23,6 +23,7 @@ use crate as pallet_assets;
 use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, GenesisBuild},
+	weights::Weight,
 };
 use sp_core::H256;
 use sp_runtime::{
@@ -98,6 +99,7 @@ impl Config for Test {
 	type ApprovalDeposit = ConstU64<1>;
 	type StringLimit = ConstU32<50>;
 	type Freezer = TestFreezer;
+	type Compliance = TestCompliance;
 	type WeightInfo = ();
 	type Extra = ();
 }
@@ -111,6 +113,7 @@ pub enum Hook {
 parameter_types! {
 	static Frozen: HashMap<(u32, u64), u64> = Default::default();
 	static Hooks: Vec<Hook> = Default::default();
+	static NonCompliant: Vec<(u32, u64)> = Default::default();
 }
 
 pub struct TestFreezer;
@@ -127,6 +130,21 @@ impl FrozenBalance<u32, u64, u64> for TestFreezer {
 	}
 }
 
+pub struct TestCompliance;
+impl TransferCompliance<u32, u64> for TestCompliance {
+	fn can_receive(asset: u32, who: &u64) -> bool {
+		!NonCompliant::get().contains(&(asset, *who))
+	}
+
+	fn can_receive_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub(crate) fn set_non_compliant(asset: u32, who: u64) {
+	NonCompliant::mutate(|v| v.push((asset, who)));
+}
+
 pub(crate) fn set_frozen_balance(asset: u32, who: u64, amount: u64) {
 	Frozen::mutate(|v| {
 		v.insert((asset, who), amount);
@@ -514,6 +514,41 @@ fn transferring_frozen_user_should_not_work() {
 	});
 }
 
+#[test]
+fn transferring_to_non_compliant_account_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 1, 1, 100));
+		set_non_compliant(0, 2);
+
+		// every kind of transfer is restricted
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 50),
+			Error::<Test>::NotCompliant
+		);
+		assert_noop!(
+			Assets::force_transfer(RuntimeOrigin::signed(1), 0, 1, 2, 50),
+			Error::<Test>::NotCompliant
+		);
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 3, 50));
+		assert_noop!(
+			Assets::transfer_approved(RuntimeOrigin::signed(3), 0, 1, 2, 50),
+			Error::<Test>::NotCompliant
+		);
+
+		// minting is not restricted, the account can still send the asset
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 50));
+
+		// the restriction only applies to the given asset
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 1, 2, 50));
+		assert_eq!(Assets::balance(1, 2), 50);
+	});
+}
+
 #[test]
 fn transferring_frozen_asset_should_not_work() {
 	new_test_ext().execute_with(|| {
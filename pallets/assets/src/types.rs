@@ -172,6 +172,29 @@ impl<AssetId, AccountId, Balance> FrozenBalance<AssetId, AccountId, Balance> for
 	fn died(_: AssetId, _: &AccountId) {}
 }
 
+/// Trait for restricting the accounts allowed to receive an asset through a transfer, for example
+/// to enforce the compliance requirements of regulated assets.
+pub trait TransferCompliance<AssetId, AccountId> {
+	/// Return true if `who` may receive the asset `asset` through a transfer.
+	///
+	/// Minting and burning are not restricted, the issuer of the asset is responsible for the
+	/// recipient of minted funds.
+	fn can_receive(asset: AssetId, who: &AccountId) -> bool;
+
+	/// The worst case weight of a `can_receive` check, charged by every transfer.
+	fn can_receive_weight() -> Weight;
+}
+
+impl<AssetId, AccountId> TransferCompliance<AssetId, AccountId> for () {
+	fn can_receive(_: AssetId, _: &AccountId) -> bool {
+		true
+	}
+
+	fn can_receive_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub(super) struct TransferFlags {
 	/// The debited account must stay alive at the end of the operation; an error is returned if
@@ -0,0 +1,25 @@
+// This file is part of BitGreen.
+// Copyright (C) 2022 BitGreen.
+// This code is licensed under MIT license (see LICENSE.txt for details)
+//
+//! Runtime API definition for the CarbonCredits pools pallet
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_carbon_credits_pool::{PoolInfo, RetirePreview};
+
+sp_api::decl_runtime_apis! {
+	pub trait CarbonCreditsPoolApi<AccountId, PoolId, AssetId, Balance, PoolConfig> where
+		AccountId: Codec,
+		PoolId: Codec,
+		AssetId: Codec,
+		Balance: Codec,
+		PoolConfig: Codec,
+	{
+		/// The details and composition of the pool
+		fn pool_info(pool_id: PoolId) -> Option<PoolInfo<AccountId, PoolConfig, AssetId, Balance>>;
+
+		/// The credits retired from the pool by `retire` for the given amount
+		fn retire_preview(pool_id: PoolId, amount: Balance) -> Option<RetirePreview<AssetId, Balance>>;
+	}
+}
@@ -0,0 +1,119 @@
+// This file is part of BitGreen.
+// Copyright (C) 2022 BitGreen.
+// This code is licensed under MIT license (see LICENSE.txt for details)
+//
+//! RPC interface for the CarbonCredits pools pallet
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+pub use pallet_carbon_credits_pool_rpc_runtime_api::{
+	CarbonCreditsPoolApi as CarbonCreditsPoolRuntimeApi, PoolInfo, RetirePreview,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+#[rpc(client, server)]
+pub trait CarbonCreditsPoolApi<BlockHash, PoolId, Balance, PoolInfo, RetirePreview> {
+	/// Get the details and composition of the pool
+	#[method(name = "carbonCreditsPool_poolInfo")]
+	fn pool_info(&self, pool_id: PoolId, at: Option<BlockHash>) -> RpcResult<Option<PoolInfo>>;
+
+	/// Get the credits retired from the pool by `retire` for the given amount
+	#[method(name = "carbonCreditsPool_retirePreview")]
+	fn retire_preview(
+		&self,
+		pool_id: PoolId,
+		amount: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RetirePreview>>;
+}
+
+/// Provides RPC methods to query the CarbonCredits pools
+pub struct CarbonCreditsPool<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> CarbonCreditsPool<C, Block> {
+	/// Create new `CarbonCreditsPool` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api
+pub enum Error {
+	/// The call to runtime failed
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, AccountId, PoolId, AssetId, Balance, PoolConfig>
+	CarbonCreditsPoolApiServer<
+		<Block as BlockT>::Hash,
+		PoolId,
+		Balance,
+		PoolInfo<AccountId, PoolConfig, AssetId, Balance>,
+		RetirePreview<AssetId, Balance>,
+	> for CarbonCreditsPool<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: CarbonCreditsPoolRuntimeApi<Block, AccountId, PoolId, AssetId, Balance, PoolConfig>,
+	AccountId: Codec + Serialize + Send + Sync + 'static,
+	PoolId: Codec + DeserializeOwned + Send + Sync + 'static,
+	AssetId: Codec + Serialize + Send + Sync + 'static,
+	Balance: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	PoolConfig: Codec + Serialize + Send + Sync + 'static,
+{
+	fn pool_info(
+		&self,
+		pool_id: PoolId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<PoolInfo<AccountId, PoolConfig, AssetId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.pool_info(&at, pool_id).map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to query pool info.",
+				Some(e.to_string()),
+			))
+			.into()
+		})
+	}
+
+	fn retire_preview(
+		&self,
+		pool_id: PoolId,
+		amount: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<RetirePreview<AssetId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.retire_preview(&at, pool_id, amount).map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to query retire preview.",
+				Some(e.to_string()),
+			))
+			.into()
+		})
+	}
+}
@@ -5,11 +5,21 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use frame_benchmarking::{account, benchmarks, vec};
+use frame_support::{
+	traits::{tokens::fungibles::Inspect, Currency},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
-use pallet_carbon_credits::{BatchGroupOf, ProjectCreateParams, RegistryListOf, SDGTypesListOf, BatchOf, BatchGroupListOf};
-use primitives::{Batch, RegistryDetails, RegistryName, SDGDetails, SdgType};
+use pallet_carbon_credits::{
+	BatchGroupListOf, BatchGroupOf, BatchOf, CurrencyBalanceOf, LocationOf, ProjectCreateParams,
+	RegistryListOf, SDGTypesListOf,
+};
+use primitives::{
+	Batch, BatchStatus, Coordinate, CreditType, Geometry, IssuanceYear, KycLevel, Location,
+	RegistryDetails, RegistryName, SDGDetails, SdgType,
+};
+use sp_runtime::traits::Bounded;
 use sp_std::convert::TryInto;
-use frame_support::BoundedVec;
 
 use super::*;
 use crate::Pallet as CarbonCreditPools;
@@ -42,24 +52,26 @@ fn get_default_sdg_details<T: Config>() -> SDGTypesListOf<T> {
 	sdg_details
 }
 
-fn get_single_batch_list<T: Config>() -> BoundedVec<BatchOf<T>, T::MaxGroupSize> {
+fn get_single_batch_list<T: Config>(
+	issuance_year: IssuanceYear,
+) -> BoundedVec<BatchOf<T>, T::MaxGroupSize> {
 	vec![Batch {
 		name: "batch_name".as_bytes().to_vec().try_into().unwrap(),
 		uuid: "batch_uuid".as_bytes().to_vec().try_into().unwrap(),
-		issuance_year: 2020_u16,
-		start_date: 2020_u16,
-		end_date: 2020_u16,
+		issuance_year,
+		start_date: issuance_year,
+		end_date: issuance_year,
 		total_supply: 100_u32.into(),
 		minted: 0_u32.into(),
 		retired: 0_u32.into(),
+		status: BatchStatus::Verified,
 	}]
 	.try_into()
 	.unwrap()
 }
 
-
 /// helper function to generate standard batch details
-fn get_default_batch_group<T: Config>() -> BatchGroupListOf<T> {
+fn get_default_batch_group<T: Config>(issuance_year: IssuanceYear) -> BatchGroupListOf<T> {
 	vec![BatchGroupOf::<T> {
 		name: "batch_group_name".as_bytes().to_vec().try_into().unwrap(),
 		uuid: "batch_group_uuid".as_bytes().to_vec().try_into().unwrap(),
@@ -67,20 +79,36 @@ fn get_default_batch_group<T: Config>() -> BatchGroupListOf<T> {
 		total_supply: 100_u32.into(),
 		minted: 0_u32.into(),
 		retired: 0_u32.into(),
-		batches: get_single_batch_list::<T>(),
+		batches: get_single_batch_list::<T>(issuance_year),
 	}]
 	.try_into()
 	.unwrap()
 }
 
+/// helper function to generate standard location
+fn get_default_location<T: Config>() -> LocationOf<T> {
+	Location {
+		geometry: Geometry::Polygon(
+			vec![
+				Coordinate { latitude: 1_000_000, longitude: 1_000_000 },
+				Coordinate { latitude: 1_000_000, longitude: 2_000_000 },
+				Coordinate { latitude: 2_000_000, longitude: 2_000_000 },
+				Coordinate { latitude: 2_000_000, longitude: 1_000_000 },
+			]
+			.try_into()
+			.unwrap(),
+		),
+		country: Some(*b"BR"),
+		region: Some("region".as_bytes().to_vec().try_into().unwrap()),
+	}
+}
+
 /// helper function to generate standard creation details
-fn get_default_creation_params<T: Config>() -> ProjectCreateParams<T>
-where
-{
+fn get_default_creation_params<T: Config>(issuance_year: IssuanceYear) -> ProjectCreateParams<T> {
 	let creation_params = ProjectCreateParams {
 		name: "name".as_bytes().to_vec().try_into().unwrap(),
 		description: "description".as_bytes().to_vec().try_into().unwrap(),
-		location: vec![(1, 1), (2, 2), (3, 3), (4, 4)].try_into().unwrap(),
+		location: get_default_location::<T>(),
 		images: vec!["image_link".as_bytes().to_vec().try_into().unwrap()].try_into().unwrap(),
 		videos: vec!["video_link".as_bytes().to_vec().try_into().unwrap()].try_into().unwrap(),
 		documents: vec!["document_link".as_bytes().to_vec().try_into().unwrap()]
@@ -88,26 +116,72 @@ where
 			.unwrap(),
 		registry_details: get_default_registry_details::<T>(),
 		sdg_details: get_default_sdg_details::<T>(),
-		batch_groups: get_default_batch_group::<T>(),
+		batch_groups: get_default_batch_group::<T>(issuance_year),
+		credit_type: CreditType::Avoidance,
 		royalties: None,
 	};
 
 	creation_params
 }
 
+/// helper function to create an approved project and mint credits to the caller
+fn create_project_with_credits<T: Config>(
+	caller: T::AccountId,
+	issuance_year: IssuanceYear,
+) -> Result<T::AssetId, &'static str> {
+	let project_id = pallet_carbon_credits::Pallet::<T>::next_project_id();
+	let asset_id = pallet_carbon_credits::Pallet::<T>::next_asset_id();
+	let creation_params = get_default_creation_params::<T>(issuance_year);
+
+	pallet_carbon_credits::Pallet::<T>::create(
+		RawOrigin::Signed(caller.clone()).into(),
+		creation_params,
+	)?;
+	pallet_carbon_credits::Pallet::<T>::approve_project(
+		RawOrigin::Signed(caller.clone()).into(),
+		project_id,
+		true,
+	)?;
+	pallet_carbon_credits::Pallet::<T>::mint(
+		RawOrigin::Signed(caller).into(),
+		project_id,
+		0_u32.into(),
+		100_u32.into(),
+		false,
+	)?;
+
+	Ok(asset_id)
+}
+
+/// helper function to setup a KYC approved and authorised caller with funds for project deposits
+fn setup_caller<T: Config>() -> Result<T::AccountId, &'static str> {
+	let caller: T::AccountId = account("account_id", 0, 0);
+	<T as pallet_carbon_credits::Config>::Currency::make_free_balance_be(
+		&caller,
+		CurrencyBalanceOf::<T>::max_value() / 2u32.into(),
+	);
+	<T as pallet_carbon_credits::Config>::BenchmarkHelper::set_kyc_level(
+		&caller,
+		KycLevel::Institutional,
+	);
+	pallet_carbon_credits::Pallet::<T>::force_add_authorized_account(
+		RawOrigin::Root.into(),
+		caller.clone(),
+	)?;
+	Ok(caller)
+}
+
 benchmarks! {
 
 	where_clause { where
 	T::PoolId: From<u32>,
-	T: pallet_membership::Config
 }
 
 	create {
-		let account_id : T::AccountId = account("account_id", 0, 0);
 		let owner : T::AccountId = account("owner", 0, 1);
 		let pool_id = 10_001_u32.into();
 		let asset_symbol =  "pool_xyz".as_bytes().to_vec().try_into().unwrap();
-	}: _(RawOrigin::Signed(account_id), pool_id, owner, Default::default(), None, asset_symbol)
+	}: _(RawOrigin::Root, pool_id, owner, Default::default(), None, asset_symbol)
 	verify {
 		assert!(
 			CarbonCreditPools::<T>::pools(pool_id).is_some()
@@ -115,54 +189,140 @@ benchmarks! {
 	}
 
 	deposit {
-		let caller : T::AccountId = account("account_id", 0, 0);
 		let owner : T::AccountId = account("owner", 0, 1);
 		// create a project and mint tokens
-		let project_id : T::ProjectId = 0_u32.into();
-		let group_id  : T::GroupId = 0_u32.into();
-		let asset_id : T::AssetId = 0_u32.into();
-		let creation_params = get_default_creation_params::<T>();
-		let caller_lookup = <T::Lookup as sp_runtime::traits::StaticLookup>::unlookup(caller.clone());
-		pallet_membership::Pallet::<T>::add_member(RawOrigin::Root.into(), caller_lookup)?;
-
-		pallet_carbon_credits::Pallet::<T>::force_add_authorized_account(RawOrigin::Root.into(), caller.clone().into())?;
-		pallet_carbon_credits::Pallet::<T>::create(RawOrigin::Signed(caller.clone()).into(), creation_params)?;
-		pallet_carbon_credits::Pallet::<T>::approve_project(RawOrigin::Signed(caller.clone()).into(), project_id, true)?;
-		pallet_carbon_credits::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), project_id, group_id, 100_u32.into(), false)?;
+		let caller = setup_caller::<T>()?;
+		let asset_id = create_project_with_credits::<T>(caller.clone(), 2020)?;
 
 		// create a pool
 		let pool_id = 10_001_u32.into();
 		let asset_symbol =  "pool_xyz".as_bytes().to_vec().try_into().unwrap();
-		CarbonCreditPools::<T>::create(RawOrigin::Signed(caller.clone()).into(), pool_id, owner, Default::default(), None, asset_symbol).unwrap();
+		CarbonCreditPools::<T>::create(RawOrigin::Root.into(), pool_id, owner, Default::default(), None, asset_symbol)?;
 	}: _(RawOrigin::Signed(caller.clone()), pool_id, asset_id, 1_u32.into())
 	verify {
 		assert_last_event::<T>(Event::Deposit { asset_id, who : caller, amount : 1_u32.into(), pool_id }.into());
 	}
 
 	retire {
-		let caller : T::AccountId = account("account_id", 0, 0);
+		// number of vintages in the pool
+		let v in 1 .. T::MaxRetireProjects::get();
+		// number of projects in the pool
+		let p in 1 .. T::MaxRetireProjects::get();
+
 		let owner : T::AccountId = account("owner", 0, 1);
-		// create a project and mint tokens
-		let project_id : T::ProjectId = 0_u32.into();
-		let group_id  : T::GroupId = 0_u32.into();
-		let asset_id : T::AssetId = 0_u32.into();
-		let creation_params = get_default_creation_params::<T>();
-		let caller_lookup = <T::Lookup as sp_runtime::traits::StaticLookup>::unlookup(caller.clone());
-		pallet_membership::Pallet::<T>::add_member(RawOrigin::Root.into(), caller_lookup)?;
-
-		pallet_carbon_credits::Pallet::<T>::force_add_authorized_account(RawOrigin::Root.into(), caller.clone().into())?;
-		pallet_carbon_credits::Pallet::<T>::create(RawOrigin::Signed(caller.clone()).into(), creation_params)?;
-		pallet_carbon_credits::Pallet::<T>::approve_project(RawOrigin::Signed(caller.clone()).into(), project_id, true)?;
-		pallet_carbon_credits::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), project_id, group_id, 100_u32.into(), false)?;
+		let caller = setup_caller::<T>()?;
+
+		// create a pool
+		let pool_id = 10_001_u32.into();
+		let asset_symbol =  "pool_xyz".as_bytes().to_vec().try_into().unwrap();
+		CarbonCreditPools::<T>::create(RawOrigin::Root.into(), pool_id, owner, Default::default(), None, asset_symbol)?;
+
+		// create `p` projects spread over `v` vintages and deposit to the pool
+		for i in 0 .. p {
+			let issuance_year = 2000_u16 + (i % v) as u16;
+			let asset_id = create_project_with_credits::<T>(caller.clone(), issuance_year)?;
+			CarbonCreditPools::<T>::deposit(RawOrigin::Signed(caller.clone()).into(), pool_id, asset_id, 10_u32.into())?;
+		}
+
+		// retire the credits of every project in the pool
+		let amount : T::Balance = (p * 10).into();
+	}: _(RawOrigin::Signed(caller.clone()), pool_id, amount, None)
+	verify {
+		assert_last_event::<T>(Event::Retired { pool_id, who : caller.clone(), amount, retirement_id : Default::default(), beneficiary : caller }.into());
+	}
+
+	redeem {
+		let owner : T::AccountId = account("owner", 0, 1);
+		let caller = setup_caller::<T>()?;
+		let asset_id = create_project_with_credits::<T>(caller.clone(), 2020)?;
 
 		// create a pool and deposit tokens
 		let pool_id = 10_001_u32.into();
 		let asset_symbol =  "pool_xyz".as_bytes().to_vec().try_into().unwrap();
-		CarbonCreditPools::<T>::create(RawOrigin::Signed(caller.clone()).into(), pool_id, owner, Default::default(), None, asset_symbol).unwrap();
-		CarbonCreditPools::<T>::deposit(RawOrigin::Signed(caller.clone()).into(), pool_id, asset_id, 10_u32.into()).unwrap();
-	}: _(RawOrigin::Signed(caller.clone()), pool_id, 1_u32.into())
+		CarbonCreditPools::<T>::create(RawOrigin::Root.into(), pool_id, owner.clone(), Default::default(), None, asset_symbol)?;
+		CarbonCreditPools::<T>::deposit(RawOrigin::Signed(caller.clone()).into(), pool_id, asset_id, 10_u32.into())?;
+		CarbonCreditPools::<T>::force_set_redemption_fee(RawOrigin::Root.into(), T::MaxRedemptionFee::get())?;
+	}: _(RawOrigin::Signed(caller.clone()), pool_id, asset_id, 10_u32.into())
+	verify {
+		assert!(<T as pallet::Config>::AssetHandler::balance(pool_id.into(), &owner) > 0_u32.into());
+	}
+
+	redeem_oldest {
+		// number of vintages in the pool
+		let v in 1 .. T::MaxRetireProjects::get();
+		// number of projects in the pool
+		let p in 1 .. T::MaxRetireProjects::get();
+
+		let owner : T::AccountId = account("owner", 0, 1);
+		let caller = setup_caller::<T>()?;
+
+		// create a pool
+		let pool_id = 10_001_u32.into();
+		let asset_symbol =  "pool_xyz".as_bytes().to_vec().try_into().unwrap();
+		CarbonCreditPools::<T>::create(RawOrigin::Root.into(), pool_id, owner, Default::default(), None, asset_symbol)?;
+
+		// create `p` projects spread over `v` vintages and deposit to the pool
+		for i in 0 .. p {
+			let issuance_year = 2000_u16 + (i % v) as u16;
+			let asset_id = create_project_with_credits::<T>(caller.clone(), issuance_year)?;
+			CarbonCreditPools::<T>::deposit(RawOrigin::Signed(caller.clone()).into(), pool_id, asset_id, 10_u32.into())?;
+		}
+
+		// redeem the credits of every project in the pool
+		let amount : T::Balance = (p * 10).into();
+	}: _(RawOrigin::Signed(caller.clone()), pool_id, amount)
+	verify {
+		assert!(CarbonCreditPools::<T>::pools(pool_id).unwrap().vintages.is_empty());
+	}
+
+	force_set_redemption_fee {
+		let redemption_fee = T::MaxRedemptionFee::get();
+	}: _(RawOrigin::Root, redemption_fee)
+	verify {
+		assert_eq!(CarbonCreditPools::<T>::redemption_fee(), redemption_fee);
+	}
+
+	update_config {
+		let owner : T::AccountId = account("owner", 0, 1);
+		let pool_id = 10_001_u32.into();
+		let asset_symbol =  "pool_xyz".as_bytes().to_vec().try_into().unwrap();
+		CarbonCreditPools::<T>::create(RawOrigin::Root.into(), pool_id, owner.clone(), Default::default(), None, asset_symbol)?;
+		let config = PoolConfigOf::<T> { credit_type: Some(CreditType::Avoidance), ..Default::default() };
+	}: _(RawOrigin::Signed(owner), pool_id, config.clone())
+	verify {
+		assert_eq!(CarbonCreditPools::<T>::pools(pool_id).unwrap().config, config);
+	}
+
+	transfer_admin {
+		let owner : T::AccountId = account("owner", 0, 1);
+		let new_admin : T::AccountId = account("new_admin", 0, 2);
+		let pool_id = 10_001_u32.into();
+		let asset_symbol =  "pool_xyz".as_bytes().to_vec().try_into().unwrap();
+		CarbonCreditPools::<T>::create(RawOrigin::Root.into(), pool_id, owner.clone(), Default::default(), None, asset_symbol)?;
+	}: _(RawOrigin::Signed(owner), pool_id, new_admin.clone())
+	verify {
+		assert_eq!(CarbonCreditPools::<T>::pools(pool_id).unwrap().admin, new_admin);
+	}
+
+	set_pool_status {
+		let owner : T::AccountId = account("owner", 0, 1);
+		let pool_id = 10_001_u32.into();
+		let asset_symbol =  "pool_xyz".as_bytes().to_vec().try_into().unwrap();
+		CarbonCreditPools::<T>::create(RawOrigin::Root.into(), pool_id, owner.clone(), Default::default(), None, asset_symbol)?;
+	}: _(RawOrigin::Signed(owner), pool_id, PoolStatus::Paused)
+	verify {
+		assert_eq!(CarbonCreditPools::<T>::pools(pool_id).unwrap().status, PoolStatus::Paused);
+	}
+
+	set_pool_fee {
+		let owner : T::AccountId = account("owner", 0, 1);
+		let pool_id = 10_001_u32.into();
+		let asset_symbol =  "pool_xyz".as_bytes().to_vec().try_into().unwrap();
+		CarbonCreditPools::<T>::create(RawOrigin::Root.into(), pool_id, owner.clone(), Default::default(), None, asset_symbol)?;
+		let retire_fee = PoolFee { fee: T::MaxPoolFee::get(), recipient: FeeRecipient::Treasury };
+	}: set_retire_fee(RawOrigin::Signed(owner), pool_id, retire_fee)
 	verify {
-		assert_last_event::<T>(Event::Retired { pool_id, who : caller, amount : 1_u32.into() }.into());
+		assert_eq!(CarbonCreditPools::<T>::pools(pool_id).unwrap().retire_fee, retire_fee);
 	}
 
 	impl_benchmark_test_suite!(CarbonCreditPools, crate::mock::new_test_ext(), crate::mock::Test);
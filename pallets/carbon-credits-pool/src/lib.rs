@@ -12,9 +12,13 @@
 //!
 //! ### Pool Config
 //! A pool creator can setup configs, these configs determine which type of tokens are accepted into
-//! the pool. Currently the owner can setup two configs for a pool 1. Registry List : This limits
-//! the pool to accept CarbonCredits's issued by the given registry's only 2. Project List : This
-//! limits the pool to accepts CarbonCredits's issued by specific project's only
+//! the pool. Currently the owner can setup the following configs for a pool 1. Registry List : This
+//! limits the pool to accept CarbonCredits's issued by the given registry's only 2. Project List :
+//! This limits the pool to accepts CarbonCredits's issued by specific project's only 3. Min/Max
+//! issuance year : This limits the vintages accepted by the pool to a range 4. Issuance year list :
+//! This limits the pool to accept CarbonCredits of the given vintages only 5. Required SDGs : This
+//! limits the pool to projects contributing to all the given SDGs 6. Credit type : This limits the
+//! pool to avoidance or removal credits only
 //!
 //! ## Interface
 //!
@@ -22,11 +26,22 @@
 //!
 //! * `create`: Creates a new pool with given config
 //! * `deposit`: Deposit some CarbonCredits tokens to generate pool tokens
-//! * `retire`: Burn a specified amount of pool tokens
+//! * `retire`: Burn a specified amount of pool tokens and mint a retirement certificate
+//! * `redeem`: Burn pool tokens to receive a specific CarbonCredits token held by the pool
+//! * `redeem_oldest`: Burn pool tokens to receive the oldest CarbonCredits tokens held by the pool
+//!
+//! ### Pool Admin Functions
+//!
+//! * `update_config`: Update the config of the pool
+//! * `transfer_admin`: Transfer the admin rights of the pool to another account
+//! * `set_pool_status`: Pause deposits or all operations of the pool
+//! * `set_deposit_fee`: Set the fee charged in pool tokens on deposits
+//! * `set_retire_fee`: Set the fee charged in pool tokens on retirements
 //!
 //! ### Permissioned Functions
 //!
 //! * `force_set_pool_storage`: Set the pool storage
+//! * `force_set_redemption_fee`: Set the fee charged on redemptions
 #![cfg_attr(not(feature = "std"), no_std)]
 pub use pallet::*;
 
@@ -36,13 +51,15 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
-// #[cfg(feature = "runtime-benchmarks")]
-// mod benchmarking;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 
 mod types;
 pub use types::*;
 
-mod weights;
+pub mod migration;
+
+pub mod weights;
 pub use weights::WeightInfo;
 
 #[frame_support::pallet]
@@ -51,12 +68,24 @@ pub mod pallet {
 	use frame_support::{
 		dispatch::DispatchResultWithPostInfo,
 		pallet_prelude::*,
-		traits::tokens::fungibles::{metadata::Mutate as MetadataMutate, Create, Mutate, Transfer},
+		storage::{with_transaction, TransactionOutcome},
+		traits::tokens::{
+			fungibles::{metadata::Mutate as MetadataMutate, Create, Inspect, Mutate, Transfer},
+			nonfungibles::{Create as NFTCreate, Mutate as NFTMutate},
+		},
 		transactional, PalletId,
 	};
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::traits::{AccountIdConversion, CheckedAdd, CheckedSub, Zero};
-	use sp_std::convert::{TryFrom, TryInto};
+	use primitives::{CreditType, IssuanceYear, RegistryName, SdgType};
+	use sp_runtime::{
+		traits::{AccountIdConversion, CheckedAdd, CheckedSub, One, Saturating, Zero},
+		Percent,
+	};
+	use sp_std::{
+		convert::{TryFrom, TryInto},
+		vec,
+		vec::Vec,
+	};
 
 	use super::*;
 
@@ -92,10 +121,25 @@ pub mod pallet {
 		type MaxIssuanceYearCount: Get<u32>;
 		/// Maximum projectIds allowed in the pool config
 		type MaxProjectIdList: Get<u32>;
+		/// Maximum number of projects a pool can hold, must not be lower than `MaxProjectIdList`
+		/// so that pools migrated from the per-vintage layout fit
+		#[pallet::constant]
+		type MaxPoolProjects: Get<u32>;
+		/// Maximum number of projects credits are taken from in a single retire or redeem
+		#[pallet::constant]
+		type MaxRetireProjects: Get<u32>;
 		/// Max length of pool asset symbol
 		type MaxAssetSymbolLength: Get<u32>;
 		/// Min permitted value for PoolId
 		type MinPoolId: Get<Self::PoolId>;
+		/// Maximum redemption fee that can be set
+		#[pallet::constant]
+		type MaxRedemptionFee: Get<Percent>;
+		/// Maximum deposit and retire fee a pool admin can set
+		#[pallet::constant]
+		type MaxPoolFee: Get<Percent>;
+		/// The treasury account receiving the pool fees
+		type TreasuryAccount: Get<Self::AccountId>;
 		/// The CarbonCredits-pools pallet id
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -103,17 +147,52 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
 	#[pallet::getter(fn pools)]
 	pub type Pools<T: Config> = StorageMap<_, Blake2_128Concat, T::PoolId, PoolOf<T>>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn redemption_fee)]
+	/// The fee charged in pool tokens when redeeming CarbonCredits from a pool, paid to the
+	/// recipient of the retire fee of the pool
+	pub type RedemptionFee<T: Config> = StorageValue<_, Percent, ValueQuery>;
+
+	#[pallet::storage]
+	/// The next retirement id of a pool, the id is also the item id of the certificate NFT
+	pub type NextRetirementId<T: Config> = StorageMap<_, Blake2_128Concat, T::PoolId, T::ItemId>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pool_retirements)]
+	/// The records of the retirements from a pool
+	pub type PoolRetirements<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::ItemId,
+		PoolRetirementRecordOf<T>,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn pool_credits)]
-	pub type PoolCredits<T: Config> = StorageMap<_, Blake2_128Concat, T::PoolId, PoolOf<T>>;
+	/// The credits held by the pools
+	/// (PoolId, IssuanceYear) => AssetId => Amount of tokens in pool
+	pub type PoolCredits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(T::PoolId, IssuanceYear),
+		Blake2_128Concat,
+		T::AssetId,
+		T::Balance,
+	>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -122,8 +201,46 @@ pub mod pallet {
 		PoolCreated { admin: T::AccountId, id: T::PoolId, config: PoolConfigOf<T> },
 		/// A new deposit was added to pool
 		Deposit { who: T::AccountId, pool_id: T::PoolId, asset_id: T::AssetId, amount: T::Balance },
-		/// Pool tokens were retired
-		Retired { who: T::AccountId, pool_id: T::PoolId, amount: T::Balance },
+		/// Pool tokens were retired, the certificate NFT `retirement_id` of the pool collection
+		/// was minted to the beneficiary
+		Retired {
+			who: T::AccountId,
+			pool_id: T::PoolId,
+			amount: T::Balance,
+			retirement_id: T::ItemId,
+			beneficiary: T::AccountId,
+		},
+		/// Pool tokens were redeemed for the underlying CarbonCredits
+		Redeemed {
+			who: T::AccountId,
+			pool_id: T::PoolId,
+			amount: T::Balance,
+			fee: T::Balance,
+			redeemed: Vec<(T::AssetId, T::Balance)>,
+		},
+		/// The redemption fee was updated
+		RedemptionFeeSet { redemption_fee: Percent },
+		/// The config of the pool was updated
+		PoolConfigUpdated { pool_id: T::PoolId, config: PoolConfigOf<T> },
+		/// The admin of the pool was changed
+		PoolAdminTransferred {
+			pool_id: T::PoolId,
+			old_admin: T::AccountId,
+			new_admin: T::AccountId,
+		},
+		/// The status of the pool was updated
+		PoolStatusUpdated { pool_id: T::PoolId, status: PoolStatus },
+		/// The deposit fee of the pool was updated
+		DepositFeeSet { pool_id: T::PoolId, deposit_fee: PoolFee },
+		/// The retire fee of the pool was updated
+		RetireFeeSet { pool_id: T::PoolId, retire_fee: PoolFee },
+		/// A pool fee was paid in pool tokens
+		PoolFeePaid {
+			pool_id: T::PoolId,
+			who: T::AccountId,
+			recipient: T::AccountId,
+			amount: T::Balance,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -149,6 +266,38 @@ pub mod pallet {
 		ProjectIssuanceYearError,
 		/// User entered an invalid amount
 		InvalidAmount,
+		/// The pool does not hold enough credits
+		InsufficientPoolCredits,
+		/// The credits in the pool do not match the supply of pool tokens
+		PoolSupplyMismatch,
+		/// The min issuance year of the pool config is greater than the max issuance year
+		InvalidIssuanceYearRange,
+		/// The credits are older than the min issuance year of the pool
+		IssuanceYearBelowMinimum,
+		/// The credits are newer than the max issuance year of the pool
+		IssuanceYearAboveMaximum,
+		/// The issuance year of the credits is not in the list permitted by the pool
+		IssuanceYearNotPermitted,
+		/// The project does not contribute to all the SDGs required by the pool
+		SdgRequirementNotMet,
+		/// The pool does not accept this type of credits
+		CreditTypeNotPermitted,
+		/// Cannot set the redemption fee above the max redemption fee
+		CannotSetMoreThanMaxRedemptionFee,
+		/// Cannot set the pool fee above the max pool fee
+		CannotSetMoreThanMaxPoolFee,
+		/// The caller is not the admin of the pool
+		NotPoolAdmin,
+		/// The pool does not accept deposits
+		PoolDepositsPaused,
+		/// The pool is paused
+		PoolPaused,
+		/// The pool has reached the max limit of projects
+		PoolProjectLimitReached,
+		/// The amount spans more projects than can be retired or redeemed in a single call
+		RetireProjectLimitReached,
+		/// The given AssetId is not a carbon credits asset
+		InvalidAssetId,
 	}
 
 	#[pallet::call]
@@ -159,7 +308,7 @@ pub mod pallet {
 		/// id : Id of the new pool
 		/// config : Config values for new pool
 		/// max_limit : Limit of maximum project-ids the pool can support, default to
-		/// T::MaxProjectIdLIst asset_symbol : Symbol for asset created for the pool
+		/// T::MaxPoolProjects asset_symbol : Symbol for asset created for the pool
 		#[transactional]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::create())]
 		pub fn create(
@@ -176,16 +325,18 @@ pub mod pallet {
 
 			ensure!(!Pools::<T>::contains_key(id), Error::<T>::PoolIdInUse);
 
+			Self::check_pool_config(&config)?;
+
 			// use default limit if limit not given by project owner
 			let actual_max_limit = match max_limit {
 				Some(limit) => {
 					ensure!(
-						limit <= T::MaxProjectIdList::get(),
+						limit <= T::MaxPoolProjects::get(),
 						Error::<T>::MaxLimitGreaterThanPermitted
 					);
 					limit
 				},
-				None => T::MaxProjectIdList::get(),
+				None => T::MaxPoolProjects::get(),
 			};
 
 			// insert to storage
@@ -195,7 +346,11 @@ pub mod pallet {
 					admin: admin.clone(),
 					config: config.clone(),
 					max_limit: actual_max_limit,
-					credits: Default::default(),
+					vintages: Default::default(),
+					total_credits: Zero::zero(),
+					status: Default::default(),
+					deposit_fee: Default::default(),
+					retire_fee: Default::default(),
 				},
 			);
 
@@ -243,9 +398,11 @@ pub mod pallet {
 			Pools::<T>::try_mutate(pool_id, |pool| -> DispatchResultWithPostInfo {
 				let pool = pool.as_mut().ok_or(Error::<T>::InvalidPoolId)?;
 
+				ensure!(pool.status == PoolStatus::Active, Error::<T>::PoolDepositsPaused);
+
 				let (project_id, group_id) =
 					pallet_carbon_credits::Pallet::<T>::asset_id_lookup(asset_id)
-						.ok_or(Error::<T>::InvalidPoolId)?;
+						.ok_or(Error::<T>::InvalidAssetId)?;
 
 				// get the details of project
 				let project_details: pallet_carbon_credits::ProjectDetail<T> =
@@ -273,6 +430,25 @@ pub mod pallet {
 					)
 				}
 
+				if let Some(required_sdgs) = &pool.config.required_sdgs {
+					for required_sdg in required_sdgs.iter() {
+						ensure!(
+							project_details
+								.sdg_details
+								.iter()
+								.any(|sdg| sdg.sdg_type == *required_sdg),
+							Error::<T>::SdgRequirementNotMet
+						);
+					}
+				}
+
+				if let Some(credit_type) = pool.config.credit_type {
+					ensure!(
+						project_details.credit_type == credit_type,
+						Error::<T>::CreditTypeNotPermitted
+					);
+				}
+
 				// calculate the issuance year for the project
 				let project_issuance_year = pallet_carbon_credits::Pallet::calculate_issuance_year(
 					project_details,
@@ -280,6 +456,28 @@ pub mod pallet {
 				)
 				.ok_or(Error::<T>::ProjectIssuanceYearError)?;
 
+				// ensure the issuance year passes the pool config
+				if let Some(min_issuance_year) = pool.config.min_issuance_year {
+					ensure!(
+						project_issuance_year >= min_issuance_year,
+						Error::<T>::IssuanceYearBelowMinimum
+					);
+				}
+
+				if let Some(max_issuance_year) = pool.config.max_issuance_year {
+					ensure!(
+						project_issuance_year <= max_issuance_year,
+						Error::<T>::IssuanceYearAboveMaximum
+					);
+				}
+
+				if let Some(issuance_year_list) = &pool.config.issuance_year_list {
+					ensure!(
+						issuance_year_list.contains(&project_issuance_year),
+						Error::<T>::IssuanceYearNotPermitted
+					);
+				}
+
 				// transfer the tokens to pallet account
 				<T as pallet::Config>::AssetHandler::transfer(
 					asset_id,
@@ -289,46 +487,61 @@ pub mod pallet {
 					false,
 				)?;
 
-				// add the project to the credits pool
-				let issuance_year_map = pool.credits.get_mut(&project_issuance_year);
-
-				// If the issuance year tokens have been deposited to the pool previously
-				// insert the project details
-				if let Some(project_map) = issuance_year_map {
-					let project_details = project_map.get_mut(&asset_id);
-					// If the project tokens have been previoulsy deposited to the
-					// pool, increment the counter
-					if let Some(existing_amount) = project_details {
-						let new_amount = existing_amount
-							.checked_add(&amount)
-							.ok_or(Error::<T>::UnexpectedOverflow)?;
+				// new projects are counted under the issuance year, the pool cannot accept more
+				// projects than the max limit
+				let credits_key = (pool_id, project_issuance_year);
+				if !PoolCredits::<T>::contains_key(credits_key, asset_id) {
+					let project_count: u32 = pool.vintages.values().sum();
+					ensure!(project_count < pool.max_limit, Error::<T>::PoolProjectLimitReached);
 
-						project_map
-							.try_insert(asset_id, new_amount)
-							.map_err(|_| Error::<T>::UnexpectedOverflow)?;
-					}
-					// If the project tokens have been NOT been previoulsy deposited to the
-					// pool, create a new entry
-					else {
-						project_map
-							.try_insert(asset_id, amount)
+					if let Some(vintage_count) = pool.vintages.get_mut(&project_issuance_year) {
+						*vintage_count = vintage_count.saturating_add(1);
+					} else {
+						pool.vintages
+							.try_insert(project_issuance_year, 1)
 							.map_err(|_| Error::<T>::UnexpectedOverflow)?;
 					}
 				}
-				// If the issuance year tokens have NOT been deposited to the pool previously
-				// create a new entry
-				else {
-					let mut project_map: ProjectDetails<T> = Default::default();
-					project_map
-						.try_insert(asset_id, amount)
-						.map_err(|_| Error::<T>::UnexpectedOverflow)?;
-					pool.credits
-						.try_insert(project_issuance_year, project_map)
-						.map_err(|_| Error::<T>::UnexpectedOverflow)?;
-				}
 
-				// Mint new pool tokens and transfer to caller
-				<T as pallet::Config>::AssetHandler::mint_into(pool_id.into(), &who, amount)?;
+				// add the project to the credits pool
+				PoolCredits::<T>::try_mutate(credits_key, asset_id, |credits| -> DispatchResult {
+					let existing_amount = credits.take().unwrap_or_else(Zero::zero);
+					*credits = Some(
+						existing_amount
+							.checked_add(&amount)
+							.ok_or(Error::<T>::UnexpectedOverflow)?,
+					);
+					Ok(())
+				})?;
+				pool.total_credits = pool
+					.total_credits
+					.checked_add(&amount)
+					.ok_or(Error::<T>::UnexpectedOverflow)?;
+
+				// Mint new pool tokens to caller, the deposit fee is minted to the fee recipient
+				let fee = pool.deposit_fee.fee.mul_ceil(amount);
+				let minted_amount =
+					amount.checked_sub(&fee).ok_or(Error::<T>::UnexpectedOverflow)?;
+				ensure!(!minted_amount.is_zero(), Error::<T>::InvalidAmount);
+				<T as pallet::Config>::AssetHandler::mint_into(
+					pool_id.into(),
+					&who,
+					minted_amount,
+				)?;
+				if !fee.is_zero() {
+					let recipient = Self::fee_recipient_account(pool, pool.deposit_fee.recipient);
+					<T as pallet::Config>::AssetHandler::mint_into(
+						pool_id.into(),
+						&recipient,
+						fee,
+					)?;
+					Self::deposit_event(Event::PoolFeePaid {
+						pool_id,
+						who: who.clone(),
+						recipient,
+						amount: fee,
+					});
+				}
 
 				// Emit an event.
 				Self::deposit_event(Event::Deposit { who, pool_id, asset_id, amount });
@@ -341,93 +554,317 @@ pub mod pallet {
 		/// CarbonCredits token supply in the pool and retire tokens starting from the oldest
 		/// issuance until the entire amount is retired.
 		///
+		/// A single call retires from at most `MaxRetireProjects` projects, the weight is charged
+		/// for that maximum and the unused weight is refunded based on the entries actually
+		/// retired.
+		///
+		/// A single certificate NFT is minted to the beneficiary for the entire retirement, the
+		/// record of the retirement links the underlying CarbonCredits retirements.
+		///
 		/// Params:
 		/// pool_id : Id of the pooltokens to retire
 		/// amount: The amount of CarbonCredits to deposit
+		/// beneficiary: The beneficiary of the retirement, defaults to the caller
 		#[transactional]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::retire())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::retire(
+			T::MaxRetireProjects::get(),
+			T::MaxRetireProjects::get()
+		))]
 		pub fn retire(
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
 			amount: T::Balance,
+			beneficiary: Option<RetirementBeneficiaryOf<T>>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
 			ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
 
+			let beneficiary = beneficiary.unwrap_or(RetirementBeneficiary {
+				account: who.clone(),
+				name: None,
+				reason: None,
+			});
+
 			Pools::<T>::try_mutate(pool_id, |pool| -> DispatchResultWithPostInfo {
 				let pool = pool.as_mut().ok_or(Error::<T>::InvalidPoolId)?;
 
-				// Burn the amount of pool tokens from caller
-				<T as pallet::Config>::AssetHandler::burn_from(pool_id.into(), &who, amount)?;
-
-				let mut remaining = amount;
-
-				let mut pool_credits_temp = pool.credits.clone().into_inner();
-
-				// Retire tokens starting from oldest until `amount` is retired
-				for (_year, project_map) in pool_credits_temp.iter_mut() {
-					// the iterator is sorted by key (year), so retire all from year before moving
-					// to next year we dont care about the project order
-					for (asset_id, available_amount) in project_map.clone().into_inner().iter_mut()
-					{
-						let actual: T::Balance;
-
-						if remaining <= *available_amount {
-							actual = remaining;
-							*available_amount = available_amount
-								.checked_sub(&actual)
-								.ok_or(Error::<T>::UnexpectedOverflow)?;
-						} else {
-							actual = *available_amount;
-							*available_amount = 0_u32.into();
-						}
+				ensure!(pool.status != PoolStatus::Paused, Error::<T>::PoolPaused);
 
-						let (project_id, group_id) =
-							pallet_carbon_credits::Pallet::<T>::asset_id_lookup(asset_id)
-								.ok_or(Error::<T>::InvalidPoolId)?;
+				// the retire fee is paid in pool tokens and not retired
+				let fee = pool.retire_fee.fee.mul_ceil(amount);
+				let retired_amount =
+					amount.checked_sub(&fee).ok_or(Error::<T>::UnexpectedOverflow)?;
+				ensure!(!retired_amount.is_zero(), Error::<T>::InvalidAmount);
 
-						// transfer the CarbonCredits tokens to caller
-						<T as pallet::Config>::AssetHandler::transfer(
-							*asset_id,
-							&Self::account_id(),
-							&who,
-							actual,
-							false,
-						)?;
-						// Retire the transferred tokens
-						pallet_carbon_credits::Pallet::<T>::retire_carbon_credits(
+				// ensure the pool holds enough credits to cover the amount
+				ensure!(pool.total_credits >= retired_amount, Error::<T>::InsufficientPoolCredits);
+
+				// Burn the amount of pool tokens from caller and transfer the fee
+				<T as pallet::Config>::AssetHandler::burn_from(
+					pool_id.into(),
+					&who,
+					retired_amount,
+				)?;
+				if !fee.is_zero() {
+					let recipient = Self::fee_recipient_account(pool, pool.retire_fee.recipient);
+					<T as pallet::Config>::AssetHandler::transfer(
+						pool_id.into(),
+						&who,
+						&recipient,
+						fee,
+						false,
+					)?;
+					Self::deposit_event(Event::PoolFeePaid {
+						pool_id,
+						who: who.clone(),
+						recipient,
+						amount: fee,
+					});
+				}
+
+				// Remove the credits from the pool starting from the oldest
+				let (credits_taken, vintages_retired, projects_retired) =
+					Self::take_pool_credits(pool_id, pool, retired_amount, None)?;
+
+				let mut retired_credits: RetiredCreditsListOf<T> = Default::default();
+				for (_year, asset_id, actual) in credits_taken.into_iter() {
+					let (project_id, group_id) =
+						pallet_carbon_credits::Pallet::<T>::asset_id_lookup(asset_id)
+							.ok_or(Error::<T>::InvalidAssetId)?;
+
+					// transfer the CarbonCredits tokens to caller
+					<T as pallet::Config>::AssetHandler::transfer(
+						asset_id,
+						&Self::account_id(),
+						&who,
+						actual,
+						false,
+					)?;
+					// Retire the transferred tokens, the pool issues a single certificate
+					let (retired_asset_id, item_id) =
+						pallet_carbon_credits::Pallet::<T>::do_retire_carbon_credits(
 							who.clone(),
 							project_id,
 							group_id,
 							actual,
+							false,
 						)?;
+					retired_credits
+						.try_push((retired_asset_id, item_id, actual))
+						.map_err(|_| Error::<T>::UnexpectedOverflow)?;
+				}
 
-						// Update value in storage
-						// TODO : Remove entry if value is zero
-						project_map
-							.try_insert(*asset_id, *available_amount)
-							.map_err(|_| Error::<T>::UnexpectedOverflow)?;
+				Self::check_pool_supply(pool_id, pool)?;
 
-						// this is safe since actual is <= remaining
-						remaining =
-							remaining.checked_sub(&actual).ok_or(Error::<T>::UnexpectedOverflow)?;
-						if remaining <= Zero::zero() {
-							break
-						}
-					}
-				}
+				let retirement_id =
+					Self::mint_retirement_certificate(pool_id, &beneficiary.account)?;
+				let beneficiary_account = beneficiary.account.clone();
 
-				pool.credits = CreditsMap::<T>::try_from(pool_credits_temp)
-					.map_err(|_| Error::<T>::UnexpectedOverflow)?;
+				PoolRetirements::<T>::insert(
+					pool_id,
+					retirement_id,
+					PoolRetirementRecord {
+						pool_id,
+						account: who.clone(),
+						beneficiary,
+						count: retired_amount,
+						retired_credits,
+						timestamp: frame_system::Pallet::<T>::block_number(),
+					},
+				);
 
 				// Emit an event.
-				Self::deposit_event(Event::Retired { who, pool_id, amount });
+				Self::deposit_event(Event::Retired {
+					who,
+					pool_id,
+					amount: retired_amount,
+					retirement_id,
+					beneficiary: beneficiary_account,
+				});
 
-				Ok(().into())
+				Ok(Some(<T as pallet::Config>::WeightInfo::retire(
+					vintages_retired,
+					projects_retired,
+				))
+				.into())
+			})
+		}
+
+		/// Redeem Pool Tokens - A user can burn pool tokens to receive the same amount of a
+		/// specific CarbonCredits token held by the pool, minus the redemption fee. The fee is paid
+		/// in pool tokens to the recipient of the retire fee.
+		///
+		/// Params:
+		/// pool_id : Id of the pooltokens to redeem
+		/// asset_id : The CarbonCredits token to receive
+		/// amount: The amount of pool tokens to redeem
+		#[transactional]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::redeem())]
+		pub fn redeem(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			asset_id: T::AssetId,
+			amount: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_redeem(who, pool_id, Some(asset_id), amount)?;
+			Ok(().into())
+		}
+
+		/// Redeem Pool Tokens - A user can burn pool tokens to receive the CarbonCredits tokens
+		/// held by the pool starting from the oldest issuance, minus the redemption fee. The fee
+		/// is paid in pool tokens to the recipient of the retire fee.
+		///
+		/// Params:
+		/// pool_id : Id of the pooltokens to redeem
+		/// amount: The amount of pool tokens to redeem
+		#[transactional]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::redeem_oldest(
+			T::MaxRetireProjects::get(),
+			T::MaxRetireProjects::get()
+		))]
+		pub fn redeem_oldest(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			amount: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let (vintages_redeemed, projects_redeemed) =
+				Self::do_redeem(who, pool_id, None, amount)?;
+			Ok(Some(<T as pallet::Config>::WeightInfo::redeem_oldest(
+				vintages_redeemed,
+				projects_redeemed,
+			))
+			.into())
+		}
+
+		/// Update the config of the pool, the config only applies to future deposits
+		/// Can only be called by the pool admin
+		#[transactional]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update_config())]
+		pub fn update_config(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			config: PoolConfigOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::check_pool_config(&config)?;
+			Pools::<T>::try_mutate(pool_id, |pool| -> DispatchResult {
+				let pool = pool.as_mut().ok_or(Error::<T>::InvalidPoolId)?;
+				ensure!(pool.admin == who, Error::<T>::NotPoolAdmin);
+				pool.config = config.clone();
+				Self::deposit_event(Event::PoolConfigUpdated { pool_id, config });
+				Ok(())
+			})
+		}
+
+		/// Transfer the admin rights of the pool to a new account
+		/// Can only be called by the pool admin
+		#[transactional]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_admin())]
+		pub fn transfer_admin(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			new_admin: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Pools::<T>::try_mutate(pool_id, |pool| -> DispatchResult {
+				let pool = pool.as_mut().ok_or(Error::<T>::InvalidPoolId)?;
+				ensure!(pool.admin == who, Error::<T>::NotPoolAdmin);
+				pool.admin = new_admin.clone();
+				Self::deposit_event(Event::PoolAdminTransferred {
+					pool_id,
+					old_admin: who,
+					new_admin,
+				});
+				Ok(())
 			})
 		}
 
+		/// Set the status of the pool, deposits and retirements can be paused
+		/// Can only be called by the pool admin
+		#[transactional]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_pool_status())]
+		pub fn set_pool_status(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			status: PoolStatus,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Pools::<T>::try_mutate(pool_id, |pool| -> DispatchResult {
+				let pool = pool.as_mut().ok_or(Error::<T>::InvalidPoolId)?;
+				ensure!(pool.admin == who, Error::<T>::NotPoolAdmin);
+				pool.status = status;
+				Self::deposit_event(Event::PoolStatusUpdated { pool_id, status });
+				Ok(())
+			})
+		}
+
+		/// Set the fee charged in pool tokens on deposits to the pool
+		/// Can only be called by the pool admin
+		#[transactional]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_pool_fee())]
+		pub fn set_deposit_fee(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			deposit_fee: PoolFee,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				deposit_fee.fee <= T::MaxPoolFee::get(),
+				Error::<T>::CannotSetMoreThanMaxPoolFee
+			);
+			Pools::<T>::try_mutate(pool_id, |pool| -> DispatchResult {
+				let pool = pool.as_mut().ok_or(Error::<T>::InvalidPoolId)?;
+				ensure!(pool.admin == who, Error::<T>::NotPoolAdmin);
+				pool.deposit_fee = deposit_fee;
+				Self::deposit_event(Event::DepositFeeSet { pool_id, deposit_fee });
+				Ok(())
+			})
+		}
+
+		/// Set the fee charged in pool tokens on retirements from the pool
+		/// Can only be called by the pool admin
+		#[transactional]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_pool_fee())]
+		pub fn set_retire_fee(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			retire_fee: PoolFee,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				retire_fee.fee <= T::MaxPoolFee::get(),
+				Error::<T>::CannotSetMoreThanMaxPoolFee
+			);
+			Pools::<T>::try_mutate(pool_id, |pool| -> DispatchResult {
+				let pool = pool.as_mut().ok_or(Error::<T>::InvalidPoolId)?;
+				ensure!(pool.admin == who, Error::<T>::NotPoolAdmin);
+				pool.retire_fee = retire_fee;
+				Self::deposit_event(Event::RetireFeeSet { pool_id, retire_fee });
+				Ok(())
+			})
+		}
+
+		/// Force set RedemptionFee value
+		/// Can only be called by ForceOrigin
+		#[transactional]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::force_set_redemption_fee())]
+		pub fn force_set_redemption_fee(
+			origin: OriginFor<T>,
+			redemption_fee: Percent,
+		) -> DispatchResult {
+			<T as pallet::Config>::ForceOrigin::ensure_origin(origin)?;
+			ensure!(
+				redemption_fee <= T::MaxRedemptionFee::get(),
+				Error::<T>::CannotSetMoreThanMaxRedemptionFee
+			);
+			RedemptionFee::<T>::set(redemption_fee);
+			Self::deposit_event(Event::RedemptionFeeSet { redemption_fee });
+			Ok(())
+		}
+
 		/// Force modify pool storage
 		#[transactional]
 		#[pallet::weight(Weight::from_ref_time(10_000_u64) + T::DbWeight::get().writes(1))]
@@ -447,5 +884,363 @@ pub mod pallet {
 		pub fn account_id() -> T::AccountId {
 			<T as pallet::Config>::PalletId::get().into_account_truncating()
 		}
+
+		/// Burn `amount` pool tokens from `who` and transfer the underlying CarbonCredits tokens,
+		/// if `asset_id` is given only that token is redeemed, else the oldest tokens are redeemed.
+		/// Returns the number of vintages and projects redeemed from.
+		pub fn do_redeem(
+			who: T::AccountId,
+			pool_id: T::PoolId,
+			asset_id: Option<T::AssetId>,
+			amount: T::Balance,
+		) -> Result<(u32, u32), DispatchError> {
+			ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+
+			Pools::<T>::try_mutate(pool_id, |pool| -> Result<(u32, u32), DispatchError> {
+				let pool = pool.as_mut().ok_or(Error::<T>::InvalidPoolId)?;
+
+				ensure!(pool.status != PoolStatus::Paused, Error::<T>::PoolPaused);
+
+				// the fee is paid in pool tokens and not redeemed
+				let fee = RedemptionFee::<T>::get().mul_ceil(amount);
+				let redeemed_amount =
+					amount.checked_sub(&fee).ok_or(Error::<T>::UnexpectedOverflow)?;
+				ensure!(!redeemed_amount.is_zero(), Error::<T>::InvalidAmount);
+
+				// ensure the pool holds enough credits to cover the amount
+				ensure!(pool.total_credits >= redeemed_amount, Error::<T>::InsufficientPoolCredits);
+
+				// Burn the redeemed pool tokens and transfer the fee to the recipient of the retire
+				// fee, redeeming and retiring both take credits out of the pool
+				<T as pallet::Config>::AssetHandler::burn_from(
+					pool_id.into(),
+					&who,
+					redeemed_amount,
+				)?;
+				if !fee.is_zero() {
+					let recipient = Self::fee_recipient_account(pool, pool.retire_fee.recipient);
+					<T as pallet::Config>::AssetHandler::transfer(
+						pool_id.into(),
+						&who,
+						&recipient,
+						fee,
+						false,
+					)?;
+					Self::deposit_event(Event::PoolFeePaid {
+						pool_id,
+						who: who.clone(),
+						recipient,
+						amount: fee,
+					});
+				}
+
+				// Remove the credits from the pool
+				let (credits_taken, vintages_redeemed, projects_redeemed) =
+					Self::take_pool_credits(pool_id, pool, redeemed_amount, asset_id)?;
+
+				// transfer the CarbonCredits tokens to caller
+				for (_year, asset_id, actual) in credits_taken.iter() {
+					<T as pallet::Config>::AssetHandler::transfer(
+						*asset_id,
+						&Self::account_id(),
+						&who,
+						*actual,
+						false,
+					)?;
+				}
+
+				Self::check_pool_supply(pool_id, pool)?;
+
+				// Emit an event.
+				Self::deposit_event(Event::Redeemed {
+					who,
+					pool_id,
+					amount,
+					fee,
+					redeemed: credits_taken
+						.into_iter()
+						.map(|(_year, asset_id, actual)| (asset_id, actual))
+						.collect(),
+				});
+
+				Ok((vintages_redeemed, projects_redeemed))
+			})
+		}
+
+		/// Remove `amount` of CarbonCredits from the pool credits starting from the oldest
+		/// issuance, if `asset_id` is given only credits of that token are removed. Exhausted
+		/// entries are removed from storage.
+		/// Returns the amount removed per issuance year and token and the number of vintages and
+		/// projects touched.
+		pub fn take_pool_credits(
+			pool_id: T::PoolId,
+			pool: &mut PoolOf<T>,
+			amount: T::Balance,
+			asset_id: Option<T::AssetId>,
+		) -> Result<(Vec<(IssuanceYear, T::AssetId, T::Balance)>, u32, u32), DispatchError> {
+			let mut remaining = amount;
+			let mut credits_taken: Vec<(IssuanceYear, T::AssetId, T::Balance)> = Default::default();
+
+			// track the number of vintages and projects touched to refund unused weight
+			let mut vintages_touched: u32 = 0;
+			let mut projects_touched: u32 = 0;
+
+			let mut vintages_temp = pool.vintages.clone().into_inner();
+
+			// Take tokens starting from oldest until `amount` is taken
+			for (year, project_count) in vintages_temp.iter_mut() {
+				if remaining.is_zero() {
+					break
+				}
+
+				let credits_key = (pool_id, *year);
+				let project_credits: Vec<(T::AssetId, T::Balance)> = match asset_id {
+					Some(asset_id) => PoolCredits::<T>::get(credits_key, asset_id)
+						.map(|available_amount| vec![(asset_id, available_amount)])
+						.unwrap_or_default(),
+					None => {
+						// only read the projects needed to cover the amount, one more than the
+						// limit is enough to know the limit is reached
+						let limit = T::MaxRetireProjects::get().saturating_sub(projects_touched);
+						let mut project_credits: Vec<(T::AssetId, T::Balance)> = Default::default();
+						let mut to_cover = remaining;
+						for (project_asset_id, available_amount) in
+							PoolCredits::<T>::iter_prefix(credits_key)
+						{
+							if to_cover.is_zero() || project_credits.len() as u32 > limit {
+								break
+							}
+							to_cover = to_cover.saturating_sub(available_amount);
+							project_credits.push((project_asset_id, available_amount));
+						}
+						project_credits
+					},
+				};
+
+				let mut vintage_touched = false;
+
+				// the vintages are sorted by key (year), so take all from year before moving
+				// to next year we dont care about the project order
+				for (project_asset_id, available_amount) in project_credits.into_iter() {
+					if remaining.is_zero() {
+						break
+					}
+
+					// the amount has to be split in several calls when it spans too many projects
+					ensure!(
+						projects_touched < T::MaxRetireProjects::get(),
+						Error::<T>::RetireProjectLimitReached
+					);
+
+					vintage_touched = true;
+					projects_touched = projects_touched.saturating_add(1);
+
+					let actual =
+						if remaining <= available_amount { remaining } else { available_amount };
+
+					let remaining_credits = available_amount
+						.checked_sub(&actual)
+						.ok_or(Error::<T>::UnexpectedOverflow)?;
+
+					// this is safe since actual is <= remaining
+					remaining =
+						remaining.checked_sub(&actual).ok_or(Error::<T>::UnexpectedOverflow)?;
+
+					// remove the projects that have been exhausted
+					if remaining_credits.is_zero() {
+						PoolCredits::<T>::remove(credits_key, project_asset_id);
+						*project_count = project_count.saturating_sub(1);
+					} else {
+						PoolCredits::<T>::insert(credits_key, project_asset_id, remaining_credits);
+					}
+
+					if !actual.is_zero() {
+						credits_taken.push((*year, project_asset_id, actual));
+					}
+				}
+
+				if vintage_touched {
+					vintages_touched = vintages_touched.saturating_add(1);
+				}
+			}
+
+			// the pool credits must cover the entire amount
+			ensure!(remaining.is_zero(), Error::<T>::InsufficientPoolCredits);
+
+			// remove the years that have been exhausted
+			vintages_temp.retain(|_, project_count| *project_count > 0);
+			pool.vintages = VintagesMap::<T>::try_from(vintages_temp)
+				.map_err(|_| Error::<T>::UnexpectedOverflow)?;
+			pool.total_credits =
+				pool.total_credits.checked_sub(&amount).ok_or(Error::<T>::UnexpectedOverflow)?;
+
+			Ok((credits_taken, vintages_touched, projects_touched))
+		}
+
+		/// Ensure the credits held by the pool match the supply of pool tokens
+		pub fn check_pool_supply(pool_id: T::PoolId, pool: &PoolOf<T>) -> DispatchResult {
+			ensure!(
+				pool.total_credits ==
+					<T as pallet::Config>::AssetHandler::total_issuance(pool_id.into()),
+				Error::<T>::PoolSupplyMismatch
+			);
+			Ok(())
+		}
+
+		/// Mint the certificate NFT of a pool retirement to the beneficiary and return the item id
+		pub fn mint_retirement_certificate(
+			pool_id: T::PoolId,
+			beneficiary: &T::AccountId,
+		) -> Result<T::ItemId, DispatchError> {
+			// create the certificate collection of the pool on the first retirement
+			let item_id = match NextRetirementId::<T>::get(pool_id) {
+				None => {
+					<T as pallet_carbon_credits::Config>::NFTHandler::create_collection(
+						&pool_id.into(),
+						&Self::account_id(),
+						&Self::account_id(),
+					)?;
+					Default::default()
+				},
+				Some(x) => x,
+			};
+
+			<T as pallet_carbon_credits::Config>::NFTHandler::mint_into(
+				&pool_id.into(),
+				&item_id,
+				beneficiary,
+			)?;
+
+			let next_item_id: T::ItemId =
+				item_id.checked_add(&One::one()).ok_or(Error::<T>::UnexpectedOverflow)?;
+			NextRetirementId::<T>::insert(pool_id, next_item_id);
+
+			Ok(item_id)
+		}
+
+		/// The account receiving the fees of the pool
+		pub fn fee_recipient_account(pool: &PoolOf<T>, recipient: FeeRecipient) -> T::AccountId {
+			match recipient {
+				FeeRecipient::Admin => pool.admin.clone(),
+				FeeRecipient::Treasury => T::TreasuryAccount::get(),
+			}
+		}
+
+		/// Ensure the values of the pool config are consistent
+		pub fn check_pool_config(config: &PoolConfigOf<T>) -> DispatchResult {
+			if let (Some(min_issuance_year), Some(max_issuance_year)) =
+				(config.min_issuance_year, config.max_issuance_year)
+			{
+				ensure!(
+					min_issuance_year <= max_issuance_year,
+					Error::<T>::InvalidIssuanceYearRange
+				);
+			}
+			Ok(())
+		}
+
+		/// The total amount of CarbonCredits held by the pool in storage, used to verify the
+		/// `total_credits` of the pool
+		pub fn total_pool_credits(
+			pool_id: T::PoolId,
+			pool: &PoolOf<T>,
+		) -> Result<T::Balance, DispatchError> {
+			let mut total: T::Balance = Zero::zero();
+			for year in pool.vintages.keys() {
+				for (_asset_id, amount) in PoolCredits::<T>::iter_prefix((pool_id, *year)) {
+					total = total.checked_add(&amount).ok_or(Error::<T>::UnexpectedOverflow)?;
+				}
+			}
+			Ok(total)
+		}
+
+		/// The details and composition of the pool, used by the runtime api
+		pub fn pool_info(pool_id: T::PoolId) -> Option<PoolInfoOf<T>> {
+			let pool = Pools::<T>::get(pool_id)?;
+
+			let mut credits: Vec<(IssuanceYear, T::AssetId, T::Balance)> = Default::default();
+			let mut registries: Vec<(RegistryName, T::Balance)> = Default::default();
+			let mut sdgs: Vec<(SdgType, T::Balance)> = Default::default();
+			let mut credit_types: Vec<(CreditType, T::Balance)> = Default::default();
+
+			for year in pool.vintages.keys() {
+				for (asset_id, amount) in PoolCredits::<T>::iter_prefix((pool_id, *year)) {
+					credits.push((*year, asset_id, amount));
+
+					let project_details = pallet_carbon_credits::Pallet::<T>::asset_id_lookup(
+						asset_id,
+					)
+					.and_then(|(project_id, _)| {
+						pallet_carbon_credits::Pallet::<T>::get_project_details(project_id)
+					});
+
+					if let Some(project_details) = project_details {
+						// only projects from the same registry are approved, see `deposit`
+						if let Some(registry) = project_details.registry_details.first() {
+							Self::add_to_breakdown(
+								&mut registries,
+								registry.reg_name.clone(),
+								amount,
+							);
+						}
+						for sdg in project_details.sdg_details.iter() {
+							Self::add_to_breakdown(&mut sdgs, sdg.sdg_type.clone(), amount);
+						}
+						Self::add_to_breakdown(
+							&mut credit_types,
+							project_details.credit_type,
+							amount,
+						);
+					}
+				}
+			}
+
+			Some(PoolInfo {
+				supply: <T as pallet::Config>::AssetHandler::total_issuance(pool_id.into()),
+				admin: pool.admin,
+				config: pool.config,
+				status: pool.status,
+				deposit_fee: pool.deposit_fee,
+				retire_fee: pool.retire_fee,
+				max_limit: pool.max_limit,
+				credits,
+				registries,
+				sdgs,
+				credit_types,
+			})
+		}
+
+		/// The credits `retire` would consume for the given amount, used by the runtime api
+		pub fn retire_preview(
+			pool_id: T::PoolId,
+			amount: T::Balance,
+		) -> Option<RetirePreviewOf<T>> {
+			let mut pool = Pools::<T>::get(pool_id)?;
+
+			let fee = pool.retire_fee.fee.mul_ceil(amount);
+			let retired = amount.checked_sub(&fee)?;
+
+			// take the credits in a transaction that is rolled back, the storage is not modified
+			let (credits, _, _) = with_transaction(|| {
+				TransactionOutcome::Rollback(Self::take_pool_credits(
+					pool_id, &mut pool, retired, None,
+				))
+			})
+			.ok()?;
+
+			Some(RetirePreview { fee, retired, credits })
+		}
+
+		/// Add the amount to the entry of the key in the breakdown list
+		fn add_to_breakdown<K: PartialEq>(
+			breakdown: &mut Vec<(K, T::Balance)>,
+			key: K,
+			amount: T::Balance,
+		) {
+			match breakdown.iter_mut().find(|(entry_key, _)| *entry_key == key) {
+				Some((_, total)) => *total = total.saturating_add(amount),
+				None => breakdown.push((key, amount)),
+			}
+		}
 	}
 }
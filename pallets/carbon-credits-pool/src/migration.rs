@@ -0,0 +1,210 @@
+use super::*;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::BoundedBTreeMap;
+use primitives::IssuanceYear;
+use scale_info::TypeInfo;
+
+pub mod v1 {
+	use super::*;
+
+	use frame_support::{
+		pallet_prelude::{StorageVersion, Weight},
+		storage_alias,
+		traits::{Get, GetStorageVersion, OnRuntimeUpgrade},
+		Blake2_128Concat,
+	};
+
+	/// The pools storage with the v1 layout
+	#[storage_alias]
+	pub type Pools<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, <T as Config>::PoolId, PoolV1Of<T>>;
+
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() >= 1 {
+				log::info!(
+					"MIGRATION : carbon-credits-pool migration V1 already executed, skipping!"
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			log::info!("MIGRATION : About to execute carbon-credits-pool migration V1!");
+
+			let mut count: u64 = 0;
+
+			// existing pools do not filter by vintage, sdg or credit type and charge no fees
+			Pools::<T>::translate::<OldPoolOf<T>, _>(|_key, old| -> Option<PoolV1Of<T>> {
+				count += 1;
+				Some(PoolV1 {
+					admin: old.admin,
+					config: PoolConfig {
+						registry_list: old.config.registry_list,
+						project_id_list: old.config.project_id_list,
+						min_issuance_year: None,
+						max_issuance_year: None,
+						issuance_year_list: None,
+						required_sdgs: None,
+						credit_type: None,
+					},
+					max_limit: old.max_limit,
+					credits: old.credits,
+					status: Default::default(),
+					deposit_fee: Default::default(),
+					retire_fee: Default::default(),
+				})
+			});
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!("MIGRATION : Carbon credits pool migration V1 complete, {} pools!", count);
+
+			T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			// new version must be set.
+			assert!(Pallet::<T>::on_chain_storage_version() >= 1);
+			Ok(())
+		}
+	}
+
+	/// The configuration of a pool before vintage filters
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, Default, TypeInfo, MaxEncodedLen)]
+	pub struct OldPoolConfig<RegistryList, MaxProjectIdList> {
+		pub registry_list: Option<RegistryList>,
+		pub project_id_list: Option<MaxProjectIdList>,
+	}
+
+	pub type OldPoolConfigOf<T> = OldPoolConfig<RegistryNameList<T>, MaxProjectIdList<T>>;
+
+	/// The data stored for a pool before status and fees
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, Default, TypeInfo, MaxEncodedLen)]
+	pub struct OldPool<AccountId, PoolConfig, CreditsMap> {
+		pub admin: AccountId,
+		pub config: PoolConfig,
+		pub max_limit: u32,
+		pub credits: CreditsMap,
+	}
+
+	pub type OldPoolOf<T> =
+		OldPool<<T as frame_system::Config>::AccountId, OldPoolConfigOf<T>, CreditsMap<T>>;
+}
+
+pub mod v2 {
+	use super::*;
+
+	use frame_support::{
+		pallet_prelude::{StorageVersion, Weight},
+		traits::{Get, GetStorageVersion, OnRuntimeUpgrade},
+	};
+	use sp_runtime::traits::Zero;
+
+	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() >= 2 {
+				log::info!(
+					"MIGRATION : carbon-credits-pool migration V2 already executed, skipping!"
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			log::info!("MIGRATION : About to execute carbon-credits-pool migration V2!");
+
+			let mut count: u64 = 0;
+			let mut credits_count: u64 = 0;
+
+			// the PoolCredits map of the previous version was never written, clear any leftover
+			// entries before storing the credits with the new layout
+			let _ = PoolCredits::<T>::clear(u32::MAX, None);
+
+			// move the credits of the pools to the PoolCredits map
+			Pools::<T>::translate::<PoolV1Of<T>, _>(|pool_id, old| -> Option<PoolOf<T>> {
+				count += 1;
+
+				let mut vintages: VintagesMap<T> = Default::default();
+				let mut total_credits: T::Balance = Zero::zero();
+
+				for (year, project_map) in old.credits.into_iter() {
+					// cannot fail, `MaxPoolProjects` is not lower than the bound of the old map
+					vintages.try_insert(year, project_map.len() as u32).ok()?;
+
+					for (asset_id, amount) in project_map.into_iter() {
+						credits_count += 1;
+						total_credits = total_credits.saturating_add(amount);
+						PoolCredits::<T>::insert((pool_id, year), asset_id, amount);
+					}
+				}
+
+				// the old max limit applied to every vintage, the pools can now hold up to the
+				// total limit of projects
+				Some(Pool {
+					admin: old.admin,
+					config: old.config,
+					max_limit: T::MaxPoolProjects::get(),
+					vintages,
+					total_credits,
+					status: old.status,
+					deposit_fee: old.deposit_fee,
+					retire_fee: old.retire_fee,
+				})
+			});
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!(
+				"MIGRATION : Carbon credits pool migration V2 complete, {} pools, {} credits!",
+				count,
+				credits_count
+			);
+
+			T::DbWeight::get().reads_writes(
+				count.saturating_add(1),
+				count.saturating_add(credits_count).saturating_add(1),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			// new version must be set.
+			assert_eq!(Pallet::<T>::on_chain_storage_version(), 2);
+			// the credits in the pools must match the supply of pool tokens
+			for (pool_id, pool) in Pools::<T>::iter() {
+				assert_eq!(Pallet::<T>::total_pool_credits(pool_id, &pool)?, pool.total_credits);
+				Pallet::<T>::check_pool_supply(pool_id, &pool)?;
+			}
+			Ok(())
+		}
+	}
+}
+
+/// Map storing the details of a given project in a pool before v2
+/// ProjectId => Amount of tokens in pool
+pub type ProjectDetails<T> = BoundedBTreeMap<
+	<T as pallet_carbon_credits::Config>::AssetId,
+	<T as pallet_carbon_credits::Config>::Balance,
+	<T as Config>::MaxProjectIdList,
+>;
+
+/// Map storing the available credits in the pool by issuance year before v2
+/// IssuanceYear => ProjectDetail
+pub type CreditsMap<T> =
+	BoundedBTreeMap<IssuanceYear, ProjectDetails<T>, <T as Config>::MaxProjectIdList>;
+
+/// The data stored for a pool before v2, the credits were stored in the pool
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, Default, TypeInfo, MaxEncodedLen)]
+pub struct PoolV1<AccountId, PoolConfig, CreditsMap> {
+	pub admin: AccountId,
+	pub config: PoolConfig,
+	pub max_limit: u32,
+	pub credits: CreditsMap,
+	pub status: PoolStatus,
+	pub deposit_fee: PoolFee,
+	pub retire_fee: PoolFee,
+}
+
+/// Type of pool before v2
+pub type PoolV1Of<T> =
+	PoolV1<<T as frame_system::Config>::AccountId, PoolConfigOf<T>, CreditsMap<T>>;
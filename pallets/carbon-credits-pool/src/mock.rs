@@ -5,16 +5,18 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	bounded_vec, parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, Everything, GenesisBuild},
+	traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, Contains, Everything, GenesisBuild},
 	PalletId,
 };
 use frame_system as system;
 use frame_system::EnsureRoot;
+use primitives::{CountryCode, JurisdictionProvider, KycLevel, KycLevelProvider};
 use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
+	Percent,
 };
 use sp_std::convert::{TryFrom, TryInto};
 
@@ -109,6 +111,7 @@ impl pallet_assets::Config for Test {
 	type Extra = ();
 	type ForceOrigin = frame_system::EnsureRoot<u64>;
 	type Freezer = ();
+	type Compliance = ();
 	type MetadataDepositBase = MetadataDepositBase;
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type StringLimit = ConstU32<50>;
@@ -126,30 +129,66 @@ impl pallet_timestamp::Config for Test {
 	type WeightInfo = ();
 }
 
+/// KYC provider for tests, the members of `KYCMembership` are verified at the institutional level
+pub struct MockKycProvider;
+impl KycLevelProvider<u64> for MockKycProvider {
+	fn kyc_level(who: &u64) -> Option<KycLevel> {
+		KYCMembership::contains(who).then_some(KycLevel::Institutional)
+	}
+}
+
+impl JurisdictionProvider<u64> for MockKycProvider {
+	fn country_code(_who: &u64) -> Option<CountryCode> {
+		None
+	}
+
+	fn is_jurisdiction_blocked(_country_code: &CountryCode) -> bool {
+		false
+	}
+}
+
+/// Benchmark helper for tests, every verified account is added to `KYCMembership`
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_carbon_credits::BenchmarkHelper<u64> for MockBenchmarkHelper {
+	fn set_kyc_level(who: &u64, _level: KycLevel) {
+		let _ = KYCMembership::add_member(RuntimeOrigin::root(), *who);
+	}
+}
+
 parameter_types! {
   pub const MarketplaceEscrowAccount : u64 = 10;
   pub const CarbonCreditsPalletId: PalletId = PalletId(*b"bitg/ccp");
   pub CarbonCreditsPalletAcccount : u64 = PalletId(*b"bitg/ccp").into_account_truncating();
   #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, MaxEncodedLen, TypeInfo, Debug)]
   pub const MaxGroupSize: u32 = 10;
+  pub const ProjectKycLevel: KycLevel = KycLevel::Institutional;
+  pub const RetireKycLevel: KycLevel = KycLevel::Basic;
 }
 
 impl pallet_carbon_credits::Config for Test {
 	type AssetHandler = Assets;
 	type AssetId = u32;
 	type Balance = u128;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockBenchmarkHelper;
 	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type DepositSlashed = ();
 	type ForceOrigin = frame_system::EnsureRoot<u64>;
 	type ItemId = u32;
 	type ProjectId = u32;
 	type MaxCoordinatesLength = ConstU32<8>;
 	type GroupId = u32;
-	type KYCProvider = KYCMembership;
+	type KYCProvider = MockKycProvider;
 	type MarketplaceEscrow = MarketplaceEscrowAccount;
 	type MaxCoordinatesLength = ConstU32<8>;
 	type MaxAuthorizedAccountCount = ConstU32<2>;
 	type MaxDocumentCount = ConstU32<2>;
 	type MaxGroupSize = MaxGroupSize;
+	type MaxMonitoringReports = ConstU32<2>;
+	type MaxProjectRevisions = ConstU32<2>;
 	type MaxIpfsReferenceLength = ConstU32<20>;
 	type MaxLongStringLength = ConstU32<100>;
 	type MaxRoyaltyRecipients = ConstU32<5>;
@@ -157,6 +196,10 @@ impl pallet_carbon_credits::Config for Test {
 	type MinProjectId = ConstU32<1000>;
 	type NFTHandler = Uniques;
 	type PalletId = CarbonCreditsPalletId;
+	type ProjectDepositBase = ConstU128<0>;
+	type ProjectDepositPerByte = ConstU128<0>;
+	type ProjectKycLevel = ProjectKycLevel;
+	type RetireKycLevel = RetireKycLevel;
 	type WeightInfo = ();
 }
 
@@ -194,6 +237,9 @@ impl pallet_membership::Config for Test {
 
 parameter_types! {
 	pub const CarbonCreditPoolsPalletId: PalletId = PalletId(*b"bit/ccpp");
+	pub const MaxRedemptionFee: Percent = Percent::from_percent(10);
+	pub const MaxPoolFee: Percent = Percent::from_percent(10);
+	pub const PoolTreasuryAccount: u64 = 20;
 }
 
 impl pallet_carbon_credits_pool::Config for Test {
@@ -203,10 +249,15 @@ impl pallet_carbon_credits_pool::Config for Test {
 	type MaxAssetSymbolLength = ConstU32<20>;
 	type MaxIssuanceYearCount = ConstU32<20>;
 	type MaxProjectIdList = ConstU32<100>;
+	type MaxPoolProjects = ConstU32<200>;
+	type MaxRetireProjects = ConstU32<5>;
+	type MaxPoolFee = MaxPoolFee;
+	type MaxRedemptionFee = MaxRedemptionFee;
 	type MaxRegistryListCount = ConstU32<2>;
 	type MinPoolId = ConstU32<10000>;
 	type PalletId = CarbonCreditPoolsPalletId;
 	type PoolId = u32;
+	type TreasuryAccount = PoolTreasuryAccount;
 	type WeightInfo = ();
 }
 
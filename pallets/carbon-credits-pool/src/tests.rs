@@ -3,7 +3,10 @@
 // This code is licensed under MIT license (see LICENSE.txt for details)
 //
 //! Tests for CarbonCredits pool pallet
-use crate::{mock::*, Config, Error, Pools};
+use crate::{
+	mock::*, Config, Error, FeeRecipient, PoolConfigOf, PoolCredits, PoolFee, PoolRetirementRecord,
+	PoolRetirements, PoolStatus, Pools, RetirePreview, RetirementBeneficiary, WeightInfo,
+};
 use frame_support::{
 	assert_noop, assert_ok,
 	traits::tokens::fungibles::{metadata::Inspect as MetadataInspect, Inspect},
@@ -11,9 +14,13 @@ use frame_support::{
 };
 use frame_system::RawOrigin;
 use pallet_carbon_credits::{
-	BatchGroupListOf, BatchGroupOf, BatchOf, ProjectCreateParams, RegistryListOf, SDGTypesListOf,
+	BatchGroupListOf, BatchGroupOf, BatchOf, LocationOf, ProjectCreateParams, RegistryListOf,
+	SDGTypesListOf,
+};
+use primitives::{
+	Batch, BatchStatus, Coordinate, CreditType, Geometry, Location, RegistryDetails, RegistryName,
+	Royalty, SDGDetails, SdgType,
 };
-use primitives::{Batch, RegistryDetails, RegistryName, Royalty, SDGDetails, SdgType};
 use sp_runtime::Percent;
 use sp_std::convert::TryInto;
 
@@ -53,6 +60,7 @@ fn get_single_batch_list<T: Config>() -> BoundedVec<BatchOf<T>, T::MaxGroupSize>
 		total_supply: 100_u32.into(),
 		minted: 0_u32.into(),
 		retired: 0_u32.into(),
+		status: BatchStatus::Verified,
 	}]
 	.try_into()
 	.unwrap()
@@ -69,6 +77,7 @@ fn get_multiple_batch_list<T: Config>() -> BoundedVec<BatchOf<T>, T::MaxGroupSiz
 			total_supply: 100_u32.into(),
 			minted: 0_u32.into(),
 			retired: 0_u32.into(),
+			status: BatchStatus::Verified,
 		},
 		Batch {
 			name: "batch_name_2".as_bytes().to_vec().try_into().unwrap(),
@@ -79,6 +88,7 @@ fn get_multiple_batch_list<T: Config>() -> BoundedVec<BatchOf<T>, T::MaxGroupSiz
 			total_supply: 100_u32.into(),
 			minted: 0_u32.into(),
 			retired: 0_u32.into(),
+			status: BatchStatus::Verified,
 		},
 	]
 	.try_into()
@@ -121,6 +131,24 @@ where
 	.unwrap()
 }
 
+/// helper function to generate standard location
+fn get_default_location<T: Config>() -> LocationOf<T> {
+	Location {
+		geometry: Geometry::Polygon(
+			vec![
+				Coordinate { latitude: 1_000_000, longitude: 1_000_000 },
+				Coordinate { latitude: 1_000_000, longitude: 2_000_000 },
+				Coordinate { latitude: 2_000_000, longitude: 2_000_000 },
+				Coordinate { latitude: 2_000_000, longitude: 1_000_000 },
+			]
+			.try_into()
+			.unwrap(),
+		),
+		country: Some(*b"BR"),
+		region: Some("region".as_bytes().to_vec().try_into().unwrap()),
+	}
+}
+
 /// helper function to generate standard creation details
 fn get_default_creation_params<T: Config>() -> ProjectCreateParams<T>
 where
@@ -134,7 +162,7 @@ where
 	let creation_params = ProjectCreateParams {
 		name: "name".as_bytes().to_vec().try_into().unwrap(),
 		description: "description".as_bytes().to_vec().try_into().unwrap(),
-		location: "(1, 1), (2, 2), (3, 3), (4, 4)".as_bytes().to_vec().try_into().unwrap(),
+		location: get_default_location::<T>(),
 		images: vec!["image_link".as_bytes().to_vec().try_into().unwrap()].try_into().unwrap(),
 		videos: vec!["video_link".as_bytes().to_vec().try_into().unwrap()].try_into().unwrap(),
 		documents: vec!["document_link".as_bytes().to_vec().try_into().unwrap()]
@@ -142,6 +170,7 @@ where
 			.unwrap(),
 		registry_details: get_default_registry_details::<T>(),
 		sdg_details: get_default_sdg_details::<T>(),
+		credit_type: CreditType::Avoidance,
 		royalties: Some(vec![royalty].try_into().unwrap()),
 		batch_groups: get_default_batch_group::<T>(),
 	};
@@ -285,6 +314,17 @@ fn deposit_works() {
 			Error::<Test>::InvalidAmount
 		);
 
+		// only carbon credits assets can be deposited
+		assert_noop!(
+			CarbonCreditPools::deposit(
+				RawOrigin::Signed(authorised_account_one).into(),
+				pool_id,
+				asset_id + 100,
+				project_tokens_to_deposit
+			),
+			Error::<Test>::InvalidAssetId
+		);
+
 		// deposit to pool should work
 		assert_ok!(CarbonCreditPools::deposit(
 			RawOrigin::Signed(authorised_account_one).into(),
@@ -317,9 +357,12 @@ fn deposit_works() {
 
 		// ensure storage updated correctly
 		let stored_pool = Pools::<Test>::get(pool_id).unwrap();
-		let stored_issuance_map = stored_pool.credits.get(&2020).unwrap();
-		let amount = stored_issuance_map.get(&project_id).unwrap();
-		assert_eq!(amount, &project_tokens_to_deposit);
+		assert_eq!(stored_pool.vintages.get(&2020), Some(&1));
+		assert_eq!(stored_pool.total_credits, project_tokens_to_deposit);
+		assert_eq!(
+			PoolCredits::<Test>::get((pool_id, 2020), project_id),
+			Some(project_tokens_to_deposit)
+		);
 	});
 }
 
@@ -376,9 +419,11 @@ fn deposit_works_for_batch_credits() {
 		// ensure storage updated correctly
 		let stored_pool = Pools::<Test>::get(pool_id).unwrap();
 		// the issuance date is the issuance date of oldest batch
-		let stored_issuance_map = stored_pool.credits.get(&2020).unwrap();
-		let amount = stored_issuance_map.get(&project_id).unwrap();
-		assert_eq!(amount, &project_tokens_to_deposit);
+		assert_eq!(stored_pool.vintages.get(&2020), Some(&1));
+		assert_eq!(
+			PoolCredits::<Test>::get((pool_id, 2020), project_id),
+			Some(project_tokens_to_deposit)
+		);
 	});
 }
 
@@ -423,34 +468,67 @@ fn retire_works() {
 		// The depositor should have gained equal pool tokens
 		assert_eq!(Assets::balance(pool_id, authorised_account_one), project_tokens_to_deposit);
 
-		// retire more than balance should fail
+		// retire more than pool credits should fail
 		assert_noop!(
 			CarbonCreditPools::retire(
 				RawOrigin::Signed(authorised_account_one).into(),
 				pool_id,
-				10_000
+				10_000,
+				None
 			),
+			Error::<Test>::InsufficientPoolCredits
+		);
+
+		// retire more than balance should fail
+		assert_ok!(Assets::transfer(
+			RawOrigin::Signed(authorised_account_one).into(),
+			pool_id,
+			3,
+			1
+		));
+		assert_noop!(
+			CarbonCreditPools::retire(RawOrigin::Signed(3).into(), pool_id, 2, None),
 			pallet_assets::Error::<Test>::BalanceLow
 		);
+		assert_ok!(Assets::transfer(
+			RawOrigin::Signed(3).into(),
+			pool_id,
+			authorised_account_one,
+			1
+		));
 
 		assert_noop!(
-			CarbonCreditPools::retire(RawOrigin::Signed(authorised_account_one).into(), pool_id, 0),
+			CarbonCreditPools::retire(
+				RawOrigin::Signed(authorised_account_one).into(),
+				pool_id,
+				0,
+				None
+			),
 			Error::<Test>::InvalidAmount
 		);
 
-		// retire should work
-		assert_ok!(CarbonCreditPools::retire(
+		// retire should work and refund the weight of unused entries
+		let post_info = CarbonCreditPools::retire(
 			RawOrigin::Signed(authorised_account_one).into(),
 			pool_id,
-			90
-		));
+			90,
+			None,
+		)
+		.unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::retire(1, 1)));
 
 		// assert_eq!(Assets::total_issuance(asset_id), 0);
 
 		assert_eq!(
 			last_event(),
-			CarbonCreditPoolEvent::Retired { who: authorised_account_one, pool_id, amount: 90 }
-				.into()
+			CarbonCreditPoolEvent::Retired {
+				who: authorised_account_one,
+				pool_id,
+				amount: 90,
+				retirement_id: 0,
+				beneficiary: authorised_account_one
+			}
+			.into()
 		);
 
 		// the caller should have lost equivalent pool tokens
@@ -459,9 +537,8 @@ fn retire_works() {
 
 		// ensure accounting worked correctly
 		let stored_pool = Pools::<Test>::get(pool_id).unwrap();
-		let stored_issuance_map = stored_pool.credits.get(&2020).unwrap();
-		let amount = stored_issuance_map.get(&project_id).unwrap();
-		assert_eq!(amount, &9_u128);
+		assert_eq!(stored_pool.total_credits, 9_u128);
+		assert_eq!(PoolCredits::<Test>::get((pool_id, 2020), project_id), Some(9_u128));
 
 		// the equivalent project tokens should have been retired
 		let stored_data =
@@ -470,3 +547,1197 @@ fn retire_works() {
 		assert_eq!(stored_data.batch_groups.get(&group_id).unwrap().retired, 90_u32.into());
 	});
 }
+
+/// helper function to create a project with credits of the given issuance year
+fn create_project_with_issuance_year(
+	originator_account: u64,
+	authorised_account: u64,
+	issuance_year: u16,
+	amount_to_mint: u128,
+) -> u32 {
+	let project_id = CarbonCredits::next_project_id();
+	let asset_id = CarbonCredits::next_asset_id();
+
+	let mut creation_params = get_default_creation_params::<Test>();
+	let mut batch_group = creation_params.batch_groups[0].clone();
+	let mut batches = batch_group.batches.clone().into_inner();
+	batches[0].issuance_year = issuance_year;
+	batches[0].start_date = issuance_year;
+	batches[0].end_date = issuance_year;
+	batch_group.batches = batches.try_into().unwrap();
+	creation_params.batch_groups = vec![batch_group].try_into().unwrap();
+
+	assert_ok!(CarbonCredits::create(
+		RawOrigin::Signed(originator_account).into(),
+		creation_params
+	));
+	assert_ok!(CarbonCredits::approve_project(
+		RawOrigin::Signed(authorised_account).into(),
+		project_id,
+		true
+	));
+	assert_ok!(CarbonCredits::mint(
+		RawOrigin::Signed(authorised_account).into(),
+		project_id,
+		0,
+		amount_to_mint,
+		false
+	));
+
+	asset_id
+}
+
+/// helper function to check the pool accounting invariants
+fn check_pool_invariants(pool_id: u32) {
+	let pool = Pools::<Test>::get(pool_id).unwrap();
+
+	// the credits in the pool match the supply of pool tokens
+	let total_credits = CarbonCreditPools::total_pool_credits(pool_id, &pool).unwrap();
+	assert_eq!(total_credits, pool.total_credits);
+	assert_eq!(total_credits, Assets::total_issuance(pool_id));
+
+	for (year, project_count) in pool.vintages.iter() {
+		// no empty vintage is stored
+		assert!(*project_count > 0);
+		let project_credits: Vec<_> = PoolCredits::<Test>::iter_prefix((pool_id, *year)).collect();
+		assert_eq!(project_credits.len() as u32, *project_count);
+		for (asset_id, amount) in project_credits {
+			// no exhausted project is stored
+			assert!(amount > 0);
+			// the pool account holds the credits
+			assert_eq!(Assets::balance(asset_id, CarbonCreditPools::account_id()), amount);
+		}
+	}
+}
+
+#[test]
+fn retire_removes_exhausted_entries() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 1;
+		let authorised_account = 10;
+		let pool_id = 10_000;
+
+		assert_ok!(CarbonCreditPools::create(
+			RawOrigin::Root.into(),
+			pool_id,
+			originator_account,
+			Default::default(),
+			None,
+			"pool_xyz".as_bytes().to_vec().try_into().unwrap(),
+		));
+		assert_ok!(CarbonCredits::force_add_authorized_account(
+			RawOrigin::Root.into(),
+			authorised_account
+		));
+
+		let old_asset_id =
+			create_project_with_issuance_year(originator_account, authorised_account, 2018, 100);
+		let new_asset_id =
+			create_project_with_issuance_year(originator_account, authorised_account, 2020, 100);
+
+		assert_ok!(CarbonCreditPools::deposit(
+			RawOrigin::Signed(originator_account).into(),
+			pool_id,
+			new_asset_id,
+			50
+		));
+		assert_ok!(CarbonCreditPools::deposit(
+			RawOrigin::Signed(originator_account).into(),
+			pool_id,
+			old_asset_id,
+			30
+		));
+
+		// retire the older vintage completely and part of the newer vintage
+		assert_ok!(CarbonCreditPools::retire(
+			RawOrigin::Signed(originator_account).into(),
+			pool_id,
+			40,
+			None
+		));
+
+		let stored_pool = Pools::<Test>::get(pool_id).unwrap();
+		assert!(stored_pool.vintages.get(&2018).is_none());
+		assert_eq!(PoolCredits::<Test>::get((pool_id, 2018), old_asset_id), None);
+		assert_eq!(PoolCredits::<Test>::get((pool_id, 2020), new_asset_id), Some(40));
+		check_pool_invariants(pool_id);
+
+		// retire the rest of the pool
+		assert_ok!(CarbonCreditPools::retire(
+			RawOrigin::Signed(originator_account).into(),
+			pool_id,
+			40,
+			None
+		));
+		assert!(Pools::<Test>::get(pool_id).unwrap().vintages.is_empty());
+		check_pool_invariants(pool_id);
+
+		// cannot retire from an empty pool
+		assert_noop!(
+			CarbonCreditPools::retire(
+				RawOrigin::Signed(originator_account).into(),
+				pool_id,
+				1,
+				None
+			),
+			Error::<Test>::InsufficientPoolCredits
+		);
+	});
+}
+
+#[test]
+fn retire_preserves_pool_invariants_for_random_sequences() {
+	// simple linear congruential generator to keep the sequence deterministic
+	fn next_random(seed: &mut u64) -> u64 {
+		*seed = seed
+			.wrapping_mul(6_364_136_223_846_793_005)
+			.wrapping_add(1_442_695_040_888_963_407);
+		*seed >> 33
+	}
+
+	for mut seed in 0..10_u64 {
+		new_test_ext().execute_with(|| {
+			let originator_account = 1;
+			let authorised_account = 10;
+			let pool_id = 10_000;
+			let credits_per_project = 100;
+
+			assert_ok!(CarbonCreditPools::create(
+				RawOrigin::Root.into(),
+				pool_id,
+				originator_account,
+				Default::default(),
+				None,
+				"pool_xyz".as_bytes().to_vec().try_into().unwrap(),
+			));
+			assert_ok!(CarbonCredits::force_add_authorized_account(
+				RawOrigin::Root.into(),
+				authorised_account
+			));
+
+			// projects of different vintages, with repeated vintages
+			let asset_ids: Vec<u32> = [2019, 2017, 2019, 2021, 2018]
+				.iter()
+				.map(|issuance_year| {
+					create_project_with_issuance_year(
+						originator_account,
+						authorised_account,
+						*issuance_year,
+						credits_per_project,
+					)
+				})
+				.collect();
+
+			let mut total_retired = 0;
+			for _ in 0..50 {
+				let pool_credits = Assets::total_issuance(pool_id);
+				if next_random(&mut seed) % 2 == 0 {
+					let asset_id = asset_ids[(next_random(&mut seed) % 5) as usize];
+					let balance = Assets::balance(asset_id, originator_account);
+					if balance == 0 {
+						continue
+					}
+					let amount = (next_random(&mut seed) as u128 % balance) + 1;
+					assert_ok!(CarbonCreditPools::deposit(
+						RawOrigin::Signed(originator_account).into(),
+						pool_id,
+						asset_id,
+						amount
+					));
+				} else {
+					let amount = (next_random(&mut seed) as u128 % (pool_credits + 10)) + 1;
+					if amount > pool_credits {
+						assert_noop!(
+							CarbonCreditPools::retire(
+								RawOrigin::Signed(originator_account).into(),
+								pool_id,
+								amount,
+								None
+							),
+							Error::<Test>::InsufficientPoolCredits
+						);
+					} else {
+						assert_ok!(CarbonCreditPools::retire(
+							RawOrigin::Signed(originator_account).into(),
+							pool_id,
+							amount,
+							None
+						));
+						total_retired += amount;
+					}
+				}
+
+				check_pool_invariants(pool_id);
+			}
+
+			// the retired amount is accounted in the projects
+			let project_retired: u128 = (0..5)
+				.map(|project_id| {
+					let project = CarbonCredits::get_project_details(project_id).unwrap();
+					project.batch_groups.get(&0).unwrap().retired
+				})
+				.sum();
+			assert_eq!(project_retired, total_retired);
+		});
+	}
+}
+
+#[test]
+fn deposit_checks_pool_config_filters() {
+	new_test_ext().execute_with(|| {
+		let authorised_account_one = 1;
+		let asset_id = 0;
+
+		// the project has 2020 vintage avoidance credits contributing to LifeOnLand
+		create_project_and_mint::<Test>(authorised_account_one, 100, false);
+
+		let create_pool = |pool_id: u32, config: PoolConfigOf<Test>| {
+			CarbonCreditPools::create(
+				RawOrigin::Root.into(),
+				pool_id,
+				authorised_account_one,
+				config,
+				None,
+				"pool_xyz".as_bytes().to_vec().try_into().unwrap(),
+			)
+		};
+
+		// min issuance year cannot be greater than max issuance year
+		assert_noop!(
+			create_pool(
+				10_000,
+				PoolConfigOf::<Test> {
+					min_issuance_year: Some(2021),
+					max_issuance_year: Some(2020),
+					..Default::default()
+				}
+			),
+			Error::<Test>::InvalidIssuanceYearRange
+		);
+
+		let rejected_configs = vec![
+			(
+				PoolConfigOf::<Test> { min_issuance_year: Some(2021), ..Default::default() },
+				Error::<Test>::IssuanceYearBelowMinimum,
+			),
+			(
+				PoolConfigOf::<Test> { max_issuance_year: Some(2019), ..Default::default() },
+				Error::<Test>::IssuanceYearAboveMaximum,
+			),
+			(
+				PoolConfigOf::<Test> {
+					issuance_year_list: Some(vec![2018, 2019].try_into().unwrap()),
+					..Default::default()
+				},
+				Error::<Test>::IssuanceYearNotPermitted,
+			),
+			(
+				PoolConfigOf::<Test> {
+					required_sdgs: Some(
+						vec![SdgType::LifeOnLand, SdgType::ClimateAction].try_into().unwrap(),
+					),
+					..Default::default()
+				},
+				Error::<Test>::SdgRequirementNotMet,
+			),
+			(
+				PoolConfigOf::<Test> {
+					credit_type: Some(CreditType::Removal),
+					..Default::default()
+				},
+				Error::<Test>::CreditTypeNotPermitted,
+			),
+		];
+
+		for (index, (config, error)) in rejected_configs.into_iter().enumerate() {
+			let pool_id = 10_000 + index as u32;
+			assert_ok!(create_pool(pool_id, config));
+			assert_noop!(
+				CarbonCreditPools::deposit(
+					RawOrigin::Signed(authorised_account_one).into(),
+					pool_id,
+					asset_id,
+					10
+				),
+				error
+			);
+		}
+
+		// a pool for 2020+ vintage avoidance credits accepts the project
+		let pool_id = 20_000;
+		assert_ok!(create_pool(
+			pool_id,
+			PoolConfigOf::<Test> {
+				min_issuance_year: Some(2020),
+				max_issuance_year: Some(2030),
+				issuance_year_list: Some(vec![2020, 2021].try_into().unwrap()),
+				required_sdgs: Some(vec![SdgType::LifeOnLand].try_into().unwrap()),
+				credit_type: Some(CreditType::Avoidance),
+				..Default::default()
+			}
+		));
+		assert_ok!(CarbonCreditPools::deposit(
+			RawOrigin::Signed(authorised_account_one).into(),
+			pool_id,
+			asset_id,
+			10
+		));
+		assert_eq!(Assets::balance(pool_id, authorised_account_one), 10);
+	});
+}
+
+#[test]
+fn redeem_works() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 1;
+		let pool_admin = 3;
+		let authorised_account = 10;
+		let pool_id = 10_000;
+
+		assert_ok!(CarbonCreditPools::create(
+			RawOrigin::Root.into(),
+			pool_id,
+			pool_admin,
+			Default::default(),
+			None,
+			"pool_xyz".as_bytes().to_vec().try_into().unwrap(),
+		));
+		assert_ok!(CarbonCredits::force_add_authorized_account(
+			RawOrigin::Root.into(),
+			authorised_account
+		));
+
+		let old_asset_id =
+			create_project_with_issuance_year(originator_account, authorised_account, 2018, 100);
+		let new_asset_id =
+			create_project_with_issuance_year(originator_account, authorised_account, 2020, 100);
+
+		for asset_id in [old_asset_id, new_asset_id] {
+			assert_ok!(CarbonCreditPools::deposit(
+				RawOrigin::Signed(originator_account).into(),
+				pool_id,
+				asset_id,
+				50
+			));
+		}
+
+		// only ForceOrigin can set the redemption fee, upto the max fee
+		assert_noop!(
+			CarbonCreditPools::force_set_redemption_fee(
+				RawOrigin::Signed(originator_account).into(),
+				Percent::from_percent(10)
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			CarbonCreditPools::force_set_redemption_fee(
+				RawOrigin::Root.into(),
+				Percent::from_percent(20)
+			),
+			Error::<Test>::CannotSetMoreThanMaxRedemptionFee
+		);
+		assert_ok!(CarbonCreditPools::force_set_redemption_fee(
+			RawOrigin::Root.into(),
+			Percent::from_percent(10)
+		));
+		assert_eq!(CarbonCreditPools::redemption_fee(), Percent::from_percent(10));
+
+		// the amount has to cover the fee
+		assert_noop!(
+			CarbonCreditPools::redeem(
+				RawOrigin::Signed(originator_account).into(),
+				pool_id,
+				new_asset_id,
+				1
+			),
+			Error::<Test>::InvalidAmount
+		);
+
+		// redeem the newer credits from the pool
+		assert_ok!(CarbonCreditPools::redeem(
+			RawOrigin::Signed(originator_account).into(),
+			pool_id,
+			new_asset_id,
+			20
+		));
+		assert_eq!(
+			last_event(),
+			CarbonCreditPoolEvent::Redeemed {
+				who: originator_account,
+				pool_id,
+				amount: 20,
+				fee: 2,
+				redeemed: vec![(new_asset_id, 18)]
+			}
+			.into()
+		);
+
+		// the fee is paid in pool tokens to the recipient of the retire fee, the admin by default
+		assert_eq!(Assets::balance(pool_id, originator_account), 80);
+		assert_eq!(Assets::balance(pool_id, pool_admin), 2);
+		assert_eq!(Assets::balance(new_asset_id, originator_account), 68);
+		let stored_pool = Pools::<Test>::get(pool_id).unwrap();
+		assert_eq!(PoolCredits::<Test>::get((pool_id, 2020), new_asset_id), Some(32));
+		check_pool_invariants(pool_id);
+
+		// cannot redeem more than the pool holds of the token
+		assert_noop!(
+			CarbonCreditPools::redeem(
+				RawOrigin::Signed(originator_account).into(),
+				pool_id,
+				new_asset_id,
+				40
+			),
+			Error::<Test>::InsufficientPoolCredits
+		);
+
+		// redeem the oldest credits from the pool
+		assert_ok!(CarbonCreditPools::redeem_oldest(
+			RawOrigin::Signed(originator_account).into(),
+			pool_id,
+			60
+		));
+		assert_eq!(
+			last_event(),
+			CarbonCreditPoolEvent::Redeemed {
+				who: originator_account,
+				pool_id,
+				amount: 60,
+				fee: 6,
+				redeemed: vec![(old_asset_id, 50), (new_asset_id, 4)]
+			}
+			.into()
+		);
+		assert_eq!(Assets::balance(old_asset_id, originator_account), 100);
+		assert_eq!(Assets::balance(new_asset_id, originator_account), 72);
+
+		// the exhausted vintage is removed from the pool
+		let stored_pool = Pools::<Test>::get(pool_id).unwrap();
+		assert!(stored_pool.vintages.get(&2018).is_none());
+		assert_eq!(PoolCredits::<Test>::get((pool_id, 2018), old_asset_id), None);
+		check_pool_invariants(pool_id);
+
+		assert_noop!(
+			CarbonCreditPools::redeem(
+				RawOrigin::Signed(originator_account).into(),
+				pool_id,
+				old_asset_id,
+				10
+			),
+			Error::<Test>::InsufficientPoolCredits
+		);
+
+		// redeemed credits are not retired
+		let project = CarbonCredits::get_project_details(0).unwrap();
+		assert_eq!(project.batch_groups.get(&0).unwrap().retired, 0);
+	});
+}
+
+#[test]
+fn pool_admin_functions_work() {
+	new_test_ext().execute_with(|| {
+		let pool_admin = 3;
+		let new_admin = 1;
+		let pool_id = 10_000;
+
+		assert_ok!(CarbonCreditPools::create(
+			RawOrigin::Root.into(),
+			pool_id,
+			pool_admin,
+			Default::default(),
+			None,
+			"pool_xyz".as_bytes().to_vec().try_into().unwrap(),
+		));
+
+		let config =
+			PoolConfigOf::<Test> { credit_type: Some(CreditType::Avoidance), ..Default::default() };
+
+		// only the pool admin can manage the pool
+		assert_noop!(
+			CarbonCreditPools::update_config(
+				RawOrigin::Signed(new_admin).into(),
+				pool_id,
+				config.clone()
+			),
+			Error::<Test>::NotPoolAdmin
+		);
+		assert_noop!(
+			CarbonCreditPools::transfer_admin(
+				RawOrigin::Signed(new_admin).into(),
+				pool_id,
+				new_admin
+			),
+			Error::<Test>::NotPoolAdmin
+		);
+		assert_noop!(
+			CarbonCreditPools::set_pool_status(
+				RawOrigin::Signed(new_admin).into(),
+				pool_id,
+				PoolStatus::Paused
+			),
+			Error::<Test>::NotPoolAdmin
+		);
+		assert_noop!(
+			CarbonCreditPools::set_deposit_fee(
+				RawOrigin::Signed(new_admin).into(),
+				pool_id,
+				Default::default()
+			),
+			Error::<Test>::NotPoolAdmin
+		);
+		assert_noop!(
+			CarbonCreditPools::update_config(
+				RawOrigin::Signed(pool_admin).into(),
+				pool_id + 1,
+				config.clone()
+			),
+			Error::<Test>::InvalidPoolId
+		);
+
+		// the new config is validated
+		assert_noop!(
+			CarbonCreditPools::update_config(
+				RawOrigin::Signed(pool_admin).into(),
+				pool_id,
+				PoolConfigOf::<Test> {
+					min_issuance_year: Some(2020),
+					max_issuance_year: Some(2018),
+					..Default::default()
+				}
+			),
+			Error::<Test>::InvalidIssuanceYearRange
+		);
+
+		assert_ok!(CarbonCreditPools::update_config(
+			RawOrigin::Signed(pool_admin).into(),
+			pool_id,
+			config.clone()
+		));
+		assert_eq!(Pools::<Test>::get(pool_id).unwrap().config, config.clone());
+		assert_eq!(
+			last_event(),
+			CarbonCreditPoolEvent::PoolConfigUpdated { pool_id, config }.into()
+		);
+
+		assert_ok!(CarbonCreditPools::set_pool_status(
+			RawOrigin::Signed(pool_admin).into(),
+			pool_id,
+			PoolStatus::DepositsPaused
+		));
+		assert_eq!(Pools::<Test>::get(pool_id).unwrap().status, PoolStatus::DepositsPaused);
+		assert_eq!(
+			last_event(),
+			CarbonCreditPoolEvent::PoolStatusUpdated {
+				pool_id,
+				status: PoolStatus::DepositsPaused
+			}
+			.into()
+		);
+
+		// the fees cannot exceed the max pool fee
+		let fee = PoolFee { fee: Percent::from_percent(20), recipient: FeeRecipient::Treasury };
+		assert_noop!(
+			CarbonCreditPools::set_deposit_fee(RawOrigin::Signed(pool_admin).into(), pool_id, fee),
+			Error::<Test>::CannotSetMoreThanMaxPoolFee
+		);
+		assert_noop!(
+			CarbonCreditPools::set_retire_fee(RawOrigin::Signed(pool_admin).into(), pool_id, fee),
+			Error::<Test>::CannotSetMoreThanMaxPoolFee
+		);
+
+		let fee = PoolFee { fee: Percent::from_percent(5), recipient: FeeRecipient::Treasury };
+		assert_ok!(CarbonCreditPools::set_deposit_fee(
+			RawOrigin::Signed(pool_admin).into(),
+			pool_id,
+			fee
+		));
+		assert_eq!(
+			last_event(),
+			CarbonCreditPoolEvent::DepositFeeSet { pool_id, deposit_fee: fee }.into()
+		);
+		assert_ok!(CarbonCreditPools::set_retire_fee(
+			RawOrigin::Signed(pool_admin).into(),
+			pool_id,
+			fee
+		));
+		assert_eq!(
+			last_event(),
+			CarbonCreditPoolEvent::RetireFeeSet { pool_id, retire_fee: fee }.into()
+		);
+		let stored_pool = Pools::<Test>::get(pool_id).unwrap();
+		assert_eq!(stored_pool.deposit_fee, fee);
+		assert_eq!(stored_pool.retire_fee, fee);
+
+		// the new admin takes over the pool
+		assert_ok!(CarbonCreditPools::transfer_admin(
+			RawOrigin::Signed(pool_admin).into(),
+			pool_id,
+			new_admin
+		));
+		assert_eq!(Pools::<Test>::get(pool_id).unwrap().admin, new_admin);
+		assert_eq!(
+			last_event(),
+			CarbonCreditPoolEvent::PoolAdminTransferred {
+				pool_id,
+				old_admin: pool_admin,
+				new_admin
+			}
+			.into()
+		);
+		assert_noop!(
+			CarbonCreditPools::set_pool_status(
+				RawOrigin::Signed(pool_admin).into(),
+				pool_id,
+				PoolStatus::Active
+			),
+			Error::<Test>::NotPoolAdmin
+		);
+		assert_ok!(CarbonCreditPools::set_pool_status(
+			RawOrigin::Signed(new_admin).into(),
+			pool_id,
+			PoolStatus::Active
+		));
+	});
+}
+
+#[test]
+fn pool_status_and_fees_work() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 1;
+		let pool_admin = 3;
+		let authorised_account = 10;
+		let pool_id = 10_000;
+		let treasury = PoolTreasuryAccount::get();
+
+		assert_ok!(CarbonCreditPools::create(
+			RawOrigin::Root.into(),
+			pool_id,
+			pool_admin,
+			Default::default(),
+			None,
+			"pool_xyz".as_bytes().to_vec().try_into().unwrap(),
+		));
+		assert_ok!(CarbonCredits::force_add_authorized_account(
+			RawOrigin::Root.into(),
+			authorised_account
+		));
+		let asset_id =
+			create_project_with_issuance_year(originator_account, authorised_account, 2020, 100);
+
+		// the deposit fee is minted to the pool admin
+		assert_ok!(CarbonCreditPools::set_deposit_fee(
+			RawOrigin::Signed(pool_admin).into(),
+			pool_id,
+			PoolFee { fee: Percent::from_percent(10), recipient: FeeRecipient::Admin }
+		));
+		assert_ok!(CarbonCreditPools::deposit(
+			RawOrigin::Signed(originator_account).into(),
+			pool_id,
+			asset_id,
+			50
+		));
+		assert_eq!(Assets::balance(pool_id, originator_account), 45);
+		assert_eq!(Assets::balance(pool_id, pool_admin), 5);
+		check_pool_invariants(pool_id);
+
+		// the retire fee is paid to the treasury and not retired
+		assert_ok!(CarbonCreditPools::set_retire_fee(
+			RawOrigin::Signed(pool_admin).into(),
+			pool_id,
+			PoolFee { fee: Percent::from_percent(10), recipient: FeeRecipient::Treasury }
+		));
+		assert_ok!(CarbonCreditPools::retire(
+			RawOrigin::Signed(originator_account).into(),
+			pool_id,
+			20,
+			None
+		));
+		assert_eq!(
+			last_event(),
+			CarbonCreditPoolEvent::Retired {
+				who: originator_account,
+				pool_id,
+				amount: 18,
+				retirement_id: 0,
+				beneficiary: originator_account
+			}
+			.into()
+		);
+		assert_eq!(Assets::balance(pool_id, originator_account), 25);
+		assert_eq!(Assets::balance(pool_id, treasury), 2);
+		let project = CarbonCredits::get_project_details(0).unwrap();
+		assert_eq!(project.batch_groups.get(&0).unwrap().retired, 18);
+		check_pool_invariants(pool_id);
+
+		// deposits are rejected while paused, retirements still work
+		assert_ok!(CarbonCreditPools::set_pool_status(
+			RawOrigin::Signed(pool_admin).into(),
+			pool_id,
+			PoolStatus::DepositsPaused
+		));
+		assert_noop!(
+			CarbonCreditPools::deposit(
+				RawOrigin::Signed(originator_account).into(),
+				pool_id,
+				asset_id,
+				10
+			),
+			Error::<Test>::PoolDepositsPaused
+		);
+		assert_ok!(CarbonCreditPools::retire(
+			RawOrigin::Signed(originator_account).into(),
+			pool_id,
+			10,
+			None
+		));
+		assert_eq!(Assets::balance(pool_id, originator_account), 15);
+		assert_eq!(Assets::balance(pool_id, treasury), 3);
+		check_pool_invariants(pool_id);
+
+		// the redemption fee follows the recipient of the retire fee
+		assert_ok!(CarbonCreditPools::force_set_redemption_fee(
+			RawOrigin::Root.into(),
+			Percent::from_percent(10)
+		));
+		assert_ok!(CarbonCreditPools::redeem(
+			RawOrigin::Signed(originator_account).into(),
+			pool_id,
+			asset_id,
+			10
+		));
+		assert_eq!(Assets::balance(pool_id, originator_account), 5);
+		assert_eq!(Assets::balance(pool_id, treasury), 4);
+		assert_eq!(Assets::balance(pool_id, pool_admin), 5);
+		check_pool_invariants(pool_id);
+
+		// a paused pool rejects all operations
+		assert_ok!(CarbonCreditPools::set_pool_status(
+			RawOrigin::Signed(pool_admin).into(),
+			pool_id,
+			PoolStatus::Paused
+		));
+		assert_noop!(
+			CarbonCreditPools::retire(
+				RawOrigin::Signed(originator_account).into(),
+				pool_id,
+				10,
+				None
+			),
+			Error::<Test>::PoolPaused
+		);
+		assert_noop!(
+			CarbonCreditPools::redeem(
+				RawOrigin::Signed(originator_account).into(),
+				pool_id,
+				asset_id,
+				10
+			),
+			Error::<Test>::PoolPaused
+		);
+		assert_noop!(
+			CarbonCreditPools::redeem_oldest(
+				RawOrigin::Signed(originator_account).into(),
+				pool_id,
+				10
+			),
+			Error::<Test>::PoolPaused
+		);
+	});
+}
+
+#[test]
+fn deposit_respects_pool_max_limit() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 1;
+		let pool_admin = 3;
+		let authorised_account = 10;
+		let pool_id = 10_000;
+
+		assert_ok!(CarbonCreditPools::create(
+			RawOrigin::Root.into(),
+			pool_id,
+			pool_admin,
+			Default::default(),
+			Some(2),
+			"pool_xyz".as_bytes().to_vec().try_into().unwrap(),
+		));
+		assert_ok!(CarbonCredits::force_add_authorized_account(
+			RawOrigin::Root.into(),
+			authorised_account
+		));
+
+		let first_asset_id =
+			create_project_with_issuance_year(originator_account, authorised_account, 2018, 100);
+		let second_asset_id =
+			create_project_with_issuance_year(originator_account, authorised_account, 2020, 100);
+		let third_asset_id =
+			create_project_with_issuance_year(originator_account, authorised_account, 2020, 100);
+
+		for asset_id in [first_asset_id, second_asset_id] {
+			assert_ok!(CarbonCreditPools::deposit(
+				RawOrigin::Signed(originator_account).into(),
+				pool_id,
+				asset_id,
+				10
+			));
+		}
+
+		// the pool cannot accept a new project above the max limit
+		assert_noop!(
+			CarbonCreditPools::deposit(
+				RawOrigin::Signed(originator_account).into(),
+				pool_id,
+				third_asset_id,
+				10
+			),
+			Error::<Test>::PoolProjectLimitReached
+		);
+
+		// projects already in the pool can still be deposited
+		assert_ok!(CarbonCreditPools::deposit(
+			RawOrigin::Signed(originator_account).into(),
+			pool_id,
+			first_asset_id,
+			10
+		));
+		check_pool_invariants(pool_id);
+	});
+}
+
+#[test]
+fn retire_respects_max_retire_projects() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 1;
+		let pool_admin = 3;
+		let authorised_account = 10;
+		let pool_id = 10_000;
+
+		// the pool limit is bounded by MaxPoolProjects
+		assert_noop!(
+			CarbonCreditPools::create(
+				RawOrigin::Root.into(),
+				pool_id,
+				pool_admin,
+				Default::default(),
+				Some(201),
+				"pool_xyz".as_bytes().to_vec().try_into().unwrap(),
+			),
+			Error::<Test>::MaxLimitGreaterThanPermitted
+		);
+		assert_ok!(CarbonCreditPools::create(
+			RawOrigin::Root.into(),
+			pool_id,
+			pool_admin,
+			Default::default(),
+			None,
+			"pool_xyz".as_bytes().to_vec().try_into().unwrap(),
+		));
+		assert_ok!(CarbonCredits::force_add_authorized_account(
+			RawOrigin::Root.into(),
+			authorised_account
+		));
+
+		// the pool holds more projects than MaxRetireProjects
+		for _ in 0..6 {
+			let asset_id = create_project_with_issuance_year(
+				originator_account,
+				authorised_account,
+				2020,
+				100,
+			);
+			assert_ok!(CarbonCreditPools::deposit(
+				RawOrigin::Signed(originator_account).into(),
+				pool_id,
+				asset_id,
+				10
+			));
+		}
+		assert_eq!(Pools::<Test>::get(pool_id).unwrap().vintages.get(&2020), Some(&6));
+
+		// a single retirement cannot span all the projects
+		assert_noop!(
+			CarbonCreditPools::retire(
+				RawOrigin::Signed(originator_account).into(),
+				pool_id,
+				60,
+				None
+			),
+			Error::<Test>::RetireProjectLimitReached
+		);
+		assert_noop!(
+			CarbonCreditPools::redeem_oldest(
+				RawOrigin::Signed(originator_account).into(),
+				pool_id,
+				60
+			),
+			Error::<Test>::RetireProjectLimitReached
+		);
+
+		// the amount can be retired in several calls
+		assert_ok!(CarbonCreditPools::retire(
+			RawOrigin::Signed(originator_account).into(),
+			pool_id,
+			50,
+			None
+		));
+		assert_ok!(CarbonCreditPools::retire(
+			RawOrigin::Signed(originator_account).into(),
+			pool_id,
+			10,
+			None
+		));
+		assert!(Pools::<Test>::get(pool_id).unwrap().vintages.is_empty());
+		check_pool_invariants(pool_id);
+	});
+}
+
+#[test]
+fn pool_info_and_retire_preview_work() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 1;
+		let pool_admin = 3;
+		let authorised_account = 10;
+		let pool_id = 10_000;
+
+		assert_ok!(CarbonCreditPools::create(
+			RawOrigin::Root.into(),
+			pool_id,
+			pool_admin,
+			Default::default(),
+			None,
+			"pool_xyz".as_bytes().to_vec().try_into().unwrap(),
+		));
+		assert_ok!(CarbonCredits::force_add_authorized_account(
+			RawOrigin::Root.into(),
+			authorised_account
+		));
+
+		let old_asset_id =
+			create_project_with_issuance_year(originator_account, authorised_account, 2018, 100);
+		let new_asset_id =
+			create_project_with_issuance_year(originator_account, authorised_account, 2020, 100);
+
+		for asset_id in [new_asset_id, old_asset_id] {
+			assert_ok!(CarbonCreditPools::deposit(
+				RawOrigin::Signed(originator_account).into(),
+				pool_id,
+				asset_id,
+				50
+			));
+		}
+
+		assert!(CarbonCreditPools::pool_info(pool_id + 1).is_none());
+		assert!(CarbonCreditPools::retire_preview(pool_id + 1, 10).is_none());
+
+		// the composition is sorted by vintage and aggregated by project details
+		let pool_info = CarbonCreditPools::pool_info(pool_id).unwrap();
+		assert_eq!(pool_info.admin, pool_admin);
+		assert_eq!(pool_info.status, PoolStatus::Active);
+		assert_eq!(pool_info.supply, 100);
+		assert_eq!(pool_info.credits, vec![(2018, old_asset_id, 50), (2020, new_asset_id, 50)]);
+		assert_eq!(pool_info.registries, vec![(RegistryName::Verra, 100)]);
+		assert_eq!(pool_info.sdgs, vec![(SdgType::LifeOnLand, 100)]);
+		assert_eq!(pool_info.credit_types, vec![(CreditType::Avoidance, 100)]);
+
+		// the preview accounts for the retire fee and consumes the oldest credits first
+		assert_ok!(CarbonCreditPools::set_retire_fee(
+			RawOrigin::Signed(pool_admin).into(),
+			pool_id,
+			PoolFee { fee: Percent::from_percent(10), recipient: FeeRecipient::Admin }
+		));
+		let preview = CarbonCreditPools::retire_preview(pool_id, 60).unwrap();
+		assert_eq!(
+			preview,
+			RetirePreview {
+				fee: 6,
+				retired: 54,
+				credits: vec![(2018, old_asset_id, 50), (2020, new_asset_id, 4)]
+			}
+		);
+
+		// the preview cannot exceed the pool credits
+		assert!(CarbonCreditPools::retire_preview(pool_id, 200).is_none());
+
+		// the preview matches the actual retirement
+		assert_ok!(CarbonCreditPools::retire(
+			RawOrigin::Signed(originator_account).into(),
+			pool_id,
+			60,
+			None
+		));
+		let pool_info = CarbonCreditPools::pool_info(pool_id).unwrap();
+		assert_eq!(pool_info.supply, 46);
+		assert_eq!(pool_info.credits, vec![(2020, new_asset_id, 46)]);
+		check_pool_invariants(pool_id);
+	});
+}
+
+#[test]
+fn retire_issues_single_certificate() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 1;
+		let pool_admin = 3;
+		let authorised_account = 10;
+		let beneficiary_account = 5;
+		let pool_id = 10_000;
+
+		assert_ok!(CarbonCreditPools::create(
+			RawOrigin::Root.into(),
+			pool_id,
+			pool_admin,
+			Default::default(),
+			None,
+			"pool_xyz".as_bytes().to_vec().try_into().unwrap(),
+		));
+		assert_ok!(CarbonCredits::force_add_authorized_account(
+			RawOrigin::Root.into(),
+			authorised_account
+		));
+
+		let old_asset_id =
+			create_project_with_issuance_year(originator_account, authorised_account, 2018, 100);
+		let new_asset_id =
+			create_project_with_issuance_year(originator_account, authorised_account, 2020, 100);
+
+		for asset_id in [old_asset_id, new_asset_id] {
+			assert_ok!(CarbonCreditPools::deposit(
+				RawOrigin::Signed(originator_account).into(),
+				pool_id,
+				asset_id,
+				50
+			));
+		}
+
+		// retire credits of both projects on behalf of the beneficiary
+		let beneficiary = RetirementBeneficiary {
+			account: beneficiary_account,
+			name: Some("beneficiary".as_bytes().to_vec().try_into().unwrap()),
+			reason: Some("offset".as_bytes().to_vec().try_into().unwrap()),
+		};
+		assert_ok!(CarbonCreditPools::retire(
+			RawOrigin::Signed(originator_account).into(),
+			pool_id,
+			60,
+			Some(beneficiary.clone())
+		));
+		assert_eq!(
+			last_event(),
+			CarbonCreditPoolEvent::Retired {
+				who: originator_account,
+				pool_id,
+				amount: 60,
+				retirement_id: 0,
+				beneficiary: beneficiary_account
+			}
+			.into()
+		);
+
+		// a single certificate is minted to the beneficiary from the pool collection
+		assert_eq!(Uniques::owner(pool_id, 0), Some(beneficiary_account));
+		assert_eq!(Uniques::owner(old_asset_id, 0), None);
+		assert_eq!(Uniques::owner(new_asset_id, 0), None);
+
+		// the record links the underlying retirements
+		assert_eq!(
+			PoolRetirements::<Test>::get(pool_id, 0),
+			Some(PoolRetirementRecord {
+				pool_id,
+				account: originator_account,
+				beneficiary,
+				count: 60,
+				retired_credits: vec![(old_asset_id, 0, 50), (new_asset_id, 0, 10)]
+					.try_into()
+					.unwrap(),
+				timestamp: 1,
+			})
+		);
+		let old_retirement = CarbonCredits::retired_carbon_credits(old_asset_id, 0).unwrap();
+		assert_eq!(old_retirement.account, originator_account);
+		assert_eq!(old_retirement.count, 50);
+		let new_retirement = CarbonCredits::retired_carbon_credits(new_asset_id, 0).unwrap();
+		assert_eq!(new_retirement.count, 10);
+
+		// the next retirement uses the next certificate of the pool collection
+		assert_ok!(CarbonCreditPools::retire(
+			RawOrigin::Signed(originator_account).into(),
+			pool_id,
+			10,
+			None
+		));
+		assert_eq!(Uniques::owner(pool_id, 1), Some(originator_account));
+		assert_eq!(
+			PoolRetirements::<Test>::get(pool_id, 1).unwrap().retired_credits.into_inner(),
+			vec![(new_asset_id, 1, 10)]
+		);
+
+		// retiring directly from the project still mints the project certificate
+		assert_ok!(CarbonCredits::retire(RawOrigin::Signed(originator_account).into(), 1, 0, 10));
+		assert_eq!(Uniques::owner(new_asset_id, 2), Some(originator_account));
+		check_pool_invariants(pool_id);
+	});
+}
+
+#[test]
+fn migration_v2_moves_pool_credits_to_storage() {
+	use crate::migration::{v1, v2::MigrateToV2, PoolV1};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+	use sp_std::collections::btree_map::BTreeMap;
+
+	new_test_ext().execute_with(|| {
+		let pool_id = 10_000;
+		StorageVersion::new(1).put::<CarbonCreditPools>();
+
+		// a pool storing the credits of two vintages with the v1 layout
+		let mut credits: crate::migration::CreditsMap<Test> = Default::default();
+		credits
+			.try_insert(
+				2018,
+				vec![(1, 50)].into_iter().collect::<BTreeMap<_, _>>().try_into().unwrap(),
+			)
+			.unwrap();
+		credits
+			.try_insert(
+				2020,
+				vec![(2, 30), (3, 20)]
+					.into_iter()
+					.collect::<BTreeMap<_, _>>()
+					.try_into()
+					.unwrap(),
+			)
+			.unwrap();
+		v1::Pools::<Test>::insert(
+			pool_id,
+			PoolV1 {
+				admin: 1,
+				config: Default::default(),
+				max_limit: 10,
+				credits,
+				status: PoolStatus::DepositsPaused,
+				deposit_fee: PoolFee {
+					fee: Percent::from_percent(1),
+					recipient: FeeRecipient::Admin,
+				},
+				retire_fee: Default::default(),
+			},
+		);
+
+		MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(CarbonCreditPools::on_chain_storage_version(), 2);
+
+		// the credits are moved to the PoolCredits map
+		let pool = Pools::<Test>::get(pool_id).unwrap();
+		assert_eq!(pool.admin, 1);
+		// the old per vintage limit is replaced by the pool limit
+		assert_eq!(pool.max_limit, 200);
+		assert_eq!(pool.deposit_fee.fee, Percent::from_percent(1));
+		assert_eq!(pool.vintages.into_inner(), vec![(2018, 1), (2020, 2)].into_iter().collect());
+		assert_eq!(pool.total_credits, 100);
+		assert_eq!(PoolCredits::<Test>::get((pool_id, 2018), 1), Some(50));
+		assert_eq!(PoolCredits::<Test>::get((pool_id, 2020), 2), Some(30));
+		assert_eq!(PoolCredits::<Test>::get((pool_id, 2020), 3), Some(20));
+
+		// the migration is not executed twice
+		MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(PoolCredits::<Test>::iter().count(), 3);
+	});
+}
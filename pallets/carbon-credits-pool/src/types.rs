@@ -4,9 +4,11 @@
 //
 //! Types for CarbonCredits-pools
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{BoundedBTreeMap, BoundedVec};
-use primitives::{IssuanceYear, RegistryName};
+use frame_support::{traits::ConstU32, BoundedBTreeMap, BoundedVec};
+use primitives::{CreditType, IssuanceYear, RegistryName, SdgType};
 use scale_info::TypeInfo;
+use sp_runtime::Percent;
+use sp_std::vec::Vec;
 
 use super::*;
 
@@ -17,46 +19,219 @@ pub type RegistryNameList<T> = BoundedVec<RegistryName, <T as Config>::MaxRegist
 pub type MaxProjectIdList<T> =
 	BoundedVec<<T as pallet_carbon_credits::Config>::AssetId, <T as Config>::MaxProjectIdList>;
 
+/// List of permitted issuance years
+pub type IssuanceYearList<T> = BoundedVec<IssuanceYear, <T as Config>::MaxIssuanceYearCount>;
+
+/// List of SDG types required from projects
+pub type SdgTypeList<T> = BoundedVec<SdgType, ConstU32<17>>;
+
 /// type to receive symbol data
 pub type SymbolStringOf<T> = BoundedVec<u8, <T as Config>::MaxAssetSymbolLength>;
 
 /// The configuration of a pool
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, Default, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct PoolConfig<RegistryList, MaxProjectIdList> {
+pub struct PoolConfig<RegistryList, MaxProjectIdList, IssuanceYearList, SdgTypeList> {
 	pub registry_list: Option<RegistryList>,
 	pub project_id_list: Option<MaxProjectIdList>,
+	/// The oldest vintage accepted by the pool
+	pub min_issuance_year: Option<IssuanceYear>,
+	/// The newest vintage accepted by the pool
+	pub max_issuance_year: Option<IssuanceYear>,
+	/// The vintages accepted by the pool
+	pub issuance_year_list: Option<IssuanceYearList>,
+	/// The SDG types a project must contribute to
+	pub required_sdgs: Option<SdgTypeList>,
+	/// The type of credits accepted by the pool
+	pub credit_type: Option<CreditType>,
 }
 
-/// Map storing the details of a given project in a pool
-/// ProjectId => Amount of tokens in pool
-pub type ProjectDetails<T> = BoundedBTreeMap<
-	<T as pallet_carbon_credits::Config>::AssetId,
-	<T as pallet_carbon_credits::Config>::Balance,
-	<T as Config>::MaxProjectIdList,
->;
+/// Map storing the number of projects held by the pool for every issuance year, the credits are
+/// stored in `PoolCredits`
+/// IssuanceYear => Number of projects
+pub type VintagesMap<T> = BoundedBTreeMap<IssuanceYear, u32, <T as Config>::MaxPoolProjects>;
 
-/// Map storing the available credits in the pool by issuance year
-/// IssuanceYear => ProjectDetail
-pub type CreditsMap<T> =
-	BoundedBTreeMap<IssuanceYear, ProjectDetails<T>, <T as Config>::MaxProjectIdList>;
+/// The status of a pool
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, Default, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PoolStatus {
+	/// The pool accepts deposits, retirements and redemptions
+	#[default]
+	Active,
+	/// The pool does not accept deposits, pool tokens can still be retired and redeemed
+	DepositsPaused,
+	/// The pool does not accept deposits, retirements or redemptions
+	Paused,
+}
+
+/// The receiver of the fees charged by a pool
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, Default, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FeeRecipient {
+	/// The fees are paid to the pool admin
+	#[default]
+	Admin,
+	/// The fees are paid to the treasury
+	Treasury,
+}
+
+/// A fee charged by a pool, paid in pool tokens
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, Default, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoolFee {
+	/// The percentage of the amount charged as fee
+	pub fee: Percent,
+	/// The receiver of the fee
+	pub recipient: FeeRecipient,
+}
 
 /// The data stored for a pool
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, Default, MaxEncodedLen)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Pool<AccountId, PoolConfig, CreditsMap> {
+pub struct Pool<AccountId, PoolConfig, VintagesMap, Balance> {
 	/// The admin of the pool
 	pub admin: AccountId,
 	/// The configs applicable to this pool
 	pub config: PoolConfig,
 	/// The maximum limit of projects this pool can accept
 	pub max_limit: u32,
-	/// The credits stored in the pool
-	pub credits: CreditsMap,
+	/// The issuance years of the credits stored in the pool
+	pub vintages: VintagesMap,
+	/// The total amount of credits stored in the pool
+	pub total_credits: Balance,
+	/// The status of the pool
+	pub status: PoolStatus,
+	/// The fee charged on deposits
+	pub deposit_fee: PoolFee,
+	/// The fee charged on retirements
+	pub retire_fee: PoolFee,
+}
+
+/// The beneficiary of a retirement from a pool
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RetirementBeneficiary<AccountId, StringType> {
+	/// The account receiving the retirement certificate
+	pub account: AccountId,
+	/// The name of the beneficiary
+	pub name: Option<StringType>,
+	/// The reason for the retirement
+	pub reason: Option<StringType>,
+}
+
+/// List of the underlying CarbonCredits retirements of a pool retirement
+/// (AssetId, ItemId, Amount), the keys link to the `RetiredCredits` of the CarbonCredits pallet
+pub type RetiredCreditsListOf<T> = BoundedVec<
+	(
+		<T as pallet_carbon_credits::Config>::AssetId,
+		<T as pallet_carbon_credits::Config>::ItemId,
+		<T as pallet_carbon_credits::Config>::Balance,
+	),
+	<T as Config>::MaxRetireProjects,
+>;
+
+/// The record of a retirement from a pool
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoolRetirementRecord<
+	PoolId,
+	AccountId,
+	Beneficiary,
+	Balance,
+	RetiredCreditsList,
+	BlockNumber,
+> {
+	/// The pool the credits were retired from
+	pub pool_id: PoolId,
+	/// The account that retired the pool tokens
+	pub account: AccountId,
+	/// The beneficiary of the retirement, the certificate is minted to the beneficiary account
+	pub beneficiary: Beneficiary,
+	/// The total count of credits retired
+	pub count: Balance,
+	/// The underlying CarbonCredits retirements
+	pub retired_credits: RetiredCreditsList,
+	/// The 'BlockNumber' of retirement
+	pub timestamp: BlockNumber,
 }
 
 /// Pool config for CarbonCredits pools pallet
-pub type PoolConfigOf<T> = PoolConfig<RegistryNameList<T>, MaxProjectIdList<T>>;
+pub type PoolConfigOf<T> =
+	PoolConfig<RegistryNameList<T>, MaxProjectIdList<T>, IssuanceYearList<T>, SdgTypeList<T>>;
 
 /// Pool for this pallet
-pub type PoolOf<T> = Pool<<T as frame_system::Config>::AccountId, PoolConfigOf<T>, CreditsMap<T>>;
+pub type PoolOf<T> = Pool<
+	<T as frame_system::Config>::AccountId,
+	PoolConfigOf<T>,
+	VintagesMap<T>,
+	<T as pallet_carbon_credits::Config>::Balance,
+>;
+
+/// Retirement beneficiary for this pallet
+pub type RetirementBeneficiaryOf<T> = RetirementBeneficiary<
+	<T as frame_system::Config>::AccountId,
+	pallet_carbon_credits::ShortStringOf<T>,
+>;
+
+/// Pool retirement record for this pallet
+pub type PoolRetirementRecordOf<T> = PoolRetirementRecord<
+	<T as Config>::PoolId,
+	<T as frame_system::Config>::AccountId,
+	RetirementBeneficiaryOf<T>,
+	<T as pallet_carbon_credits::Config>::Balance,
+	RetiredCreditsListOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
+/// Pool info for this pallet
+pub type PoolInfoOf<T> = PoolInfo<
+	<T as frame_system::Config>::AccountId,
+	PoolConfigOf<T>,
+	<T as pallet_carbon_credits::Config>::AssetId,
+	<T as pallet_carbon_credits::Config>::Balance,
+>;
+
+/// Retire preview for this pallet
+pub type RetirePreviewOf<T> = RetirePreview<
+	<T as pallet_carbon_credits::Config>::AssetId,
+	<T as pallet_carbon_credits::Config>::Balance,
+>;
+
+/// The details and composition of a pool, returned by the runtime api
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoolInfo<AccountId, PoolConfig, AssetId, Balance> {
+	/// The admin of the pool
+	pub admin: AccountId,
+	/// The configs applicable to this pool
+	pub config: PoolConfig,
+	/// The status of the pool
+	pub status: PoolStatus,
+	/// The fee charged on deposits
+	pub deposit_fee: PoolFee,
+	/// The fee charged on retirements
+	pub retire_fee: PoolFee,
+	/// The maximum limit of projects this pool can accept
+	pub max_limit: u32,
+	/// The total supply of pool tokens
+	pub supply: Balance,
+	/// The credits held by the pool as (issuance year, asset id, amount), oldest first
+	pub credits: Vec<(IssuanceYear, AssetId, Balance)>,
+	/// The credits held by the pool for every registry
+	pub registries: Vec<(RegistryName, Balance)>,
+	/// The credits held by the pool contributing to every SDG
+	pub sdgs: Vec<(SdgType, Balance)>,
+	/// The credits held by the pool for every credit type
+	pub credit_types: Vec<(CreditType, Balance)>,
+}
+
+/// The outcome of a retirement from a pool, returned by the runtime api
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RetirePreview<AssetId, Balance> {
+	/// The retire fee paid in pool tokens
+	pub fee: Balance,
+	/// The amount of credits retired
+	pub retired: Balance,
+	/// The credits retired as (issuance year, asset id, amount), oldest first
+	pub credits: Vec<(IssuanceYear, AssetId, Balance)>,
+}
@@ -6,6 +6,9 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2022-06-27, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! Entries marked `NOT BENCHMARKED` were added after this run and are estimated from their
+//! storage accesses, they must be replaced by re-running the command below.
 
 // Executed Command:
 // ./target/release/bitg-node
@@ -15,11 +18,11 @@
 // --steps=50
 // --repeat=20
 // --log=warn
-// --pallet=pallet-carbon-credits-pools
+// --pallet=pallet-carbon-credits-pool
 // --extrinsic=*
 // --execution=wasm
 // --wasm-execution=compiled
-// --output=./pallets/vcu-pools/src/weights.rs
+// --output=./pallets/carbon-credits-pool/src/weights.rs
 // --template=./.maintain/bitg-weight-template.hbs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
@@ -34,7 +37,14 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn create() -> Weight;
 	fn deposit() -> Weight;
-	fn retire() -> Weight;
+	fn retire(v: u32, p: u32, ) -> Weight;
+	fn redeem() -> Weight;
+	fn redeem_oldest(v: u32, p: u32, ) -> Weight;
+	fn force_set_redemption_fee() -> Weight;
+	fn update_config() -> Weight;
+	fn transfer_admin() -> Weight;
+	fn set_pool_status() -> Weight;
+	fn set_pool_fee() -> Weight;
 }
 
 /// Weights for pallet_carbon_credits_pool using the Substrate node and recommended hardware.
@@ -49,18 +59,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: VCUPools Pools (r:1 w:1)
+	// Storage: VCUPools PoolCredits (r:1 w:1)
 	// Storage: VCU Projects (r:1 w:0)
 	// Storage: Assets Asset (r:2 w:2)
 	// Storage: Assets Account (r:3 w:3)
 	// Storage: System Account (r:2 w:2)
 	fn deposit() -> Weight {
 		Weight::from_ref_time(67_000_000_u64)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
+	// NOT BENCHMARKED
 	// Storage: VCUPools Pools (r:1 w:1)
+	// Storage: VCUPools PoolCredits (r:1 w:1)
 	// Storage: Assets Asset (r:2 w:2)
 	// Storage: Assets Account (r:3 w:3)
+	// Storage: VCU AssetIdLookup (r:1 w:0)
 	// Storage: VCU Projects (r:1 w:1)
 	// Storage: VCU NextItemId (r:1 w:1)
 	// Storage: Uniques Class (r:1 w:1)
@@ -69,10 +83,84 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Uniques ClassAccount (r:0 w:1)
 	// Storage: Uniques Account (r:0 w:1)
 	// Storage: VCU RetiredCredits (r:0 w:1)
-	fn retire() -> Weight {
-		Weight::from_ref_time(101_000_000_u64)
-			.saturating_add(T::DbWeight::get().reads(11_u64))
-			.saturating_add(T::DbWeight::get().writes(13_u64))
+	// Storage: VCUPools NextRetirementId (r:1 w:1)
+	// Storage: VCUPools PoolRetirements (r:0 w:1)
+	/// The range of component `v` is `[1, 100]`.
+	/// The range of component `p` is `[1, 100]`.
+	fn retire(v: u32, p: u32, ) -> Weight {
+		Weight::from_ref_time(71_000_000_u64)
+			.saturating_add(Weight::from_ref_time(1_850_000_u64).saturating_mul(v as u64))
+			.saturating_add(Weight::from_ref_time(48_700_000_u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v as u64)))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(p as u64)))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().writes((8_u64).saturating_mul(p as u64)))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCUPools Pools (r:1 w:1)
+	// Storage: VCUPools PoolCredits (r:1 w:1)
+	// Storage: VCUPools RedemptionFee (r:1 w:0)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: Assets Account (r:4 w:4)
+	// Storage: System Account (r:1 w:1)
+	fn redeem() -> Weight {
+		Weight::from_ref_time(72_000_000_u64)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCUPools Pools (r:1 w:1)
+	// Storage: VCUPools PoolCredits (r:1 w:1)
+	// Storage: VCUPools RedemptionFee (r:1 w:0)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: Assets Account (r:4 w:4)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `v` is `[1, 100]`.
+	/// The range of component `p` is `[1, 100]`.
+	fn redeem_oldest(v: u32, p: u32, ) -> Weight {
+		Weight::from_ref_time(52_000_000_u64)
+			.saturating_add(Weight::from_ref_time(1_420_000_u64).saturating_mul(v as u64))
+			.saturating_add(Weight::from_ref_time(24_300_000_u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v as u64)))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(p as u64)))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(p as u64)))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCUPools RedemptionFee (r:0 w:1)
+	fn force_set_redemption_fee() -> Weight {
+		Weight::from_ref_time(14_000_000_u64)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCUPools Pools (r:1 w:1)
+	fn update_config() -> Weight {
+		Weight::from_ref_time(22_000_000_u64)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCUPools Pools (r:1 w:1)
+	fn transfer_admin() -> Weight {
+		Weight::from_ref_time(19_000_000_u64)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCUPools Pools (r:1 w:1)
+	fn set_pool_status() -> Weight {
+		Weight::from_ref_time(18_000_000_u64)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCUPools Pools (r:1 w:1)
+	fn set_pool_fee() -> Weight {
+		Weight::from_ref_time(18_000_000_u64)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 }
 
@@ -87,18 +175,22 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	// Storage: VCUPools Pools (r:1 w:1)
+	// Storage: VCUPools PoolCredits (r:1 w:1)
 	// Storage: VCU Projects (r:1 w:0)
 	// Storage: Assets Asset (r:2 w:2)
 	// Storage: Assets Account (r:3 w:3)
 	// Storage: System Account (r:2 w:2)
 	fn deposit() -> Weight {
 		Weight::from_ref_time(67_000_000_u64)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
+	// NOT BENCHMARKED
 	// Storage: VCUPools Pools (r:1 w:1)
+	// Storage: VCUPools PoolCredits (r:1 w:1)
 	// Storage: Assets Asset (r:2 w:2)
 	// Storage: Assets Account (r:3 w:3)
+	// Storage: VCU AssetIdLookup (r:1 w:0)
 	// Storage: VCU Projects (r:1 w:1)
 	// Storage: VCU NextItemId (r:1 w:1)
 	// Storage: Uniques Class (r:1 w:1)
@@ -107,9 +199,83 @@ impl WeightInfo for () {
 	// Storage: Uniques ClassAccount (r:0 w:1)
 	// Storage: Uniques Account (r:0 w:1)
 	// Storage: VCU RetiredCredits (r:0 w:1)
-	fn retire() -> Weight {
-		Weight::from_ref_time(101_000_000_u64)
-			.saturating_add(RocksDbWeight::get().reads(11_u64))
-			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	// Storage: VCUPools NextRetirementId (r:1 w:1)
+	// Storage: VCUPools PoolRetirements (r:0 w:1)
+	/// The range of component `v` is `[1, 100]`.
+	/// The range of component `p` is `[1, 100]`.
+	fn retire(v: u32, p: u32, ) -> Weight {
+		Weight::from_ref_time(71_000_000_u64)
+			.saturating_add(Weight::from_ref_time(1_850_000_u64).saturating_mul(v as u64))
+			.saturating_add(Weight::from_ref_time(48_700_000_u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v as u64)))
+			.saturating_add(RocksDbWeight::get().reads((7_u64).saturating_mul(p as u64)))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().writes((8_u64).saturating_mul(p as u64)))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCUPools Pools (r:1 w:1)
+	// Storage: VCUPools PoolCredits (r:1 w:1)
+	// Storage: VCUPools RedemptionFee (r:1 w:0)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: Assets Account (r:4 w:4)
+	// Storage: System Account (r:1 w:1)
+	fn redeem() -> Weight {
+		Weight::from_ref_time(72_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCUPools Pools (r:1 w:1)
+	// Storage: VCUPools PoolCredits (r:1 w:1)
+	// Storage: VCUPools RedemptionFee (r:1 w:0)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: Assets Account (r:4 w:4)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `v` is `[1, 100]`.
+	/// The range of component `p` is `[1, 100]`.
+	fn redeem_oldest(v: u32, p: u32, ) -> Weight {
+		Weight::from_ref_time(52_000_000_u64)
+			.saturating_add(Weight::from_ref_time(1_420_000_u64).saturating_mul(v as u64))
+			.saturating_add(Weight::from_ref_time(24_300_000_u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v as u64)))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(p as u64)))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(p as u64)))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCUPools RedemptionFee (r:0 w:1)
+	fn force_set_redemption_fee() -> Weight {
+		Weight::from_ref_time(14_000_000_u64)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCUPools Pools (r:1 w:1)
+	fn update_config() -> Weight {
+		Weight::from_ref_time(22_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCUPools Pools (r:1 w:1)
+	fn transfer_admin() -> Weight {
+		Weight::from_ref_time(19_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCUPools Pools (r:1 w:1)
+	fn set_pool_status() -> Weight {
+		Weight::from_ref_time(18_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCUPools Pools (r:1 w:1)
+	fn set_pool_fee() -> Weight {
+		Weight::from_ref_time(18_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 }
@@ -0,0 +1,22 @@
+// This file is part of BitGreen.
+// Copyright (C) 2022 BitGreen.
+// This code is licensed under MIT license (see LICENSE.txt for details)
+//
+//! Runtime API definition for the CarbonCredits pallet
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait CarbonCreditsApi<ProjectId, ProjectRevision> where
+		ProjectId: Codec,
+		ProjectRevision: Codec,
+	{
+		/// The revision of the project that created the given version
+		fn project_revision(project_id: ProjectId, version: u32) -> Option<ProjectRevision>;
+
+		/// The projects whose location overlaps the location of the given project
+		fn overlapping_projects(project_id: ProjectId) -> Vec<ProjectId>;
+	}
+}
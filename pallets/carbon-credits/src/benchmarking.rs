@@ -5,9 +5,13 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use frame_benchmarking::{account, benchmarks, vec};
-use frame_support::BoundedVec;
+use frame_support::{traits::Currency, BoundedVec};
 use frame_system::RawOrigin;
-use primitives::{Batch, RegistryDetails, RegistryName, SDGDetails, SdgType};
+use primitives::{
+	Batch, BatchStatus, Coordinate, CreditType, Geometry, KycLevel, Location, RegistryDetails,
+	RegistryName, SDGDetails, SdgType,
+};
+use sp_runtime::traits::Bounded;
 use sp_std::convert::TryInto;
 
 use super::*;
@@ -16,6 +20,14 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+/// helper function to fund an account for the project deposit
+fn fund_account<T: Config>(account_id: &T::AccountId) {
+	T::Currency::make_free_balance_be(
+		account_id,
+		CurrencyBalanceOf::<T>::max_value() / 2u32.into(),
+	);
+}
+
 /// helper function to generate standard registry details
 fn get_default_registry_details<T: Config>() -> RegistryListOf<T> {
 	let registry_details = RegistryDetails {
@@ -50,6 +62,7 @@ fn get_single_batch_list<T: Config>() -> BoundedVec<BatchOf<T>, T::MaxGroupSize>
 		total_supply: 100_u32.into(),
 		minted: 0_u32.into(),
 		retired: 0_u32.into(),
+		status: BatchStatus::Verified,
 	}]
 	.try_into()
 	.unwrap()
@@ -70,6 +83,24 @@ fn get_default_batch_group<T: Config>() -> BatchGroupListOf<T> {
 	.unwrap()
 }
 
+/// helper function to generate standard location
+fn get_default_location<T: Config>() -> LocationOf<T> {
+	Location {
+		geometry: Geometry::Polygon(
+			vec![
+				Coordinate { latitude: 1_000_000, longitude: 1_000_000 },
+				Coordinate { latitude: 1_000_000, longitude: 2_000_000 },
+				Coordinate { latitude: 2_000_000, longitude: 2_000_000 },
+				Coordinate { latitude: 2_000_000, longitude: 1_000_000 },
+			]
+			.try_into()
+			.unwrap(),
+		),
+		country: Some(*b"BR"),
+		region: Some("region".as_bytes().to_vec().try_into().unwrap()),
+	}
+}
+
 /// helper function to generate standard creation details
 fn get_default_creation_params<T: Config>() -> ProjectCreateParams<T>
 where
@@ -77,7 +108,7 @@ where
 	let creation_params = ProjectCreateParams {
 		name: "name".as_bytes().to_vec().try_into().unwrap(),
 		description: "description".as_bytes().to_vec().try_into().unwrap(),
-		location: "location".as_bytes().to_vec().try_into().unwrap(),
+		location: get_default_location::<T>(),
 		images: vec!["image_link".as_bytes().to_vec().try_into().unwrap()].try_into().unwrap(),
 		videos: vec!["video_link".as_bytes().to_vec().try_into().unwrap()].try_into().unwrap(),
 		documents: vec!["document_link".as_bytes().to_vec().try_into().unwrap()]
@@ -86,26 +117,37 @@ where
 		registry_details: get_default_registry_details::<T>(),
 		sdg_details: get_default_sdg_details::<T>(),
 		batch_groups: get_default_batch_group::<T>(),
+		credit_type: CreditType::Avoidance,
 		royalties: None,
 	};
 
 	creation_params
 }
 
+/// helper function to generate creation details with a projected batch for forward credits
+fn get_forward_creation_params<T: Config>() -> ProjectCreateParams<T> {
+	let mut creation_params = get_default_creation_params::<T>();
+	for group in creation_params.batch_groups.iter_mut() {
+		for batch in group.batches.iter_mut() {
+			batch.status = BatchStatus::Projected;
+		}
+	}
+	creation_params
+}
+
 benchmarks! {
 
 	where_clause { where
 	T::AssetId: From<u32>,
 	T::ItemId: From<u32>,
-	T: pallet_membership::Config
 }
 
 	create {
 		let caller : T::AccountId = account("account_id", 0, 0);
 		let project_id : T::ProjectId = 0_u32.into();
 		let creation_params = get_default_creation_params::<T>();
-		let caller_lookup = <T::Lookup as sp_runtime::traits::StaticLookup>::unlookup(caller.clone());
-		pallet_membership::Pallet::<T>::add_member(RawOrigin::Root.into(), caller_lookup)?;
+		T::BenchmarkHelper::set_kyc_level(&caller, KycLevel::Institutional);
+		fund_account::<T>(&caller);
 	}: _(RawOrigin::Signed(caller.into()), creation_params.into())
 	verify {
 		assert!(Projects::<T>::get(project_id).is_some());
@@ -116,8 +158,8 @@ benchmarks! {
 		let project_id : T::ProjectId = 0_u32.into();
 		let creation_params = get_default_creation_params::<T>();
 		CarbonCredits::<T>::force_add_authorized_account(RawOrigin::Root.into(), caller.clone().into())?;
-		let caller_lookup = <T::Lookup as sp_runtime::traits::StaticLookup>::unlookup(caller.clone());
-		pallet_membership::Pallet::<T>::add_member(RawOrigin::Root.into(), caller_lookup)?;
+		T::BenchmarkHelper::set_kyc_level(&caller, KycLevel::Institutional);
+		fund_account::<T>(&caller);
 		CarbonCredits::<T>::create(RawOrigin::Signed(caller.clone()).into(), creation_params)?;
 	}: _(RawOrigin::Signed(caller.into()), project_id, true)
 	verify {
@@ -130,8 +172,8 @@ benchmarks! {
 		let group_id : T::GroupId = 0_u32.into();
 		let creation_params = get_default_creation_params::<T>();
 
-		let caller_lookup = <T::Lookup as sp_runtime::traits::StaticLookup>::unlookup(caller.clone());
-		pallet_membership::Pallet::<T>::add_member(RawOrigin::Root.into(), caller_lookup)?;
+		T::BenchmarkHelper::set_kyc_level(&caller, KycLevel::Institutional);
+		fund_account::<T>(&caller);
 
 		CarbonCredits::<T>::force_add_authorized_account(RawOrigin::Root.into(), caller.clone().into())?;
 		CarbonCredits::<T>::create(RawOrigin::Signed(caller.clone()).into(), creation_params)?;
@@ -148,8 +190,8 @@ benchmarks! {
 		let asset_id : T::AssetId = 0_u32.into();
 		let creation_params = get_default_creation_params::<T>();
 
-		let caller_lookup = <T::Lookup as sp_runtime::traits::StaticLookup>::unlookup(caller.clone());
-		pallet_membership::Pallet::<T>::add_member(RawOrigin::Root.into(), caller_lookup)?;
+		T::BenchmarkHelper::set_kyc_level(&caller, KycLevel::Institutional);
+		fund_account::<T>(&caller);
 
 		CarbonCredits::<T>::force_add_authorized_account(RawOrigin::Root.into(), caller.clone().into())?;
 		CarbonCredits::<T>::create(RawOrigin::Signed(caller.clone()).into(), creation_params)?;
@@ -162,6 +204,174 @@ benchmarks! {
 		assert_last_event::<T>(Event::CarbonCreditRetired { project_id, group_id, asset_id, account : caller, amount : 10_u32.into(), retire_data :retire_data.retire_data }.into());
 	}
 
+	extend_batch_group {
+		let caller : T::AccountId = account("account_id", 0, 0);
+		let project_id : T::ProjectId = 0_u32.into();
+		let group_id : T::GroupId = 0_u32.into();
+		let creation_params = get_default_creation_params::<T>();
+
+		T::BenchmarkHelper::set_kyc_level(&caller, KycLevel::Institutional);
+		fund_account::<T>(&caller);
+
+		CarbonCredits::<T>::force_add_authorized_account(RawOrigin::Root.into(), caller.clone().into())?;
+		CarbonCredits::<T>::create(RawOrigin::Signed(caller.clone()).into(), creation_params)?;
+		CarbonCredits::<T>::approve_project(RawOrigin::Signed(caller.clone()).into(), project_id, true)?;
+		let mut batches = get_single_batch_list::<T>();
+		batches[0].uuid = "batch_uuid_2".as_bytes().to_vec().try_into().unwrap();
+	}: _(RawOrigin::Root, project_id, group_id, batches)
+	verify {
+		assert_last_event::<T>(Event::BatchGroupExtended { project_id, group_id, added_supply : 100_u32.into(), total_supply : 200_u32.into() }.into());
+	}
+
+	split_batch_group {
+		let caller : T::AccountId = account("account_id", 0, 0);
+		let project_id : T::ProjectId = 0_u32.into();
+		let group_id : T::GroupId = 0_u32.into();
+		let creation_params = get_default_creation_params::<T>();
+
+		T::BenchmarkHelper::set_kyc_level(&caller, KycLevel::Institutional);
+		fund_account::<T>(&caller);
+
+		CarbonCredits::<T>::force_add_authorized_account(RawOrigin::Root.into(), caller.clone().into())?;
+		CarbonCredits::<T>::create(RawOrigin::Signed(caller.clone()).into(), creation_params)?;
+		CarbonCredits::<T>::approve_project(RawOrigin::Signed(caller.clone()).into(), project_id, true)?;
+		// mint half of the batch so the other half can be moved to a new group
+		CarbonCredits::<T>::mint(RawOrigin::Signed(caller.clone()).into(), project_id, group_id, 50_u32.into(), false)?;
+	}: _(RawOrigin::Root, project_id, group_id, 0)
+	verify {
+		let project = Projects::<T>::get(project_id).unwrap();
+		assert_eq!(project.batch_groups.len(), 2);
+	}
+
+	withdraw_project {
+		let caller : T::AccountId = account("account_id", 0, 0);
+		let project_id : T::ProjectId = 0_u32.into();
+		let creation_params = get_default_creation_params::<T>();
+
+		T::BenchmarkHelper::set_kyc_level(&caller, KycLevel::Institutional);
+		fund_account::<T>(&caller);
+
+		CarbonCredits::<T>::create(RawOrigin::Signed(caller.clone()).into(), creation_params)?;
+	}: _(RawOrigin::Signed(caller), project_id)
+	verify {
+		assert_last_event::<T>(Event::ProjectWithdrawn { project_id }.into());
+	}
+
+	add_monitoring_report {
+		let caller : T::AccountId = account("account_id", 0, 0);
+		let project_id : T::ProjectId = 0_u32.into();
+		let creation_params = get_default_creation_params::<T>();
+
+		T::BenchmarkHelper::set_kyc_level(&caller, KycLevel::Institutional);
+		fund_account::<T>(&caller);
+
+		CarbonCredits::<T>::force_add_authorized_account(RawOrigin::Root.into(), caller.clone().into())?;
+		CarbonCredits::<T>::create(RawOrigin::Signed(caller.clone()).into(), creation_params)?;
+		CarbonCredits::<T>::approve_project(RawOrigin::Signed(caller.clone()).into(), project_id, true)?;
+		let ipfs_hash : IpfsLinkOf<T> = "report_link".as_bytes().to_vec().try_into().unwrap();
+	}: _(RawOrigin::Signed(caller), project_id, ipfs_hash.clone(), 1000, 2000)
+	verify {
+		assert_last_event::<T>(Event::MonitoringReportAdded { project_id, ipfs_hash, period_start : 1000, period_end : 2000 }.into());
+	}
+
+	mint_forward {
+		let caller : T::AccountId = account("account_id", 0, 0);
+		let project_id : T::ProjectId = 0_u32.into();
+		let group_id : T::GroupId = 0_u32.into();
+		let creation_params = get_forward_creation_params::<T>();
+
+		T::BenchmarkHelper::set_kyc_level(&caller, KycLevel::Institutional);
+		fund_account::<T>(&caller);
+
+		CarbonCredits::<T>::force_add_authorized_account(RawOrigin::Root.into(), caller.clone().into())?;
+		CarbonCredits::<T>::create(RawOrigin::Signed(caller.clone()).into(), creation_params)?;
+		CarbonCredits::<T>::approve_project(RawOrigin::Signed(caller.clone()).into(), project_id, true)?;
+		// the forward asset is created on the first mint
+		let asset_id : T::AssetId = 1_u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), project_id, group_id, 100_u32.into())
+	verify {
+		assert_last_event::<T>(Event::ForwardCreditMinted { project_id, group_id, asset_id, recipient : caller, amount : 100_u32.into() }.into());
+	}
+
+	verify_batch {
+		let caller : T::AccountId = account("account_id", 0, 0);
+		let project_id : T::ProjectId = 0_u32.into();
+		let group_id : T::GroupId = 0_u32.into();
+		let creation_params = get_forward_creation_params::<T>();
+
+		T::BenchmarkHelper::set_kyc_level(&caller, KycLevel::Institutional);
+		fund_account::<T>(&caller);
+
+		CarbonCredits::<T>::force_add_authorized_account(RawOrigin::Root.into(), caller.clone().into())?;
+		CarbonCredits::<T>::create(RawOrigin::Signed(caller.clone()).into(), creation_params)?;
+		CarbonCredits::<T>::approve_project(RawOrigin::Signed(caller.clone()).into(), project_id, true)?;
+		CarbonCredits::<T>::mint_forward(RawOrigin::Signed(caller.clone()).into(), project_id, group_id, 100_u32.into())?;
+	}: _(RawOrigin::Root, project_id, group_id, 0)
+	verify {
+		assert_last_event::<T>(Event::BatchVerified { project_id, group_id, issuance_year : 2020, convertible_amount : 100_u32.into() }.into());
+	}
+
+	convert_forward_credits {
+		let caller : T::AccountId = account("account_id", 0, 0);
+		let project_id : T::ProjectId = 0_u32.into();
+		let group_id : T::GroupId = 0_u32.into();
+		let creation_params = get_forward_creation_params::<T>();
+
+		T::BenchmarkHelper::set_kyc_level(&caller, KycLevel::Institutional);
+		fund_account::<T>(&caller);
+
+		CarbonCredits::<T>::force_add_authorized_account(RawOrigin::Root.into(), caller.clone().into())?;
+		CarbonCredits::<T>::create(RawOrigin::Signed(caller.clone()).into(), creation_params)?;
+		CarbonCredits::<T>::approve_project(RawOrigin::Signed(caller.clone()).into(), project_id, true)?;
+		CarbonCredits::<T>::mint_forward(RawOrigin::Signed(caller.clone()).into(), project_id, group_id, 100_u32.into())?;
+		CarbonCredits::<T>::verify_batch(RawOrigin::Root.into(), project_id, group_id, 0)?;
+	}: _(RawOrigin::Signed(caller.clone()), project_id, group_id, 100_u32.into())
+	verify {
+		assert_last_event::<T>(Event::ForwardCreditsConverted { project_id, group_id, account : caller, amount : 100_u32.into() }.into());
+	}
+
+	force_slash_project_deposit {
+		let caller : T::AccountId = account("account_id", 0, 0);
+		let project_id : T::ProjectId = 0_u32.into();
+		let creation_params = get_default_creation_params::<T>();
+
+		T::BenchmarkHelper::set_kyc_level(&caller, KycLevel::Institutional);
+		fund_account::<T>(&caller);
+
+		// the deposit is held until the project is approved
+		CarbonCredits::<T>::create(RawOrigin::Signed(caller.clone()).into(), creation_params)?;
+	}: _(RawOrigin::Root, project_id)
+	verify {
+		assert!(!ProjectDeposits::<T>::contains_key(project_id));
+		assert!(Projects::<T>::get(project_id).is_none());
+	}
+
+	force_remove_project {
+		// number of retirement records of the project
+		let r in 1 .. 100;
+
+		let caller : T::AccountId = account("account_id", 0, 0);
+		let project_id : T::ProjectId = 0_u32.into();
+		let group_id : T::GroupId = 0_u32.into();
+		let creation_params = get_default_creation_params::<T>();
+
+		T::BenchmarkHelper::set_kyc_level(&caller, KycLevel::Institutional);
+		fund_account::<T>(&caller);
+
+		CarbonCredits::<T>::force_add_authorized_account(RawOrigin::Root.into(), caller.clone().into())?;
+		CarbonCredits::<T>::create(RawOrigin::Signed(caller.clone()).into(), creation_params)?;
+		CarbonCredits::<T>::approve_project(RawOrigin::Signed(caller.clone()).into(), project_id, true)?;
+		CarbonCredits::<T>::mint(RawOrigin::Signed(caller.clone()).into(), project_id, group_id, r.into(), false)?;
+
+		// retire all credits one by one so that no credits are left in circulation
+		for _ in 0 .. r {
+			CarbonCredits::<T>::retire(RawOrigin::Signed(caller.clone()).into(), project_id, group_id, 1_u32.into())?;
+		}
+	}: _(RawOrigin::Root, project_id, r)
+	verify {
+		assert_last_event::<T>(Event::ProjectRemoved { project_id, asset_ids: vec![0u32.into()] }.into());
+	}
+
 	force_add_authorized_account {
 		let account_id : T::AccountId = account("account_id", 0, 0);
 	}: _(RawOrigin::Root, account_id.clone().into())
@@ -197,6 +407,7 @@ benchmarks! {
 			documents: params.documents,
 			registry_details: params.registry_details,
 			sdg_details: params.sdg_details,
+			credit_type: params.credit_type,
 			royalties: params.royalties,
 			batch_groups: Default::default(),
 			created: 1_u32.into(),
@@ -0,0 +1,46 @@
+// This file is part of BitGreen.
+// Copyright (C) 2022 BitGreen.
+// This code is licensed under MIT license (see LICENSE.txt for details)
+//! Transfer compliance of the CarbonCredits assets
+use frame_support::{
+	traits::{Contains, Get},
+	weights::Weight,
+};
+use pallet_assets::TransferCompliance;
+use primitives::{JurisdictionProvider, KycLevelProvider};
+use sp_std::marker::PhantomData;
+
+use crate::{Config, Pallet};
+
+/// Restricts the receivers of the assets created by the CarbonCredits pallet to KYC verified
+/// accounts outside blocked jurisdictions, the pallet account, the marketplace escrow and the
+/// accounts in `Whitelist`, for example the accounts of the pallets holding credits. Other assets
+/// are not restricted.
+pub struct KycTransferCompliance<T, Whitelist>(PhantomData<(T, Whitelist)>);
+
+impl<T: Config, Whitelist: Contains<T::AccountId>> TransferCompliance<T::AssetId, T::AccountId>
+	for KycTransferCompliance<T, Whitelist>
+{
+	fn can_receive(asset: T::AssetId, who: &T::AccountId) -> bool {
+		if !Pallet::<T>::is_carbon_credit_asset(asset) {
+			return true
+		}
+		if *who == Pallet::<T>::account_id() ||
+			*who == T::MarketplaceEscrow::get() ||
+			Whitelist::contains(who)
+		{
+			return true
+		}
+		T::KYCProvider::kyc_level(who).is_some() && !T::KYCProvider::is_account_blocked(who)
+	}
+
+	fn can_receive_weight() -> Weight {
+		// Storage: CarbonCredits AssetIdLookup (r:1 w:0)
+		// Storage: CarbonCredits ForwardAssetIdLookup (r:1 w:0)
+		// KYC level: the record, the derived account link, the parent record, the identity
+		// membership and the identity judgements (r:5 w:0)
+		// Jurisdiction: the record, the derived account link, the parent record and the blocked
+		// jurisdictions (r:4 w:0)
+		T::DbWeight::get().reads(11)
+	}
+}
@@ -8,21 +8,29 @@ use frame_support::{
 	pallet_prelude::*,
 	traits::{
 		tokens::{
-			fungibles::{metadata::Mutate as MetadataMutate, Create, Mutate},
+			fungibles::{metadata::Mutate as MetadataMutate, Create, Destroy, Inspect, Mutate},
 			nonfungibles::{Create as NFTCreate, Mutate as NFTMutate},
 		},
-		Contains, Get,
+		Get, OnUnbalanced, ReservableCurrency,
 	},
 	BoundedBTreeMap,
 };
-use primitives::BatchRetireData;
-use sp_runtime::traits::{AccountIdConversion, CheckedAdd, CheckedSub, One, Zero};
+use primitives::{
+	is_valid_country_code, is_valid_polygon, polygon_contains, polygons_overlap, BatchRetireData,
+	BatchStatus, Geometry, JurisdictionProvider, KycLevel, KycLevelProvider,
+};
+use sp_runtime::traits::{
+	AccountIdConversion, CheckedAdd, CheckedSub, Hash, One, Saturating, UniqueSaturatedInto, Zero,
+};
 use sp_std::{cmp, convert::TryInto, vec::Vec};
 
 use crate::{
-	AssetIdLookup, AuthorizedAccounts, BatchGroupOf, BatchRetireDataList, BatchRetireDataOf,
-	Config, Error, Event, NextAssetId, NextItemId, NextProjectId, Pallet, ProjectCreateParams,
-	ProjectDetail, Projects, RetiredCarbonCreditsData, RetiredCredits,
+	ArchivedRetiredCredits, AssetIdLookup, AuthorizedAccounts, BatchGroupOf, BatchListOf, BatchOf,
+	BatchRetireDataList, BatchRetireDataOf, Config, ConvertibleForwardCredits, CurrencyBalanceOf,
+	Error, Event, ForwardAssetIdLookup, ForwardAssetIds, IpfsLinkOf, LocationOf, MonitoringReport,
+	MonitoringReports, NextAssetId, NextItemId, NextProjectId, Pallet, ProjectCreateParams,
+	ProjectDeposits, ProjectDetail, ProjectRevision, ProjectRevisions, Projects,
+	RetiredCarbonCreditsData, RetiredCredits, UnconvertedForwardCredits,
 };
 
 impl<T: Config> Pallet<T> {
@@ -36,15 +44,30 @@ impl<T: Config> Pallet<T> {
 		Projects::<T>::get(project_id)
 	}
 
-	/// Checks if given account is kyc approved
-	pub fn check_kyc_approval(account_id: &T::AccountId) -> DispatchResult {
-		if !T::KYCProvider::contains(account_id) {
+	/// Checks if given account is kyc approved at the given level
+	pub fn check_kyc_approval(account_id: &T::AccountId, level: KycLevel) -> DispatchResult {
+		if !T::KYCProvider::has_kyc_level(account_id, level) {
 			Err(Error::<T>::KYCAuthorisationFailed.into())
 		} else {
 			Ok(())
 		}
 	}
 
+	/// Returns true if the asset was created by the pallet, as spot or forward credits
+	pub fn is_carbon_credit_asset(asset_id: T::AssetId) -> bool {
+		AssetIdLookup::<T>::contains_key(asset_id) ||
+			ForwardAssetIdLookup::<T>::contains_key(asset_id)
+	}
+
+	/// Checks that the given account does not belong to a blocked jurisdiction
+	pub fn check_jurisdiction(account_id: &T::AccountId) -> DispatchResult {
+		if T::KYCProvider::is_account_blocked(account_id) {
+			Err(Error::<T>::JurisdictionBlocked.into())
+		} else {
+			Ok(())
+		}
+	}
+
 	/// Checks if the given account_id is part of authorized account list
 	pub fn check_authorized_account(account_id: &T::AccountId) -> DispatchResult {
 		let authorized_accounts = AuthorizedAccounts::<T>::get();
@@ -68,32 +91,18 @@ impl<T: Config> Pallet<T> {
 				let mut created_asset_ids: Vec<T::AssetId> = Default::default();
 
 				for (group_id, mut group) in project.batch_groups.iter_mut() {
-					let asset_id = Self::next_asset_id();
-					let next_asset_id =
-						asset_id.checked_add(&1u32.into()).ok_or(Error::<T>::Overflow)?;
-					NextAssetId::<T>::put(next_asset_id);
-
-					// create the asset
-					T::AssetHandler::create(asset_id, Self::account_id(), true, 1_u32.into())?;
-
-					// set metadata for the asset
-					T::AssetHandler::set(
-						asset_id,
-						&Self::account_id(),
-						project_id.to_string().as_bytes().to_vec(), // asset name
-						project_id.to_string().as_bytes().to_vec(), // asset symbol
-						0,
-					)?;
+					let asset_id = Self::create_group_asset(project_id, *group_id)?;
 
 					// set the asset id
 					group.asset_id = asset_id;
 
-					AssetIdLookup::<T>::insert(group.asset_id, (project_id, group_id));
-
 					// add the assetId for event updation
 					created_asset_ids.push(asset_id);
 				}
 
+				// the project is no longer pending, return the creation deposit
+				Self::release_project_deposit(project_id, &project.originator);
+
 				Self::deposit_event(Event::ProjectApproved {
 					project_id,
 					asset_ids: created_asset_ids,
@@ -106,6 +115,202 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Calculate the deposit required to store `project`
+	/// The deposit is the base deposit plus the per byte deposit for the encoded project data
+	pub fn calculate_project_deposit(project: &ProjectDetail<T>) -> CurrencyBalanceOf<T> {
+		let bytes: u32 = project.encoded_size().unique_saturated_into();
+		T::ProjectDepositBase::get()
+			.saturating_add(T::ProjectDepositPerByte::get().saturating_mul(bytes.into()))
+	}
+
+	/// Set the deposit held for `project_id` to `new_deposit`, the difference to the current
+	/// deposit is reserved from or returned to `who`
+	pub fn update_project_deposit(
+		who: &T::AccountId,
+		project_id: T::ProjectId,
+		new_deposit: CurrencyBalanceOf<T>,
+	) -> DispatchResult {
+		let old_deposit = ProjectDeposits::<T>::get(project_id);
+
+		if new_deposit > old_deposit {
+			T::Currency::reserve(who, new_deposit.saturating_sub(old_deposit))?;
+		} else if new_deposit < old_deposit {
+			T::Currency::unreserve(who, old_deposit.saturating_sub(new_deposit));
+		}
+
+		ProjectDeposits::<T>::insert(project_id, new_deposit);
+		Ok(())
+	}
+
+	/// Return the deposit held for `project_id` to `who`
+	pub fn release_project_deposit(project_id: T::ProjectId, who: &T::AccountId) {
+		let amount = ProjectDeposits::<T>::take(project_id);
+		if !amount.is_zero() {
+			T::Currency::unreserve(who, amount);
+			Self::deposit_event(Event::ProjectDepositReleased {
+				project_id,
+				who: who.clone(),
+				amount,
+			});
+		}
+	}
+
+	/// Slash the deposit held for `project_id`, a project that never had assets created is
+	/// removed from storage. A project that was rejected after approval keeps its details since
+	/// its assets and retirement records still refer to it, it can be removed with
+	/// `force_remove_project` once no credits are in circulation.
+	pub fn slash_project_deposit(project_id: T::ProjectId) -> DispatchResult {
+		let project = Projects::<T>::get(project_id).ok_or(Error::<T>::ProjectNotFound)?;
+		let amount = ProjectDeposits::<T>::take(project_id);
+		ensure!(!amount.is_zero(), Error::<T>::NoProjectDeposit);
+
+		let (imbalance, remainder) = T::Currency::slash_reserved(&project.originator, amount);
+		T::DepositSlashed::on_unbalanced(imbalance);
+
+		if !Self::project_has_assets(project_id, &project) {
+			Self::remove_unapproved_project(project_id, &project);
+		}
+
+		Self::deposit_event(Event::ProjectDepositSlashed {
+			project_id,
+			who: project.originator,
+			amount: amount.saturating_sub(remainder),
+		});
+		Ok(())
+	}
+
+	/// Returns true if assets were created for any group of `project`
+	fn project_has_assets(project_id: T::ProjectId, project: &ProjectDetail<T>) -> bool {
+		project.batch_groups.iter().any(|(group_id, group)| {
+			AssetIdLookup::<T>::get(group.asset_id) == Some((project_id, *group_id)) ||
+				ForwardAssetIds::<T>::contains_key(project_id, group_id)
+		})
+	}
+
+	/// Remove a project without assets and its details from storage
+	fn remove_unapproved_project(project_id: T::ProjectId, project: &ProjectDetail<T>) {
+		Projects::<T>::remove(project_id);
+		ProjectRevisions::<T>::remove(project_id);
+		MonitoringReports::<T>::remove(project_id);
+		for group_id in project.batch_groups.keys() {
+			ConvertibleForwardCredits::<T>::remove(project_id, group_id);
+		}
+	}
+
+	/// Create the asset representing the credits of a group and register it in the lookup
+	pub fn create_group_asset(
+		project_id: T::ProjectId,
+		group_id: T::GroupId,
+	) -> Result<T::AssetId, DispatchError> {
+		let asset_id = Self::next_asset_id();
+		let next_asset_id = asset_id.checked_add(&1u32.into()).ok_or(Error::<T>::Overflow)?;
+		NextAssetId::<T>::put(next_asset_id);
+
+		// create the asset
+		T::AssetHandler::create(asset_id, Self::account_id(), true, 1_u32.into())?;
+
+		// set metadata for the asset
+		T::AssetHandler::set(
+			asset_id,
+			&Self::account_id(),
+			project_id.to_string().as_bytes().to_vec(), // asset name
+			project_id.to_string().as_bytes().to_vec(), // asset symbol
+			0,
+		)?;
+
+		AssetIdLookup::<T>::insert(asset_id, (project_id, group_id));
+
+		Ok(asset_id)
+	}
+
+	/// Ensure the supply accounting of a group is consistent with the batches it holds
+	/// The group totals must always equal the sum of the batch values and every batch must
+	/// satisfy `retired <= minted <= total_supply`
+	pub fn check_group_supply(group: &BatchGroupOf<T>) -> DispatchResult {
+		let mut total_supply: T::Balance = Zero::zero();
+		let mut minted: T::Balance = Zero::zero();
+		let mut retired: T::Balance = Zero::zero();
+
+		for batch in group.batches.iter() {
+			ensure!(batch.minted <= batch.total_supply, Error::<T>::SupplyAmountMismatch);
+			ensure!(batch.retired <= batch.minted, Error::<T>::SupplyAmountMismatch);
+
+			total_supply =
+				total_supply.checked_add(&batch.total_supply).ok_or(Error::<T>::Overflow)?;
+			minted = minted.checked_add(&batch.minted).ok_or(Error::<T>::Overflow)?;
+			retired = retired.checked_add(&batch.retired).ok_or(Error::<T>::Overflow)?;
+		}
+
+		ensure!(total_supply == group.total_supply, Error::<T>::SupplyAmountMismatch);
+		ensure!(minted == group.minted, Error::<T>::SupplyAmountMismatch);
+		ensure!(retired == group.retired, Error::<T>::SupplyAmountMismatch);
+
+		Ok(())
+	}
+
+	/// Ensure no two batches of a group share the same uuid, the uuid identifies the batch in
+	/// UnconvertedForwardCredits
+	pub fn check_batch_uuids(batches: &[BatchOf<T>]) -> DispatchResult {
+		for (index, batch) in batches.iter().enumerate() {
+			ensure!(
+				!batches[index + 1..].iter().any(|other| other.uuid == batch.uuid),
+				Error::<T>::DuplicateBatchUuid
+			);
+		}
+		Ok(())
+	}
+
+	/// Ensure the location of a project is well formed
+	/// Coordinates must be within the valid latitude/longitude range, a polygon needs at least
+	/// three vertices and the country must be an assigned ISO 3166-1 alpha-2 code
+	pub fn check_location(location: &LocationOf<T>) -> DispatchResult {
+		let is_valid_geometry = match &location.geometry {
+			Geometry::Point(point) => point.is_valid(),
+			Geometry::Polygon(polygon) => is_valid_polygon(polygon),
+		};
+		ensure!(is_valid_geometry, Error::<T>::InvalidLocation);
+
+		if let Some(country) = &location.country {
+			ensure!(is_valid_country_code(country), Error::<T>::InvalidLocation);
+		}
+
+		if let Some(region) = &location.region {
+			ensure!(!region.is_empty(), Error::<T>::InvalidLocation);
+		}
+
+		Ok(())
+	}
+
+	/// Returns true if the two locations overlap
+	/// Two boundaries overlap if they share any area, a point overlaps a boundary it lies in,
+	/// points never overlap each other
+	pub fn locations_overlap(first: &LocationOf<T>, second: &LocationOf<T>) -> bool {
+		match (&first.geometry, &second.geometry) {
+			(Geometry::Polygon(a), Geometry::Polygon(b)) => polygons_overlap(a, b),
+			(Geometry::Polygon(polygon), Geometry::Point(point)) |
+			(Geometry::Point(point), Geometry::Polygon(polygon)) => polygon_contains(polygon, point),
+			(Geometry::Point(_), Geometry::Point(_)) => false,
+		}
+	}
+
+	/// Get the list of projects whose location overlaps the location of `project_id`
+	/// Overlapping boundaries can indicate double counting of credits, this iterates over all
+	/// projects and is only exposed offchain through the `CarbonCreditsApi` runtime api so that
+	/// approvers can review the overlaps before approving a project
+	pub fn get_overlapping_projects(project_id: T::ProjectId) -> Vec<T::ProjectId> {
+		let project = match Projects::<T>::get(project_id) {
+			Some(project) => project,
+			None => return Default::default(),
+		};
+
+		Projects::<T>::iter()
+			.filter(|(id, other)| {
+				*id != project_id && Self::locations_overlap(&project.location, &other.location)
+			})
+			.map(|(id, _)| id)
+			.collect()
+	}
+
 	/// Calculate the issuance year for a group
 	/// For a project with a single batch it's the issuance year of that batch
 	/// For a project with multiple batches, its the issuance year of the oldest batch
@@ -136,6 +341,8 @@ impl<T: Config> Pallet<T> {
 			// cannot create a new project with empty batch_groups
 			ensure!(!params.batch_groups.is_empty(), Error::<T>::CannotCreateProjectWithoutCredits);
 
+			Self::check_location(&params.location)?;
+
 			let mut batch_group_map: BoundedBTreeMap<_, _, _> = Default::default();
 			let mut group_id: T::GroupId = 0u32.into();
 
@@ -169,6 +376,8 @@ impl<T: Config> Pallet<T> {
 					Error::<T>::CannotCreateProjectWithoutCredits
 				);
 
+				Self::check_batch_uuids(&group.batches)?;
+
 				// sort batch data in ascending order of issuance year
 				group.batches.sort_by(|x, y| x.issuance_year.cmp(&y.issuance_year));
 				group.total_supply = group_total_supply;
@@ -192,12 +401,17 @@ impl<T: Config> Pallet<T> {
 				registry_details: params.registry_details,
 				batch_groups: batch_group_map,
 				sdg_details: params.sdg_details,
+				credit_type: params.credit_type,
 				royalties: params.royalties,
 				created: now,
 				updated: None,
 				approved: false,
 			};
 
+			// reserve the creation deposit from the originator
+			let deposit = Self::calculate_project_deposit(&new_project);
+			Self::update_project_deposit(&new_project.originator, project_id, deposit)?;
+
 			*project = Some(new_project);
 
 			Ok(project_id)
@@ -221,6 +435,8 @@ impl<T: Config> Pallet<T> {
 			// only originator can resubmit
 			ensure!(project.originator == admin, Error::<T>::NotAuthorised);
 
+			Self::check_location(&params.location)?;
+
 			let mut batch_group_map: BoundedBTreeMap<_, _, _> = Default::default();
 			let mut group_id: T::GroupId = 0u32.into();
 
@@ -253,6 +469,8 @@ impl<T: Config> Pallet<T> {
 					Error::<T>::CannotCreateProjectWithoutCredits
 				);
 
+				Self::check_batch_uuids(&group.batches)?;
+
 				// sort batch data in ascending order of issuance year
 				group.batches.sort_by(|x, y| x.issuance_year.cmp(&y.issuance_year));
 				group.total_supply = group_total_supply;
@@ -275,6 +493,7 @@ impl<T: Config> Pallet<T> {
 				documents: params.documents,
 				registry_details: params.registry_details,
 				sdg_details: params.sdg_details,
+				credit_type: params.credit_type,
 				royalties: params.royalties,
 				batch_groups: batch_group_map,
 				created: project.created,
@@ -282,6 +501,10 @@ impl<T: Config> Pallet<T> {
 				approved: false,
 			};
 
+			// adjust the deposit to the size of the resubmitted data
+			let deposit = Self::calculate_project_deposit(&new_project);
+			Self::update_project_deposit(&new_project.originator, project_id, deposit)?;
+
 			*project = new_project;
 
 			// emit event
@@ -291,6 +514,98 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Withdraw a project that has never been approved, the project is removed from storage and
+	/// the creation deposit is returned to the originator
+	pub fn do_withdraw_project(admin: T::AccountId, project_id: T::ProjectId) -> DispatchResult {
+		let project = Projects::<T>::get(project_id).ok_or(Error::<T>::ProjectNotFound)?;
+
+		// only originator can withdraw
+		ensure!(project.originator == admin, Error::<T>::NotAuthorised);
+
+		// approved projects cannot be withdrawn
+		ensure!(!project.approved, Error::<T>::CannotModifyApprovedProject);
+
+		// a project that was rejected after approval still has assets, it cannot be withdrawn
+		ensure!(
+			!Self::project_has_assets(project_id, &project),
+			Error::<T>::CannotModifyApprovedProject
+		);
+
+		Self::release_project_deposit(project_id, &admin);
+		Self::remove_unapproved_project(project_id, &project);
+
+		Self::deposit_event(Event::ProjectWithdrawn { project_id });
+		Ok(())
+	}
+
+	/// Remove a project and destroy the assets of its groups, the project cannot have any credits
+	/// in circulation. The retirement records of the project are moved to the archive and the
+	/// collections of retirement certificates are destroyed. At most `retired_items` retirement
+	/// records are archived, the call fails if the project has more.
+	pub fn do_remove_project(project_id: T::ProjectId, retired_items: u32) -> DispatchResult {
+		let project = Projects::<T>::get(project_id).ok_or(Error::<T>::ProjectNotFound)?;
+
+		// collect the assets created for this project
+		let mut asset_ids: Vec<T::AssetId> = Default::default();
+		for (group_id, group) in project.batch_groups.iter() {
+			if AssetIdLookup::<T>::get(group.asset_id) == Some((project_id, *group_id)) {
+				asset_ids.push(group.asset_id);
+			}
+			if let Some(forward_asset_id) = ForwardAssetIds::<T>::get(project_id, group_id) {
+				asset_ids.push(forward_asset_id);
+			}
+		}
+
+		// remove the forward credit details of the groups
+		for group_id in project.batch_groups.keys() {
+			ForwardAssetIds::<T>::remove(project_id, group_id);
+			ConvertibleForwardCredits::<T>::remove(project_id, group_id);
+		}
+
+		// ensure no credits are still held by any account
+		for asset_id in asset_ids.iter() {
+			ensure!(
+				T::AssetHandler::total_issuance(*asset_id).is_zero(),
+				Error::<T>::CreditsInCirculation
+			);
+		}
+
+		let mut archived: u32 = 0;
+		for asset_id in asset_ids.iter() {
+			// destroy the asset class
+			if let Some(destroy_witness) = T::AssetHandler::get_destroy_witness(asset_id) {
+				T::AssetHandler::destroy(*asset_id, destroy_witness, None)?;
+			}
+
+			// archive the retirement records of the asset
+			for (item_id, retired_data) in RetiredCredits::<T>::drain_prefix(asset_id) {
+				archived = archived.saturating_add(1);
+				ensure!(archived <= retired_items, Error::<T>::InvalidRetirementWitness);
+				ArchivedRetiredCredits::<T>::insert(project_id, (*asset_id, item_id), retired_data);
+			}
+
+			// destroy the retirement certificates, the records are kept in the archive
+			if let Some(destroy_witness) = T::NFTHandler::get_destroy_witness(asset_id) {
+				T::NFTHandler::destroy(*asset_id, destroy_witness, None)?;
+			}
+
+			NextItemId::<T>::remove(asset_id);
+			AssetIdLookup::<T>::remove(asset_id);
+			ForwardAssetIdLookup::<T>::remove(asset_id);
+		}
+
+		// return the deposit of unapproved projects
+		Self::release_project_deposit(project_id, &project.originator);
+
+		// remove project from storage
+		Projects::<T>::remove(project_id);
+		ProjectRevisions::<T>::remove(project_id);
+		MonitoringReports::<T>::remove(project_id);
+
+		Self::deposit_event(Event::ProjectRemoved { project_id, asset_ids });
+		Ok(())
+	}
+
 	/// Update a project that has already been approved, this function only allows the owner to
 	/// update certain fields of the project description, once approved the project cannot modify
 	/// the batch groups data.
@@ -310,6 +625,11 @@ impl<T: Config> Pallet<T> {
 			// only originator can resubmit
 			ensure!(project.originator == admin, Error::<T>::NotAuthorised);
 
+			Self::check_location(&params.location)?;
+
+			// keep a record of the documents being replaced
+			Self::record_project_revision(project_id, project, admin.clone(), now);
+
 			let new_project = ProjectDetail {
 				originator: admin,
 				name: params.name,
@@ -320,6 +640,8 @@ impl<T: Config> Pallet<T> {
 				documents: params.documents,
 				registry_details: params.registry_details,
 				sdg_details: params.sdg_details,
+				// the credit type of the issued credits cannot be changed
+				credit_type: project.credit_type,
 				royalties: params.royalties,
 				// we don't allow editing of the project batch data
 				batch_groups: project.batch_groups.clone(),
@@ -337,6 +659,89 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Content hash of the images, videos and documents of a project
+	pub fn project_documents_hash(project: &ProjectDetail<T>) -> T::Hash {
+		T::Hashing::hash_of(&(&project.images, &project.videos, &project.documents))
+	}
+
+	/// Record the current documents of `project` in the revision log
+	/// If the log is full, the oldest revision is dropped
+	pub fn record_project_revision(
+		project_id: T::ProjectId,
+		project: &ProjectDetail<T>,
+		editor: T::AccountId,
+		now: T::BlockNumber,
+	) {
+		let previous_documents_hash = Self::project_documents_hash(project);
+
+		ProjectRevisions::<T>::mutate(project_id, |revisions| {
+			let version = revisions.last().map_or(1, |x| x.version.saturating_add(1));
+
+			let revision = ProjectRevision { version, previous_documents_hash, editor, block: now };
+			if revisions.len() as u32 >= T::MaxProjectRevisions::get() && !revisions.is_empty() {
+				revisions.remove(0);
+			}
+			// cannot fail, an entry was removed if the list was full
+			let _ = revisions.try_push(revision);
+
+			Self::deposit_event(Event::ProjectRevisionRecorded {
+				project_id,
+				version,
+				previous_documents_hash,
+			});
+		});
+	}
+
+	/// Get the revision of a project by version
+	pub fn get_project_revision(
+		project_id: T::ProjectId,
+		version: u32,
+	) -> Option<ProjectRevision<T>> {
+		ProjectRevisions::<T>::get(project_id)
+			.into_iter()
+			.find(|x| x.version == version)
+	}
+
+	/// Add a monitoring report to an approved project, this can only be done by the originator
+	pub fn do_add_monitoring_report(
+		admin: T::AccountId,
+		project_id: T::ProjectId,
+		ipfs_hash: IpfsLinkOf<T>,
+		period_start: u64,
+		period_end: u64,
+	) -> DispatchResult {
+		let project = Projects::<T>::get(project_id).ok_or(Error::<T>::ProjectNotFound)?;
+
+		// only originator can add reports
+		ensure!(project.originator == admin, Error::<T>::NotAuthorised);
+
+		// reports document the issuance of an approved project
+		ensure!(project.approved, Error::<T>::ProjectNotApproved);
+
+		ensure!(period_start < period_end, Error::<T>::InvalidReportingPeriod);
+
+		MonitoringReports::<T>::try_mutate(project_id, |reports| -> DispatchResult {
+			reports
+				.try_push(MonitoringReport {
+					ipfs_hash: ipfs_hash.clone(),
+					period_start,
+					period_end,
+					submitter: admin,
+					submitted: frame_system::Pallet::<T>::block_number(),
+				})
+				.map_err(|_| Error::<T>::TooManyMonitoringReports)?;
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::MonitoringReportAdded {
+			project_id,
+			ipfs_hash,
+			period_start,
+			period_end,
+		});
+		Ok(())
+	}
+
 	/// Add a new batch group to the project, this can only be done by the originator
 	pub fn do_add_batch_group(
 		admin: T::AccountId,
@@ -384,6 +789,8 @@ impl<T: Config> Pallet<T> {
 				Error::<T>::CannotCreateProjectWithoutCredits
 			);
 
+			Self::check_batch_uuids(&batch_group.batches)?;
+
 			// sort batch data in ascending order of issuance year
 			batch_group.batches.sort_by(|x, y| x.issuance_year.cmp(&y.issuance_year));
 			batch_group.total_supply = group_total_supply;
@@ -402,6 +809,150 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Add newly verified batches to an existing group of an approved project, the supply of the
+	/// group is increased by the supply of the new batches and the group keeps its asset
+	pub fn do_extend_batch_group(
+		project_id: T::ProjectId,
+		group_id: T::GroupId,
+		batches: BatchListOf<T>,
+	) -> DispatchResult {
+		Projects::<T>::try_mutate(project_id, |project| -> DispatchResult {
+			let project = project.as_mut().ok_or(Error::<T>::ProjectNotFound)?;
+
+			// the group asset only exists once the project is approved
+			ensure!(project.approved, Error::<T>::ProjectNotApproved);
+
+			let group = project.batch_groups.get_mut(&group_id).ok_or(Error::<T>::GroupNotFound)?;
+
+			ensure!(!batches.is_empty(), Error::<T>::NoBatchesToAdd);
+
+			let mut batch_list: Vec<_> = group.batches.clone().into_iter().collect();
+			let mut added_supply: T::Balance = Zero::zero();
+
+			for batch in batches.into_iter() {
+				ensure!(batch.total_supply > Zero::zero(), Error::<T>::BatchSupplyIsZero);
+
+				ensure!(
+					batch.minted.is_zero() && batch.retired.is_zero(),
+					Error::<T>::BatchAlreadyIssued
+				);
+
+				added_supply =
+					added_supply.checked_add(&batch.total_supply).ok_or(Error::<T>::Overflow)?;
+
+				batch_list.push(batch);
+			}
+
+			Self::check_batch_uuids(&batch_list)?;
+
+			// sort batch data in ascending order of issuance year
+			batch_list.sort_by(|x, y| x.issuance_year.cmp(&y.issuance_year));
+
+			group.batches = batch_list.try_into().map_err(|_| Error::<T>::TooManyBatches)?;
+			group.total_supply =
+				group.total_supply.checked_add(&added_supply).ok_or(Error::<T>::Overflow)?;
+
+			// sanity check to ensure accounting is correct
+			Self::check_group_supply(group)?;
+
+			// emit event
+			Self::deposit_event(Event::BatchGroupExtended {
+				project_id,
+				group_id,
+				added_supply,
+				total_supply: group.total_supply,
+			});
+
+			Ok(())
+		})
+	}
+
+	/// Move the unminted credits of a batch in `group_id` to a new group with its own asset.
+	/// The minted part of the batch stays in the original group so that the circulating tokens
+	/// remain backed, if nothing was minted from the batch it is removed from the group.
+	pub fn do_split_batch_group(
+		project_id: T::ProjectId,
+		group_id: T::GroupId,
+		batch_index: u32,
+	) -> DispatchResult {
+		Projects::<T>::try_mutate(project_id, |project| -> DispatchResult {
+			let project = project.as_mut().ok_or(Error::<T>::ProjectNotFound)?;
+
+			// the group asset only exists once the project is approved
+			ensure!(project.approved, Error::<T>::ProjectNotApproved);
+
+			let new_batch = {
+				let group =
+					project.batch_groups.get_mut(&group_id).ok_or(Error::<T>::GroupNotFound)?;
+
+				let mut batch_list: Vec<_> = group.batches.clone().into_iter().collect();
+				let index = batch_index as usize;
+				let mut batch = batch_list.get(index).cloned().ok_or(Error::<T>::BatchNotFound)?;
+
+				// only credits that have not been minted can be moved to a new token
+				let available =
+					batch.total_supply.checked_sub(&batch.minted).ok_or(Error::<T>::Overflow)?;
+				ensure!(!available.is_zero(), Error::<T>::NoCreditsAvailableToSplit);
+
+				let mut new_batch = batch.clone();
+				new_batch.total_supply = available;
+				new_batch.minted = Zero::zero();
+				new_batch.retired = Zero::zero();
+
+				// the original batch keeps the credits backing the circulating tokens
+				batch.total_supply = batch.minted;
+				if batch.total_supply.is_zero() {
+					batch_list.remove(index);
+				} else {
+					batch_list[index] = batch;
+				}
+
+				ensure!(!batch_list.is_empty(), Error::<T>::CannotSplitLastBatch);
+
+				group.batches = batch_list.try_into().map_err(|_| Error::<T>::TooManyBatches)?;
+				group.total_supply =
+					group.total_supply.checked_sub(&available).ok_or(Error::<T>::Overflow)?;
+
+				// sanity check to ensure accounting is correct
+				Self::check_group_supply(group)?;
+
+				new_batch
+			};
+
+			let new_group_id: T::GroupId = (project.batch_groups.len() as u32).into();
+			let asset_id = Self::create_group_asset(project_id, new_group_id)?;
+			let amount = new_batch.total_supply;
+
+			let new_group = BatchGroupOf::<T> {
+				name: new_batch.name.clone(),
+				uuid: new_batch.uuid.clone(),
+				asset_id,
+				total_supply: amount,
+				minted: Zero::zero(),
+				retired: Zero::zero(),
+				batches: sp_std::vec![new_batch]
+					.try_into()
+					.map_err(|_| Error::<T>::TooManyBatches)?,
+			};
+
+			project
+				.batch_groups
+				.try_insert(new_group_id, new_group)
+				.map_err(|_| Error::<T>::TooManyGroups)?;
+
+			// emit event
+			Self::deposit_event(Event::BatchGroupSplit {
+				project_id,
+				group_id,
+				new_group_id,
+				asset_id,
+				amount,
+			});
+
+			Ok(())
+		})
+	}
+
 	pub fn mint_carbon_credits(
 		_sender: T::AccountId,
 		project_id: T::ProjectId,
@@ -444,6 +995,11 @@ impl<T: Config> Pallet<T> {
 
 			let mut remaining = amount_to_mint;
 			for batch in batch_list.iter_mut() {
+				// projected batches are minted as forward credits
+				if batch.status == BatchStatus::Projected {
+					continue
+				}
+
 				// lets mint from the older batches as much as possible
 				let available_to_mint =
 					batch.total_supply.checked_sub(&batch.minted).ok_or(Error::<T>::Overflow)?;
@@ -486,6 +1042,243 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Create the asset representing the forward credits of a group
+	pub fn create_forward_asset(
+		project_id: T::ProjectId,
+		group_id: T::GroupId,
+	) -> Result<T::AssetId, DispatchError> {
+		let asset_id = Self::next_asset_id();
+		let next_asset_id = asset_id.checked_add(&1u32.into()).ok_or(Error::<T>::Overflow)?;
+		NextAssetId::<T>::put(next_asset_id);
+
+		// create the asset
+		T::AssetHandler::create(asset_id, Self::account_id(), true, 1_u32.into())?;
+
+		// set metadata for the asset
+		let mut asset_name = project_id.to_string().as_bytes().to_vec();
+		asset_name.extend_from_slice(b"-F");
+		T::AssetHandler::set(
+			asset_id,
+			&Self::account_id(),
+			asset_name.clone(), // asset name
+			asset_name,         // asset symbol
+			0,
+		)?;
+
+		ForwardAssetIds::<T>::insert(project_id, group_id, asset_id);
+		ForwardAssetIdLookup::<T>::insert(asset_id, (project_id, group_id));
+
+		Ok(asset_id)
+	}
+
+	/// Mint forward credits from the projected batches of a group to the originator
+	pub fn mint_forward_credits(
+		project_id: T::ProjectId,
+		group_id: T::GroupId,
+		amount_to_mint: T::Balance,
+	) -> DispatchResult {
+		if amount_to_mint.is_zero() {
+			return Ok(())
+		}
+
+		Projects::<T>::try_mutate(project_id, |project| -> DispatchResult {
+			// ensure the project exists
+			let project = project.as_mut().ok_or(Error::<T>::ProjectNotFound)?;
+
+			// ensure the project is approved
+			ensure!(project.approved, Error::<T>::ProjectNotApproved);
+
+			// ensure the group exists
+			let group = project.batch_groups.get_mut(&group_id).ok_or(Error::<T>::GroupNotFound)?;
+
+			// Mint in the projected batches only
+			let mut batch_list: Vec<_> = group.batches.clone().into_iter().collect();
+
+			let mut remaining = amount_to_mint;
+			for batch in batch_list.iter_mut() {
+				if batch.status != BatchStatus::Projected {
+					continue
+				}
+
+				// lets mint from the older batches as much as possible
+				let available_to_mint =
+					batch.total_supply.checked_sub(&batch.minted).ok_or(Error::<T>::Overflow)?;
+
+				let actual = cmp::min(available_to_mint, remaining);
+
+				batch.minted = batch.minted.checked_add(&actual).ok_or(Error::<T>::Overflow)?;
+
+				// this is safe since actual is <= remaining
+				remaining = remaining.checked_sub(&actual).ok_or(Error::<T>::Overflow)?;
+				if remaining <= Zero::zero() {
+					break
+				}
+			}
+
+			// the projected batches cannot cover the amount
+			ensure!(remaining == Zero::zero(), Error::<T>::AmountGreaterThanSupply);
+
+			group.batches = batch_list.try_into().map_err(|_| Error::<T>::Overflow)?;
+
+			// increase the minted count
+			group.minted = group.minted.checked_add(&amount_to_mint).ok_or(Error::<T>::Overflow)?;
+
+			// another check to ensure accounting is correct
+			ensure!(group.minted <= group.total_supply, Error::<T>::AmountGreaterThanSupply);
+
+			// create the forward asset on first mint
+			let asset_id = match ForwardAssetIds::<T>::get(project_id, group_id) {
+				Some(asset_id) => asset_id,
+				None => Self::create_forward_asset(project_id, group_id)?,
+			};
+
+			// mint the forward asset to the originator
+			let recipient = project.originator.clone();
+			T::AssetHandler::mint_into(asset_id, &recipient, amount_to_mint)?;
+
+			// emit event
+			Self::deposit_event(Event::ForwardCreditMinted {
+				project_id,
+				group_id,
+				asset_id,
+				recipient,
+				amount: amount_to_mint,
+			});
+
+			Ok(())
+		})
+	}
+
+	/// Mark a projected batch as verified
+	/// The forward credits minted from the batch become convertible to spot credits
+	pub fn do_verify_batch(
+		project_id: T::ProjectId,
+		group_id: T::GroupId,
+		batch_index: u32,
+	) -> DispatchResult {
+		Projects::<T>::try_mutate(project_id, |project| -> DispatchResult {
+			// ensure the project exists
+			let project = project.as_mut().ok_or(Error::<T>::ProjectNotFound)?;
+
+			// ensure the project is approved
+			ensure!(project.approved, Error::<T>::ProjectNotApproved);
+
+			// ensure the group exists
+			let group = project.batch_groups.get_mut(&group_id).ok_or(Error::<T>::GroupNotFound)?;
+
+			let mut batch_list: Vec<_> = group.batches.clone().into_iter().collect();
+			let batch =
+				batch_list.get_mut(batch_index as usize).ok_or(Error::<T>::BatchNotFound)?;
+
+			ensure!(batch.status == BatchStatus::Projected, Error::<T>::BatchAlreadyVerified);
+			batch.status = BatchStatus::Verified;
+
+			// the forward credits minted from this batch can now be converted
+			let convertible_amount = batch.minted;
+			let issuance_year = batch.issuance_year;
+			ConvertibleForwardCredits::<T>::try_mutate(
+				project_id,
+				group_id,
+				|convertible| -> DispatchResult {
+					*convertible =
+						convertible.checked_add(&convertible_amount).ok_or(Error::<T>::Overflow)?;
+					Ok(())
+				},
+			)?;
+
+			// until converted the forward credits of the batch cannot be retired
+			if !convertible_amount.is_zero() {
+				UnconvertedForwardCredits::<T>::try_mutate(
+					(project_id, group_id, &batch.uuid),
+					|unconverted| -> DispatchResult {
+						*unconverted = unconverted
+							.checked_add(&convertible_amount)
+							.ok_or(Error::<T>::Overflow)?;
+						Ok(())
+					},
+				)?;
+			}
+
+			group.batches = batch_list.try_into().map_err(|_| Error::<T>::Overflow)?;
+
+			Self::deposit_event(Event::BatchVerified {
+				project_id,
+				group_id,
+				issuance_year,
+				convertible_amount,
+			});
+
+			Ok(())
+		})
+	}
+
+	/// Convert forward credits of verified batches to spot credits 1:1
+	pub fn do_convert_forward_credits(
+		who: T::AccountId,
+		project_id: T::ProjectId,
+		group_id: T::GroupId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::InsufficientConvertibleCredits);
+
+		let project = Projects::<T>::get(project_id).ok_or(Error::<T>::ProjectNotFound)?;
+		let group = project.batch_groups.get(&group_id).ok_or(Error::<T>::GroupNotFound)?;
+		let forward_asset_id =
+			ForwardAssetIds::<T>::get(project_id, group_id).ok_or(Error::<T>::NoForwardCredits)?;
+
+		ConvertibleForwardCredits::<T>::try_mutate(
+			project_id,
+			group_id,
+			|convertible| -> DispatchResult {
+				*convertible = convertible
+					.checked_sub(&amount)
+					.ok_or(Error::<T>::InsufficientConvertibleCredits)?;
+				Ok(())
+			},
+		)?;
+
+		// convert the forward credits of the oldest verified batches first
+		let mut remaining = amount;
+		for batch in group.batches.iter() {
+			let key = (project_id, group_id, batch.uuid.clone());
+			let unconverted = UnconvertedForwardCredits::<T>::get(&key);
+			let actual = cmp::min(unconverted, remaining);
+			if actual.is_zero() {
+				continue
+			}
+
+			// this is safe since actual is <= unconverted
+			let left = unconverted.checked_sub(&actual).ok_or(Error::<T>::Overflow)?;
+			if left.is_zero() {
+				UnconvertedForwardCredits::<T>::remove(&key);
+			} else {
+				UnconvertedForwardCredits::<T>::insert(&key, left);
+			}
+
+			// this is safe since actual is <= remaining
+			remaining = remaining.checked_sub(&actual).ok_or(Error::<T>::Overflow)?;
+			if remaining.is_zero() {
+				break
+			}
+		}
+
+		// this should not happen since the convertible amount is the sum of the batches
+		ensure!(remaining.is_zero(), Error::<T>::InsufficientConvertibleCredits);
+
+		// burn the forward credits and mint the spot credits
+		T::AssetHandler::burn_from(forward_asset_id, &who, amount)?;
+		T::AssetHandler::mint_into(group.asset_id, &who, amount)?;
+
+		Self::deposit_event(Event::ForwardCreditsConverted {
+			project_id,
+			group_id,
+			account: who,
+			amount,
+		});
+
+		Ok(())
+	}
+
 	/// Retire carbon credits for given project_id
 	pub fn retire_carbon_credits(
 		from: T::AccountId,
@@ -493,13 +1286,28 @@ impl<T: Config> Pallet<T> {
 		group_id: T::GroupId,
 		amount: T::Balance,
 	) -> DispatchResult {
-		let now = frame_system::Pallet::<T>::block_number();
-
 		if amount.is_zero() {
 			return Ok(())
 		}
 
-		Projects::<T>::try_mutate(project_id, |project| -> DispatchResult {
+		Self::do_retire_carbon_credits(from, project_id, group_id, amount, true)?;
+		Ok(())
+	}
+
+	/// Retire carbon credits for given project_id and return the (asset_id, item_id) key of the
+	/// retirement record in `RetiredCredits`. The certificate NFT is only minted to the caller if
+	/// `mint_certificate` is set, this allows callers to issue a single certificate for
+	/// retirements spanning multiple projects.
+	pub fn do_retire_carbon_credits(
+		from: T::AccountId,
+		project_id: T::ProjectId,
+		group_id: T::GroupId,
+		amount: T::Balance,
+		mint_certificate: bool,
+	) -> Result<(T::AssetId, T::ItemId), DispatchError> {
+		let now = frame_system::Pallet::<T>::block_number();
+
+		Projects::<T>::try_mutate(project_id, |project| -> Result<_, DispatchError> {
 			// ensure the project exists
 			let project = project.as_mut().ok_or(Error::<T>::ProjectNotFound)?;
 
@@ -527,10 +1335,21 @@ impl<T: Config> Pallet<T> {
 			let mut batch_retire_data_list: BatchRetireDataList<T> = Default::default();
 			let mut remaining = amount;
 			for batch in batch_list.iter_mut() {
+				// forward credits cannot be retired
+				if batch.status == BatchStatus::Projected {
+					continue
+				}
+
 				// lets retire from the older batches as much as possible
-				// this is safe since we ensure minted >= retired
-				let available_to_retire =
-					batch.minted.checked_sub(&batch.retired).ok_or(Error::<T>::Overflow)?;
+				// the forward credits of the batch that are not converted yet are not backed by
+				// spot credits, this is safe since we ensure minted >= retired + unconverted
+				let unconverted =
+					UnconvertedForwardCredits::<T>::get((project_id, group_id, batch.uuid.clone()));
+				let available_to_retire = batch
+					.minted
+					.checked_sub(&batch.retired)
+					.and_then(|x| x.checked_sub(&unconverted))
+					.ok_or(Error::<T>::Overflow)?;
 
 				let actual = cmp::min(available_to_retire, remaining);
 
@@ -585,7 +1404,9 @@ impl<T: Config> Pallet<T> {
 			};
 
 			// mint the NFT to caller
-			T::NFTHandler::mint_into(&group.asset_id, &item_id, &from)?;
+			if mint_certificate {
+				T::NFTHandler::mint_into(&group.asset_id, &item_id, &from)?;
+			}
 			// Increment the NextItemId storage
 			let next_item_id: T::ItemId =
 				item_id.checked_add(&One::one()).ok_or(Error::<T>::Overflow)?;
@@ -612,7 +1433,7 @@ impl<T: Config> Pallet<T> {
 				retire_data: batch_retire_data_list,
 			});
 
-			Ok(())
+			Ok((group.asset_id, item_id))
 		})
 	}
 }
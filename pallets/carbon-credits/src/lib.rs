@@ -7,6 +7,14 @@
 //! Credits. These onchain CarbonCredits units can represent a single type of CarbonCredits or can
 //! build to represent a combination of different types of Carbon Credits.
 //!
+//! Creating a project reserves a deposit from the originator, the deposit is scaled by the encoded
+//! size of the project data and is returned once the project is approved or withdrawn. Governance
+//! can slash the deposit of projects submitted as spam.
+//!
+//! The runtime can restrict the transfers of the CarbonCredits units with the
+//! `KycTransferCompliance` hook of pallet-assets, so the units can only be received by KYC
+//! verified accounts outside blocked jurisdictions and whitelisted pallet accounts.
+//!
 //! The CarbonCredits units are created by an account that controls CarbonCredit units, represented
 //! in the pallet as the originator. The creation process will store the CarbonCredits details on
 //! the pallet storage and then mint the given amount of CarbonCredits units using the Asset Handler
@@ -23,8 +31,13 @@
 //!
 //! * `create`: Creates a new project onchain with details of batches of credits
 //! * `resubmit`: Resubmit data for a project that has not been approved
+//! * `withdraw_project`: Withdraw a project that has not been approved and refund the deposit
 //! * `mint`: Mint a specified amount of token credits
 //! * `retire`: Burn a specified amount of token credits
+//! * `convert_forward_credits`: Convert forward credits of verified batches to spot credits
+//! * `update_project_details`: Update an approved project, the previous documents are recorded in
+//!   the revision log
+//! * `add_monitoring_report`: Add a monitoring report for a reporting period to an approved project
 //!
 //! ### Permissioned Functions
 //!
@@ -32,9 +45,16 @@
 //! * `force_remove_authorized_account`: Removes an authorized_account from the list
 //! * `force_set_next_asset_id`: Set the NextAssetId in storage
 //! * `approve_project`: Set the project status to approved so minting can be executed
+//! * `mint_forward`: Mint forward credits from the projected batches of a project
 //! * `force_set_project_storage` : Set the project storage
 //! * `force_set_next_item_id` : Set the NextItemId storage
 //! * `force_set_retired_carbon_credit` : Set the RetiredCarbonCredits storage
+//! * `extend_batch_group` : Add newly verified batches to an existing batch group
+//! * `split_batch_group` : Move the unminted credits of a batch to a new batch group
+//! * `force_slash_project_deposit` : Slash the creation deposit of a spam project
+//! * `verify_batch` : Mark a projected batch as verified so its forward credits can be converted
+//! * `force_remove_project` : Remove a project without credits in circulation and destroy its
+//!   assets and retirement certificate collections
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
@@ -55,18 +75,31 @@ mod functions;
 pub mod migration;
 pub use functions::*;
 
+mod compliance;
+pub use compliance::*;
+
 mod weights;
-use frame_support::traits::Contains;
+use primitives::{JurisdictionProvider, KycLevel, KycLevelProvider};
 pub use weights::WeightInfo;
 
+/// Helper to set up the KYC of accounts in the benchmarks of this pallet and the pallets using it
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId> {
+	/// Verify `who` at the given KYC level with the KYC provider of the runtime
+	fn set_kyc_level(who: &AccountId, level: KycLevel);
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use codec::HasCompact;
 	use frame_support::{
 		pallet_prelude::*,
-		traits::tokens::{
-			fungibles::{metadata::Mutate as MetadataMutate, Create, Destroy, Mutate},
-			nonfungibles::{Create as NFTCreate, Mutate as NFTMutate},
+		traits::{
+			tokens::{
+				fungibles::{metadata::Mutate as MetadataMutate, Create, Destroy, Mutate},
+				nonfungibles::{Create as NFTCreate, Destroy as NFTDestroy, Mutate as NFTMutate},
+			},
+			OnUnbalanced, ReservableCurrency,
 		},
 		transactional, PalletId,
 	};
@@ -166,10 +199,37 @@ pub mod pallet {
 
 		// NFT handler config
 		type NFTHandler: NFTCreate<Self::AccountId, CollectionId = Self::AssetId, ItemId = Self::ItemId>
-			+ NFTMutate<Self::AccountId>;
+			+ NFTMutate<Self::AccountId>
+			+ NFTDestroy<Self::AccountId>;
 
 		/// KYC provider config
-		type KYCProvider: Contains<Self::AccountId>;
+		type KYCProvider: KycLevelProvider<Self::AccountId> + JurisdictionProvider<Self::AccountId>;
+
+		/// Helper to verify the KYC of accounts in benchmarks
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: crate::BenchmarkHelper<Self::AccountId>;
+
+		/// The KYC level required to create and manage projects
+		#[pallet::constant]
+		type ProjectKycLevel: Get<KycLevel>;
+
+		/// The KYC level required to retire credits
+		#[pallet::constant]
+		type RetireKycLevel: Get<KycLevel>;
+
+		/// The currency used to reserve the project creation deposit
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The base deposit reserved from the originator when creating a project
+		#[pallet::constant]
+		type ProjectDepositBase: Get<CurrencyBalanceOf<Self>>;
+
+		/// The additional deposit reserved per byte of encoded project data
+		#[pallet::constant]
+		type ProjectDepositPerByte: Get<CurrencyBalanceOf<Self>>;
+
+		/// Handler for the deposits slashed from spam projects
+		type DepositSlashed: OnUnbalanced<NegativeImbalanceOf<Self>>;
 
 		/// The origin which may forcibly set storage or add authorised accounts
 		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
@@ -185,20 +245,30 @@ pub mod pallet {
 		type MaxLongStringLength: Get<u32>;
 		/// Maximum length of ipfs reference data
 		type MaxIpfsReferenceLength: Get<u32>;
-		/// Maximum amount of location cordinates to store
+		/// Maximum amount of coordinates in a project boundary
 		type MaxCoordinatesLength: Get<u32>;
 		/// Maximum count of documents for one type
 		type MaxDocumentCount: Get<u32>;
 		/// Maximum amount of carbon credits in a bundle
 		type MaxGroupSize: Get<u32> + TypeInfo + Clone + Parameter;
+		/// Maximum amount of revisions kept for a project
+		#[pallet::constant]
+		type MaxProjectRevisions: Get<u32>;
+		/// Maximum amount of monitoring reports for a project
+		#[pallet::constant]
+		type MaxMonitoringReports: Get<u32>;
 		/// Minimum value of AssetId for CarbonCredits
 		type MinProjectId: Get<Self::AssetId>;
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -228,12 +298,76 @@ pub mod pallet {
 	pub(super) type Projects<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::ProjectId, ProjectDetail<T>>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn project_deposits)]
+	/// The deposit reserved from the originator of a project pending approval
+	pub type ProjectDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ProjectId, CurrencyBalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn project_revisions)]
+	/// The log of document changes for a project
+	pub type ProjectRevisions<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ProjectId, ProjectRevisionListOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn monitoring_reports)]
+	/// The monitoring reports submitted for a project
+	pub type MonitoringReports<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ProjectId, MonitoringReportListOf<T>, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn asset_id_lookup)]
 	/// AssetId details for project/group
 	pub(super) type AssetIdLookup<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AssetId, (T::ProjectId, T::GroupId)>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn forward_asset_ids)]
+	/// AssetId of the forward credits for project/group
+	pub type ForwardAssetIds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ProjectId,
+		Blake2_128Concat,
+		T::GroupId,
+		T::AssetId,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn forward_asset_id_lookup)]
+	/// Project/group details for forward credits AssetId
+	pub type ForwardAssetIdLookup<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, (T::ProjectId, T::GroupId)>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn convertible_forward_credits)]
+	/// Amount of forward credits of verified batches that can be converted to spot credits
+	pub type ConvertibleForwardCredits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ProjectId,
+		Blake2_128Concat,
+		T::GroupId,
+		T::Balance,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn unconverted_forward_credits)]
+	/// Forward credits minted from a verified batch that have not been converted yet, keyed by the
+	/// uuid of the batch. These credits are not backed by spot credits and cannot be retired
+	pub type UnconvertedForwardCredits<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::ProjectId>,
+			NMapKey<Blake2_128Concat, T::GroupId>,
+			NMapKey<Blake2_128Concat, ShortStringOf<T>>,
+		),
+		T::Balance,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn retired_carbon_credits)]
 	/// The retired CarbonCredits record
@@ -246,6 +380,18 @@ pub mod pallet {
 		RetiredCarbonCreditsData<T>,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn archived_retired_carbon_credits)]
+	/// The retired CarbonCredits records of removed projects
+	pub type ArchivedRetiredCredits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ProjectId,
+		Blake2_128Concat,
+		(T::AssetId, T::ItemId),
+		RetiredCarbonCreditsData<T>,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -275,6 +421,18 @@ pub mod pallet {
 			/// The ProjectId of the approved project
 			project_id: T::ProjectId,
 		},
+		/// Project has been withdrawn by the originator
+		ProjectWithdrawn {
+			/// The ProjectId of the withdrawn project
+			project_id: T::ProjectId,
+		},
+		/// Project has been removed by ForceOrigin
+		ProjectRemoved {
+			/// The ProjectId of the removed project
+			project_id: T::ProjectId,
+			/// The AssetIds destroyed with the project
+			asset_ids: Vec<T::AssetId>,
+		},
 		// An amount of Carbon Credits was minted
 		CarbonCreditMinted {
 			/// The ProjectId of the minted CarbonCredits
@@ -286,6 +444,41 @@ pub mod pallet {
 			/// The amount of CarbonCredits units minted
 			amount: T::Balance,
 		},
+		// An amount of forward credits was minted
+		ForwardCreditMinted {
+			/// The ProjectId of the minted credits
+			project_id: T::ProjectId,
+			/// The GroupId of the minted credits
+			group_id: T::GroupId,
+			/// The AssetId of the forward credits
+			asset_id: T::AssetId,
+			/// The AccountId that received the minted credits
+			recipient: T::AccountId,
+			/// The amount of forward credits minted
+			amount: T::Balance,
+		},
+		/// A projected batch was verified
+		BatchVerified {
+			/// The ProjectId of the batch
+			project_id: T::ProjectId,
+			/// The GroupId of the batch
+			group_id: T::GroupId,
+			/// The issuance year of the verified batch
+			issuance_year: u16,
+			/// The amount of forward credits that became convertible
+			convertible_amount: T::Balance,
+		},
+		/// Forward credits were converted to spot credits
+		ForwardCreditsConverted {
+			/// The ProjectId of the credits
+			project_id: T::ProjectId,
+			/// The GroupId of the credits
+			group_id: T::GroupId,
+			/// The AccountId that converted the credits
+			account: T::AccountId,
+			/// The amount of credits converted
+			amount: T::Balance,
+		},
 		// An existing CarbonCredits was retired
 		CarbonCreditRetired {
 			/// The ProjectId of the retired CarbonCredits
@@ -306,6 +499,26 @@ pub mod pallet {
 			/// The ProjectId of the updated project
 			project_id: T::ProjectId,
 		},
+		/// A new revision of the project documents was recorded
+		ProjectRevisionRecorded {
+			/// The ProjectId of the updated project
+			project_id: T::ProjectId,
+			/// The version created by the update
+			version: u32,
+			/// Content hash of the replaced documents
+			previous_documents_hash: T::Hash,
+		},
+		/// A monitoring report was added to the project
+		MonitoringReportAdded {
+			/// The ProjectId of the project
+			project_id: T::ProjectId,
+			/// ipfs-hash of the report
+			ipfs_hash: IpfsLinkOf<T>,
+			/// Start of the reporting period
+			period_start: u64,
+			/// End of the reporting period
+			period_end: u64,
+		},
 		/// A new batch group was added to the project
 		BatchGroupAdded {
 			/// The ProjectId of the updated project
@@ -313,6 +526,48 @@ pub mod pallet {
 			/// GroupId of the new batch group
 			group_id: T::GroupId,
 		},
+		/// New batches were added to an existing batch group
+		BatchGroupExtended {
+			/// The ProjectId of the updated project
+			project_id: T::ProjectId,
+			/// GroupId of the extended batch group
+			group_id: T::GroupId,
+			/// The supply added to the group
+			added_supply: T::Balance,
+			/// The new total supply of the group
+			total_supply: T::Balance,
+		},
+		/// A batch was split from a batch group into a new group
+		BatchGroupSplit {
+			/// The ProjectId of the updated project
+			project_id: T::ProjectId,
+			/// GroupId of the group the batch was split from
+			group_id: T::GroupId,
+			/// GroupId of the newly created group
+			new_group_id: T::GroupId,
+			/// AssetId of the newly created group
+			asset_id: T::AssetId,
+			/// The supply moved to the new group
+			amount: T::Balance,
+		},
+		/// The creation deposit of a project was returned to the originator
+		ProjectDepositReleased {
+			/// The ProjectId of the project
+			project_id: T::ProjectId,
+			/// The originator of the project
+			who: T::AccountId,
+			/// The amount of deposit released
+			amount: CurrencyBalanceOf<T>,
+		},
+		/// The creation deposit of a project was slashed
+		ProjectDepositSlashed {
+			/// The ProjectId of the project
+			project_id: T::ProjectId,
+			/// The originator of the project
+			who: T::AccountId,
+			/// The amount of deposit slashed
+			amount: CurrencyBalanceOf<T>,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -326,6 +581,8 @@ pub mod pallet {
 		ProjectAlreadyExists,
 		/// Account failed KYC checks
 		KYCAuthorisationFailed,
+		/// The account belongs to a blocked jurisdiction
+		JurisdictionBlocked,
 		/// The account is not authorised
 		NotAuthorised,
 		/// The project cannot be created without credits
@@ -354,6 +611,40 @@ pub mod pallet {
 		GroupNotFound,
 		/// Can only update an approved project, use resubmit for rejected projects
 		CannotUpdateUnapprovedProject,
+		/// The batch does not exist in the group
+		BatchNotFound,
+		/// Max batches in a group exceeded
+		TooManyBatches,
+		/// The batch has no unminted credits that can be split
+		NoCreditsAvailableToSplit,
+		/// Cannot split the only batch of a group
+		CannotSplitLastBatch,
+		/// The project does not hold any deposit
+		NoProjectDeposit,
+		/// The batch has already been verified
+		BatchAlreadyVerified,
+		/// The group has no forward credits
+		NoForwardCredits,
+		/// Not enough forward credits of verified batches to convert
+		InsufficientConvertibleCredits,
+		/// The location of the project is not valid
+		InvalidLocation,
+		/// The reporting period must end after it starts
+		InvalidReportingPeriod,
+		/// Max monitoring reports for the project exceeded
+		TooManyMonitoringReports,
+		/// Cannot remove a project with credits in circulation
+		CreditsInCirculation,
+		/// No batches were given to extend the group with
+		NoBatchesToAdd,
+		/// Cannot add a batch that has zero supply
+		BatchSupplyIsZero,
+		/// Cannot add a batch that already has minted or retired credits
+		BatchAlreadyIssued,
+		/// The project has more retirement records than the given witness
+		InvalidRetirementWitness,
+		/// Two batches of a group cannot have the same uuid
+		DuplicateBatchUuid,
 	}
 
 	#[pallet::call]
@@ -364,7 +655,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::create())]
 		pub fn create(origin: OriginFor<T>, params: ProjectCreateParams<T>) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			Self::check_kyc_approval(&sender)?;
+			Self::check_kyc_approval(&sender, T::ProjectKycLevel::get())?;
 			let project_id = Self::create_project(sender, params)?;
 			// emit event
 			Self::deposit_event(Event::ProjectCreated { project_id });
@@ -381,10 +672,19 @@ pub mod pallet {
 			params: ProjectCreateParams<T>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			Self::check_kyc_approval(&sender)?;
+			Self::check_kyc_approval(&sender, T::ProjectKycLevel::get())?;
 			Self::resubmit_project(sender, project_id, params)
 		}
 
+		/// Withdraw a project that has not been approved
+		/// The project is removed and the creation deposit is returned to the originator
+		#[transactional]
+		#[pallet::weight(T::WeightInfo::withdraw_project())]
+		pub fn withdraw_project(origin: OriginFor<T>, project_id: T::ProjectId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_withdraw_project(sender, project_id)
+		}
+
 		/// Set the project status to approve/reject
 		#[transactional]
 		#[pallet::weight(T::WeightInfo::approve_project())]
@@ -413,7 +713,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			Self::check_authorized_account(&sender)?;
-			// Self::check_kyc_approval(&sender)?;
+			// Self::check_kyc_approval(&sender, T::ProjectKycLevel::get())?;
 			Self::mint_carbon_credits(
 				sender,
 				project_id,
@@ -423,6 +723,38 @@ pub mod pallet {
 			)
 		}
 
+		/// Mint forward credits from the projected batches of an approved project
+		/// The forward credits are minted in the ascending order of issuance year of the projected
+		/// batches and can be converted 1:1 to spot credits once the batch is verified
+		#[transactional]
+		#[pallet::weight(T::WeightInfo::mint_forward())]
+		pub fn mint_forward(
+			origin: OriginFor<T>,
+			project_id: T::ProjectId,
+			group_id: T::GroupId,
+			amount_to_mint: T::Balance,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::check_authorized_account(&sender)?;
+			Self::mint_forward_credits(project_id, group_id, amount_to_mint)
+		}
+
+		/// Convert forward credits of verified batches to spot credits
+		/// The forward credits are converted in the ascending order of issuance year of the
+		/// verified batches, any holder verified at the `RetireKycLevel` can convert
+		#[transactional]
+		#[pallet::weight(T::WeightInfo::convert_forward_credits())]
+		pub fn convert_forward_credits(
+			origin: OriginFor<T>,
+			project_id: T::ProjectId,
+			group_id: T::GroupId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::check_kyc_approval(&sender, T::RetireKycLevel::get())?;
+			Self::do_convert_forward_credits(sender, project_id, group_id, amount)
+		}
+
 		/// Retire existing credits from owner
 		/// The tokens are always retired in the ascending order of credits, for example, if the
 		/// `amount` is 150 and the project has 100 tokens of 2019 and 2020 year. Then we retire
@@ -436,7 +768,8 @@ pub mod pallet {
 			amount: T::Balance,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			Self::check_kyc_approval(&sender)?;
+			Self::check_kyc_approval(&sender, T::RetireKycLevel::get())?;
+			Self::check_jurisdiction(&sender)?;
 			Self::retire_carbon_credits(sender, project_id, group_id, amount)
 		}
 
@@ -554,7 +887,7 @@ pub mod pallet {
 			group_id: T::GroupId,
 		) -> DispatchResult {
 			T::ForceOrigin::ensure_origin(origin)?;
-			Self::check_kyc_approval(&sender)?;
+			Self::check_kyc_approval(&sender, T::ProjectKycLevel::get())?;
 			Self::do_approve_project(project_id, true)?;
 			Self::mint_carbon_credits(
 				sender,
@@ -566,25 +899,20 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Force remove an project asset from storage, can be used by ForceOrigin to remove
-		/// unapproved projects Can only be called by ForceOrigin
+		/// Force remove a project and its assets from storage, the project cannot have any
+		/// credits in circulation, the retirement records are moved to ArchivedRetiredCredits
+		/// and the retirement certificate collections are destroyed.
+		/// `retired_items` is the upper bound of the number of retirement records of the project
+		/// Can only be called by ForceOrigin
 		#[transactional]
-		#[pallet::weight(T::WeightInfo::force_set_project_storage())]
+		#[pallet::weight(T::WeightInfo::force_remove_project(*retired_items))]
 		pub fn force_remove_project(
 			origin: OriginFor<T>,
 			project_id: T::ProjectId,
+			retired_items: u32,
 		) -> DispatchResult {
 			T::ForceOrigin::ensure_origin(origin)?;
-			let project = Projects::<T>::get(project_id).ok_or(Error::<T>::ProjectNotFound)?;
-			// remove all assets connected to this project
-			for (_group_id, group) in project.batch_groups.iter() {
-				let destroy_witness = T::AssetHandler::get_destroy_witness(&group.asset_id)
-					.ok_or(Error::<T>::ProjectNotFound)?;
-				T::AssetHandler::destroy(group.asset_id, destroy_witness, None)?;
-			}
-			// remove project from storage
-			Projects::<T>::take(project_id);
-			Ok(())
+			Self::do_remove_project(project_id, retired_items)
 		}
 
 		/// Modify the details of an approved project
@@ -597,10 +925,26 @@ pub mod pallet {
 			params: ProjectCreateParams<T>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			Self::check_kyc_approval(&sender)?;
+			Self::check_kyc_approval(&sender, T::ProjectKycLevel::get())?;
 			Self::update_project(sender, project_id, params)
 		}
 
+		/// Add a monitoring report to an approved project without modifying the project details
+		/// Can only be called by the ProjectOwner
+		#[transactional]
+		#[pallet::weight(T::WeightInfo::add_monitoring_report())]
+		pub fn add_monitoring_report(
+			origin: OriginFor<T>,
+			project_id: T::ProjectId,
+			ipfs_hash: IpfsLinkOf<T>,
+			period_start: u64,
+			period_end: u64,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::check_kyc_approval(&sender, T::ProjectKycLevel::get())?;
+			Self::do_add_monitoring_report(sender, project_id, ipfs_hash, period_start, period_end)
+		}
+
 		/// Add a new batch group to the project
 		/// Can only be called by the ProjectOwner
 		#[transactional]
@@ -611,9 +955,68 @@ pub mod pallet {
 			batch_group: BatchGroupOf<T>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			Self::check_kyc_approval(&sender)?;
+			Self::check_kyc_approval(&sender, T::ProjectKycLevel::get())?;
 			Self::do_add_batch_group(sender, project_id, batch_group)
 		}
+
+		/// Add newly verified batches to an existing batch group of an approved project
+		/// The supply of the group is increased, existing tokens of the group are not affected
+		/// Can only be called by ForceOrigin
+		#[transactional]
+		#[pallet::weight(T::WeightInfo::extend_batch_group())]
+		pub fn extend_batch_group(
+			origin: OriginFor<T>,
+			project_id: T::ProjectId,
+			group_id: T::GroupId,
+			batches: BatchListOf<T>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::do_extend_batch_group(project_id, group_id, batches)
+		}
+
+		/// Split the unminted credits of a batch into a new batch group with its own asset
+		/// Can only be called by ForceOrigin
+		#[transactional]
+		#[pallet::weight(T::WeightInfo::split_batch_group())]
+		pub fn split_batch_group(
+			origin: OriginFor<T>,
+			project_id: T::ProjectId,
+			group_id: T::GroupId,
+			batch_index: u32,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::do_split_batch_group(project_id, group_id, batch_index)
+		}
+
+		/// Mark a projected batch of an approved project as verified, the forward credits minted
+		/// from the batch can be converted 1:1 to spot credits and its unminted supply can be
+		/// minted as spot credits with `mint`
+		/// Can only be called by ForceOrigin
+		#[transactional]
+		#[pallet::weight(T::WeightInfo::verify_batch())]
+		pub fn verify_batch(
+			origin: OriginFor<T>,
+			project_id: T::ProjectId,
+			group_id: T::GroupId,
+			batch_index: u32,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::do_verify_batch(project_id, group_id, batch_index)
+		}
+
+		/// Slash the creation deposit of a project that was submitted as spam, the project is
+		/// removed unless assets were created for it before it was rejected
+		/// The slashed amount is handled by `DepositSlashed`
+		/// Can only be called by ForceOrigin
+		#[transactional]
+		#[pallet::weight(T::WeightInfo::force_slash_project_deposit())]
+		pub fn force_slash_project_deposit(
+			origin: OriginFor<T>,
+			project_id: T::ProjectId,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::slash_project_deposit(project_id)
+		}
 	}
 }
 
@@ -626,6 +1029,6 @@ impl<T: Config> primitives::CarbonCreditsValidator for Pallet<T> {
 	type AssetId = T::AssetId;
 
 	fn get_project_details(asset_id: &Self::AssetId) -> Option<(Self::ProjectId, Self::GroupId)> {
-		AssetIdLookup::<T>::get(asset_id)
+		AssetIdLookup::<T>::get(asset_id).or_else(|| ForwardAssetIdLookup::<T>::get(asset_id))
 	}
 }
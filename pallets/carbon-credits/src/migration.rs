@@ -1,61 +1,193 @@
 use super::*;
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::BoundedVec;
+use frame_support::BoundedBTreeMap;
+use primitives::BatchGroup;
 use scale_info::TypeInfo;
 
-pub mod v1 {
+pub mod v2 {
 	use super::*;
 	use crate::types::ProjectDetail;
 
 	use frame_support::{
-		pallet_prelude::Weight,
-		traits::{Get, OnRuntimeUpgrade},
+		pallet_prelude::{StorageVersion, Weight},
+		traits::{Get, GetStorageVersion, OnRuntimeUpgrade},
 	};
+	use primitives::{Batch, BatchStatus, Coordinate, Geometry, Location};
+	use sp_std::vec::Vec;
 
-	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
-	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+	mod old {
+		use super::*;
+		use frame_support::{storage_alias, Blake2_128Concat};
+
+		/// The projects storage before v2
+		#[storage_alias]
+		pub type Projects<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, <T as Config>::ProjectId, OldProjectDetail<T>>;
+	}
+
+	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
 		fn on_runtime_upgrade() -> Weight {
-			log::info!("MIGRATION : About to execute carbon-credits migration!");
-
-			// convert the project type to new format
-			Projects::<T>::translate::<OldProjectDetail<T>, _>(
-				|_key, old| -> Option<ProjectDetail<T>> {
-					let converted_project = ProjectDetail {
-						originator: old.originator,
-						name: old.name,
-						description: old.description,
-						location: Default::default(), /* set the location as default string since
-						                               * we don't have any live projects yet */
-						images: old.images,
-						videos: old.videos,
-						documents: old.documents,
-						registry_details: old.registry_details,
-						sdg_details: old.sdg_details,
-						royalties: old.royalties,
-						batch_groups: old.batch_groups,
-						created: old.created,
-						updated: old.updated,
-						approved: old.approved,
-					};
-					Some(converted_project)
-				},
-			);
+			if Pallet::<T>::on_chain_storage_version() >= 2 {
+				log::info!("MIGRATION : carbon-credits migration V2 already executed, skipping!");
+				return T::DbWeight::get().reads(1)
+			}
+
+			log::info!("MIGRATION : About to execute carbon-credits migration V2!");
+
+			let mut count: u64 = 0;
+			let mut converted: u64 = 0;
+
+			// convert the free-form location to the structured location and set the status of
+			// existing batches to verified
+			let old_projects: Vec<_> = old::Projects::<T>::iter().collect();
+			for (project_id, old) in old_projects {
+				count += 1;
 
-			log::info!("MIGRATION : Carbon credits migration complete!");
+				// the bounds of the old and new types are the same so this is not expected to
+				// fail, if it does the entry is kept as is to be fixed by
+				// `force_set_project_storage`
+				let batch_groups = match convert_batch_groups::<T>(old.batch_groups) {
+					Some(batch_groups) => batch_groups,
+					None => {
+						log::error!(
+							"MIGRATION : Failed to convert the batch groups of project {:?}, entry kept unchanged!",
+							project_id
+						);
+						continue
+					},
+				};
+
+				// the free-form location cannot be converted to coordinates, we keep the text
+				// as region, the originator can set the correct location with an update
+				let mut region = old.location.into_inner();
+				region.truncate(T::MaxShortStringLength::get() as usize);
+
+				let converted_project = ProjectDetail {
+					originator: old.originator,
+					name: old.name,
+					description: old.description,
+					location: Location {
+						geometry: Geometry::Point(Coordinate::default()),
+						country: None,
+						region: region.try_into().ok().filter(|x: &ShortStringOf<T>| !x.is_empty()),
+					},
+					images: old.images,
+					videos: old.videos,
+					documents: old.documents,
+					registry_details: old.registry_details,
+					sdg_details: old.sdg_details,
+					credit_type: Default::default(),
+					royalties: old.royalties,
+					batch_groups,
+					created: old.created,
+					updated: old.updated,
+					approved: old.approved,
+				};
+
+				crate::Projects::<T>::insert(project_id, converted_project);
+				converted += 1;
+			}
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!(
+				"MIGRATION : Carbon credits migration V2 complete, {} of {} projects converted!",
+				converted,
+				count
+			);
 
-			T::DbWeight::get().reads_writes(1, 1)
+			T::DbWeight::get().reads_writes(count.saturating_add(1), converted.saturating_add(1))
 		}
 
 		#[cfg(feature = "try-runtime")]
 		fn post_upgrade() -> Result<(), &'static str> {
 			// new version must be set.
-			assert_eq!(Pallet::<T>::on_chain_storage_version(), 1);
+			assert_eq!(Pallet::<T>::on_chain_storage_version(), 2);
 			Ok(())
 		}
 	}
+
+	/// Convert the batch groups to the new format, all existing batches are verified
+	fn convert_batch_groups<T: Config>(old: OldBatchGroupMapOf<T>) -> Option<BatchGroupMapOf<T>> {
+		let mut batch_groups: BatchGroupMapOf<T> = Default::default();
+
+		for (group_id, group) in old.into_iter() {
+			let batches: Vec<BatchOf<T>> = group
+				.batches
+				.into_iter()
+				.map(|batch| Batch {
+					name: batch.name,
+					uuid: batch.uuid,
+					issuance_year: batch.issuance_year,
+					start_date: batch.start_date,
+					end_date: batch.end_date,
+					total_supply: batch.total_supply,
+					minted: batch.minted,
+					retired: batch.retired,
+					status: BatchStatus::Verified,
+				})
+				.collect();
+
+			let new_group = BatchGroupOf::<T> {
+				name: group.name,
+				uuid: group.uuid,
+				asset_id: group.asset_id,
+				total_supply: group.total_supply,
+				minted: group.minted,
+				retired: group.retired,
+				batches: batches.try_into().ok()?,
+			};
+
+			batch_groups.try_insert(group_id, new_group).ok()?;
+		}
+
+		Some(batch_groups)
+	}
 }
 
-/// Details of the project stored on-chain
+/// Batch stored on-chain before v2, without the verification status
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OldBatch<StringType, Balance> {
+	/// Descriptive name for this batch of credits
+	pub name: StringType,
+	/// UUID for this batch, usually provided by the registry
+	pub uuid: StringType,
+	/// The year the associated credits were issued
+	pub issuance_year: u16,
+	/// start date for multi year batch
+	pub start_date: u16,
+	/// end date for multi year batch
+	pub end_date: u16,
+	/// The total_supply of the credits
+	pub total_supply: Balance,
+	/// The amount of tokens minted for this batch
+	pub minted: Balance,
+	/// The amount of tokens retired for this batch
+	pub retired: Balance,
+}
+
+/// Type of batch before v2
+pub type OldBatchOf<T> = OldBatch<ShortStringOf<T>, <T as pallet::Config>::Balance>;
+
+/// Type of group before v2
+pub type OldBatchGroupOf<T> = BatchGroup<
+	ShortStringOf<T>,
+	<T as pallet::Config>::AssetId,
+	<T as pallet::Config>::Balance,
+	OldBatchOf<T>,
+	<T as pallet::Config>::MaxGroupSize,
+>;
+
+/// Map of groups before v2
+pub type OldBatchGroupMapOf<T> = BoundedBTreeMap<
+	<T as pallet::Config>::GroupId,
+	OldBatchGroupOf<T>,
+	<T as pallet::Config>::MaxGroupSize,
+>;
+
+/// Details of the project stored on-chain before v2, the location is a free-form string
 #[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(T))]
 #[codec(mel_bound(T: pallet::Config))]
@@ -69,7 +201,7 @@ pub struct OldProjectDetail<T: pallet::Config> {
 	/// Description of the project
 	pub description: LongStringOf<T>,
 	/// Location co-ordinates of thie project
-	pub location: BoundedVec<(u32, u32), <T as pallet::Config>::MaxCoordinatesLength>,
+	pub location: LongStringOf<T>,
 	/// List of ipfs-hashes of images related to the project
 	pub images: IpfsLinkListsOf<T>,
 	/// List of ipfs-hashes of videos related to the project
@@ -83,7 +215,7 @@ pub struct OldProjectDetail<T: pallet::Config> {
 	/// The royalties to be paid when tokens are purchased
 	pub royalties: Option<RoyaltyRecipientsOf<T>>,
 	/// groups included in the project
-	pub batch_groups: BatchGroupMapOf<T>,
+	pub batch_groups: OldBatchGroupMapOf<T>,
 	// origination details
 	/// Creation time of project
 	pub created: T::BlockNumber,
@@ -5,11 +5,14 @@ use crate as pallet_carbon_credits;
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	bounded_vec, parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, Everything, GenesisBuild},
+	traits::{
+		AsEnsureOriginWithArg, ConstU128, ConstU32, Contains, Everything, GenesisBuild, Nothing,
+	},
 	PalletId,
 };
 use frame_system as system;
 use frame_system::EnsureRoot;
+use primitives::{CountryCode, JurisdictionProvider, KycLevel, KycLevelProvider};
 use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::{
@@ -105,6 +108,7 @@ impl pallet_assets::Config for Test {
 	type Extra = ();
 	type ForceOrigin = frame_system::EnsureRoot<u64>;
 	type Freezer = ();
+	type Compliance = pallet_carbon_credits::KycTransferCompliance<Test, Nothing>;
 	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
 	type MetadataDepositBase = MetadataDepositBase;
 	type MetadataDepositPerByte = MetadataDepositPerByte;
@@ -123,28 +127,71 @@ impl pallet_timestamp::Config for Test {
 	type WeightInfo = ();
 }
 
+/// KYC provider for tests, the members of `KYCMembership` are verified at the institutional level
+/// and account 7 is verified at the basic level in a blocked jurisdiction
+pub struct MockKycProvider;
+impl KycLevelProvider<u64> for MockKycProvider {
+	fn kyc_level(who: &u64) -> Option<KycLevel> {
+		if KYCMembership::contains(who) {
+			return Some(KycLevel::Institutional)
+		}
+		if *who == 7 {
+			return Some(KycLevel::Basic)
+		}
+		None
+	}
+}
+
+impl JurisdictionProvider<u64> for MockKycProvider {
+	fn country_code(who: &u64) -> Option<CountryCode> {
+		(*who == 7).then_some(*b"KP")
+	}
+
+	fn is_jurisdiction_blocked(country_code: &CountryCode) -> bool {
+		country_code == b"KP"
+	}
+}
+
+/// Benchmark helper for tests, every verified account is added to `KYCMembership`
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_carbon_credits::BenchmarkHelper<u64> for MockBenchmarkHelper {
+	fn set_kyc_level(who: &u64, _level: KycLevel) {
+		let _ = KYCMembership::add_member(RuntimeOrigin::root(), *who);
+	}
+}
+
 parameter_types! {
   pub const MarketplaceEscrowAccount : u64 = 10;
   pub const CarbonCreditsPalletId: PalletId = PalletId(*b"bitg/ccp");
   pub CarbonCreditsPalletAcccount : u64 = PalletId(*b"bitg/ccp").into_account_truncating();
   #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, MaxEncodedLen, TypeInfo, Debug)]
   pub const MaxGroupSize: u32 = 10;
+  pub const ProjectKycLevel: KycLevel = KycLevel::Institutional;
+  pub const RetireKycLevel: KycLevel = KycLevel::Basic;
 }
 
 impl pallet_carbon_credits::Config for Test {
 	type AssetHandler = Assets;
 	type AssetId = u32;
 	type Balance = u128;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockBenchmarkHelper;
 	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type DepositSlashed = ();
 	type ForceOrigin = frame_system::EnsureRoot<u64>;
 	type ItemId = u32;
 	type ProjectId = u32;
 	type GroupId = u32;
-	type KYCProvider = KYCMembership;
+	type KYCProvider = MockKycProvider;
 	type MarketplaceEscrow = MarketplaceEscrowAccount;
 	type MaxAuthorizedAccountCount = ConstU32<2>;
 	type MaxDocumentCount = ConstU32<2>;
 	type MaxGroupSize = MaxGroupSize;
+	type MaxMonitoringReports = ConstU32<2>;
+	type MaxProjectRevisions = ConstU32<2>;
 	type MaxIpfsReferenceLength = ConstU32<20>;
 	type MaxLongStringLength = ConstU32<100>;
 	type MaxCoordinatesLength = ConstU32<8>;
@@ -153,6 +200,10 @@ impl pallet_carbon_credits::Config for Test {
 	type MinProjectId = ConstU32<1000>;
 	type NFTHandler = Uniques;
 	type PalletId = CarbonCreditsPalletId;
+	type ProjectDepositBase = ConstU128<100>;
+	type ProjectDepositPerByte = ConstU128<1>;
+	type ProjectKycLevel = ProjectKycLevel;
+	type RetireKycLevel = RetireKycLevel;
 	type WeightInfo = ();
 }
 
@@ -192,6 +243,12 @@ impl pallet_membership::Config for Test {
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
 
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 1_000_000), (3, 1_000_000), (10, 1_000_000)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
 	pallet_membership::GenesisConfig::<Test> {
 		members: bounded_vec![1, 3, 10],
 		..Default::default()
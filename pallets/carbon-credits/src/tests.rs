@@ -8,13 +8,17 @@ use frame_support::{
 	BoundedVec, PalletId,
 };
 use frame_system::RawOrigin;
-use primitives::{Batch, RegistryDetails, RegistryName, Royalty, SDGDetails, SdgType};
+use primitives::{
+	Batch, BatchStatus, CarbonCreditsValidator, Coordinate, CreditType, Geometry, Location,
+	RegistryDetails, RegistryName, Royalty, SDGDetails, SdgType,
+};
 use sp_runtime::{traits::AccountIdConversion, Percent};
 use sp_std::convert::TryInto;
 
 use crate::{
-	mock::*, BatchGroupListOf, BatchGroupOf, BatchOf, Config, Error, NextItemId,
-	ProjectCreateParams, Projects, RegistryListOf, RetiredCredits, SDGTypesListOf,
+	mock::*, BatchGroupListOf, BatchGroupOf, BatchListOf, BatchOf, Config, Error, ForwardAssetIds,
+	IpfsLinkOf, LocationOf, NextItemId, ProjectCreateParams, ProjectDeposits, Projects,
+	RegistryListOf, RetiredCredits, SDGTypesListOf,
 };
 
 pub type CarbonCreditsEvent = crate::Event<Test>;
@@ -53,6 +57,7 @@ fn get_single_batch_list<T: Config>() -> BoundedVec<BatchOf<T>, T::MaxGroupSize>
 		total_supply: 100_u32.into(),
 		minted: 0_u32.into(),
 		retired: 0_u32.into(),
+		status: BatchStatus::Verified,
 	}]
 	.try_into()
 	.unwrap()
@@ -69,6 +74,7 @@ fn get_multiple_batch_list<T: Config>() -> BoundedVec<BatchOf<T>, T::MaxGroupSiz
 			total_supply: 100_u32.into(),
 			minted: 0_u32.into(),
 			retired: 0_u32.into(),
+			status: BatchStatus::Verified,
 		},
 		Batch {
 			name: "batch_name_2".as_bytes().to_vec().try_into().unwrap(),
@@ -79,6 +85,7 @@ fn get_multiple_batch_list<T: Config>() -> BoundedVec<BatchOf<T>, T::MaxGroupSiz
 			total_supply: 100_u32.into(),
 			minted: 0_u32.into(),
 			retired: 0_u32.into(),
+			status: BatchStatus::Verified,
 		},
 	]
 	.try_into()
@@ -178,6 +185,24 @@ fn create_and_approve_project_batch(originator_account: u64, authorised_account:
 	),);
 }
 
+/// helper function to generate standard location
+fn get_default_location<T: Config>() -> LocationOf<T> {
+	Location {
+		geometry: Geometry::Polygon(
+			vec![
+				Coordinate { latitude: 1_000_000, longitude: 1_000_000 },
+				Coordinate { latitude: 1_000_000, longitude: 2_000_000 },
+				Coordinate { latitude: 2_000_000, longitude: 2_000_000 },
+				Coordinate { latitude: 2_000_000, longitude: 1_000_000 },
+			]
+			.try_into()
+			.unwrap(),
+		),
+		country: Some(*b"BR"),
+		region: Some("region".as_bytes().to_vec().try_into().unwrap()),
+	}
+}
+
 /// helper function to generate standard creation details
 fn get_default_creation_params<T: Config>() -> ProjectCreateParams<T>
 where
@@ -191,7 +216,7 @@ where
 	let creation_params = ProjectCreateParams {
 		name: "name".as_bytes().to_vec().try_into().unwrap(),
 		description: "description".as_bytes().to_vec().try_into().unwrap(),
-		location: "(1, 1), (2, 2), (3, 3), (4, 4)".as_bytes().to_vec().try_into().unwrap(),
+		location: get_default_location::<T>(),
 		images: vec!["image_link".as_bytes().to_vec().try_into().unwrap()].try_into().unwrap(),
 		videos: vec!["video_link".as_bytes().to_vec().try_into().unwrap()].try_into().unwrap(),
 		documents: vec!["document_link".as_bytes().to_vec().try_into().unwrap()]
@@ -199,6 +224,7 @@ where
 			.unwrap(),
 		registry_details: get_default_registry_details::<T>(),
 		sdg_details: get_default_sdg_details::<T>(),
+		credit_type: CreditType::Avoidance,
 		royalties: Some(vec![royalty].try_into().unwrap()),
 		batch_groups: get_default_batch_group::<T>(),
 	};
@@ -345,6 +371,24 @@ fn create_works_for_multiple_batch() {
 	});
 }
 
+#[test]
+fn create_fails_for_duplicate_batch_uuids() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 1;
+
+		let mut creation_params = get_default_creation_params::<Test>();
+		// replace the default with mutiple batches sharing the same uuid
+		let mut batch_group = get_multiple_batch_group::<Test>();
+		batch_group[0].batches[1].uuid = "batch_uuid".as_bytes().to_vec().try_into().unwrap();
+		creation_params.batch_groups = batch_group;
+
+		assert_noop!(
+			CarbonCredits::create(RawOrigin::Signed(originator_account).into(), creation_params),
+			Error::<Test>::DuplicateBatchUuid
+		);
+	});
+}
+
 #[test]
 fn create_fails_for_multiple_batch_with_single_batch_supply_zero() {
 	new_test_ext().execute_with(|| {
@@ -362,6 +406,7 @@ fn create_fails_for_multiple_batch_with_single_batch_supply_zero() {
 				total_supply: 0_u32.into(), // this should be rejected
 				minted: 0_u32.into(),
 				retired: 0_u32.into(),
+				status: BatchStatus::Verified,
 			},
 			Batch {
 				name: "batch_name_2".as_bytes().to_vec().try_into().unwrap(),
@@ -372,6 +417,7 @@ fn create_fails_for_multiple_batch_with_single_batch_supply_zero() {
 				total_supply: 100_u32.into(),
 				minted: 0_u32.into(),
 				retired: 0_u32.into(),
+				status: BatchStatus::Verified,
 			},
 		]
 		.try_into()
@@ -614,12 +660,61 @@ fn cleanup_after_project_reject_works() {
 			CarbonCreditsEvent::ProjectApproved { project_id, asset_ids: vec![0u32] }.into()
 		);
 
+		// mint and retire credits
+		assert_ok!(CarbonCredits::mint(
+			RawOrigin::Signed(authorised_account).into(),
+			project_id,
+			0,
+			100,
+			false
+		));
+
+		// cannot remove a project with credits in circulation
+		assert_noop!(
+			CarbonCredits::force_remove_project(RawOrigin::Root.into(), project_id, 1),
+			Error::<Test>::CreditsInCirculation
+		);
+
+		assert_ok!(CarbonCredits::retire(
+			RawOrigin::Signed(originator_account).into(),
+			project_id,
+			0,
+			100
+		));
+		let retired_data = RetiredCredits::<Test>::get(0, 0).unwrap();
+		assert_eq!(Uniques::owner(0, 0), Some(originator_account));
+
+		// the witness must cover the retirement records
+		assert_noop!(
+			CarbonCredits::force_remove_project(RawOrigin::Root.into(), project_id, 0),
+			Error::<Test>::InvalidRetirementWitness
+		);
+
 		// remove the project from storage
-		assert_ok!(CarbonCredits::force_remove_project(RawOrigin::Root.into(), project_id,),);
+		assert_ok!(CarbonCredits::force_remove_project(RawOrigin::Root.into(), project_id, 1));
+
+		assert_eq!(
+			last_event(),
+			CarbonCreditsEvent::ProjectRemoved { project_id, asset_ids: vec![0u32] }.into()
+		);
 
 		// ensure storage is cleaned
 		assert_eq!(CarbonCredits::get_project_details(project_id), None);
 		assert_eq!(Assets::total_issuance(0), 0);
+		assert!(Assets::name(0).is_empty());
+		assert_eq!(CarbonCredits::asset_id_lookup(0), None);
+		assert_eq!(CarbonCredits::next_item_id(0), None);
+
+		// the retirement certificate collection is destroyed
+		assert_eq!(Uniques::owner(0, 0), None);
+		assert_eq!(Uniques::collection_owner(0), None);
+
+		// the retirement records are archived
+		assert_eq!(RetiredCredits::<Test>::get(0, 0), None);
+		assert_eq!(
+			CarbonCredits::archived_retired_carbon_credits(project_id, (0, 0)),
+			Some(retired_data)
+		);
 	});
 }
 
@@ -790,15 +885,33 @@ fn mint_without_list_to_marketplace_works_for_single_batch() {
 		assert_eq!(Assets::symbol(expected_asset_id), "0".as_bytes().to_vec());
 		assert_eq!(Assets::decimals(expected_asset_id), 0_u8);
 
-		// the originator can freely transfer the tokens
+		// the tokens can only be transferred to kyc accounts outside blocked jurisdictions
+		assert_noop!(
+			Assets::transfer(
+				RawOrigin::Signed(originator_account).into(),
+				expected_asset_id,
+				2,
+				amount_to_mint - 1
+			),
+			pallet_assets::Error::<Test>::NotCompliant
+		);
+		assert_noop!(
+			Assets::transfer(
+				RawOrigin::Signed(originator_account).into(),
+				expected_asset_id,
+				7,
+				amount_to_mint - 1
+			),
+			pallet_assets::Error::<Test>::NotCompliant
+		);
 		assert_ok!(Assets::transfer(
 			RawOrigin::Signed(originator_account).into(),
 			expected_asset_id,
-			2,
+			3,
 			amount_to_mint - 1
 		));
 		assert_eq!(Assets::balance(expected_asset_id, originator_account), 1);
-		assert_eq!(Assets::balance(expected_asset_id, 2), amount_to_mint - 1);
+		assert_eq!(Assets::balance(expected_asset_id, 3), amount_to_mint - 1);
 
 		// the originator cannot burn the tokens or mint more tokens
 		assert_noop!(
@@ -890,15 +1003,15 @@ fn mint_without_list_to_marketplace_works_for_multiple_batches() {
 		assert_eq!(Assets::minimum_balance(expected_asset_id), 1);
 		assert_eq!(Assets::balance(expected_asset_id, originator_account), amount_to_mint);
 
-		// the originator can freely transfer the tokens
+		// the originator can transfer the tokens to kyc accounts
 		assert_ok!(Assets::transfer(
 			RawOrigin::Signed(originator_account).into(),
 			expected_asset_id,
-			2,
+			3,
 			amount_to_mint - 1
 		));
 		assert_eq!(Assets::balance(expected_asset_id, originator_account), 1);
-		assert_eq!(Assets::balance(expected_asset_id, 2), amount_to_mint - 1);
+		assert_eq!(Assets::balance(expected_asset_id, 3), amount_to_mint - 1);
 
 		// the originator cannot burn the tokens or mint more tokens
 		assert_noop!(
@@ -1596,3 +1709,1075 @@ fn add_batch_group_works() {
 		assert_eq!(group_data.retired, 0_u32.into());
 	});
 }
+
+#[test]
+fn extend_batch_group_works() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 1;
+		let authorised_account = 10;
+		let project_id = 0;
+		let group_id = 0;
+
+		let new_batches: BatchListOf<Test> = vec![Batch {
+			name: "batch_name_3".as_bytes().to_vec().try_into().unwrap(),
+			uuid: "batch_uuid_3".as_bytes().to_vec().try_into().unwrap(),
+			issuance_year: 2022_u16,
+			start_date: 2022_u16,
+			end_date: 2022_u16,
+			total_supply: 50_u32.into(),
+			minted: 0_u32.into(),
+			retired: 0_u32.into(),
+			status: BatchStatus::Verified,
+		}]
+		.try_into()
+		.unwrap();
+
+		// unapproved project cannot be extended
+		let creation_params = get_default_creation_params::<Test>();
+		assert_ok!(CarbonCredits::create(
+			RawOrigin::Signed(originator_account).into(),
+			creation_params
+		));
+		assert_noop!(
+			CarbonCredits::extend_batch_group(
+				RawOrigin::Root.into(),
+				project_id,
+				group_id,
+				new_batches.clone()
+			),
+			Error::<Test>::ProjectNotApproved
+		);
+
+		add_authorised_account(authorised_account);
+		assert_ok!(CarbonCredits::approve_project(
+			RawOrigin::Signed(authorised_account).into(),
+			project_id,
+			true
+		),);
+
+		assert_ok!(CarbonCredits::mint(
+			RawOrigin::Signed(authorised_account).into(),
+			project_id,
+			group_id,
+			100,
+			false
+		));
+
+		// only ForceOrigin can extend a group
+		assert_noop!(
+			CarbonCredits::extend_batch_group(
+				RawOrigin::Signed(originator_account).into(),
+				project_id,
+				group_id,
+				new_batches.clone()
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_noop!(
+			CarbonCredits::extend_batch_group(
+				RawOrigin::Root.into(),
+				project_id,
+				1,
+				new_batches.clone()
+			),
+			Error::<Test>::GroupNotFound
+		);
+
+		assert_noop!(
+			CarbonCredits::extend_batch_group(
+				RawOrigin::Root.into(),
+				project_id,
+				group_id,
+				Default::default()
+			),
+			Error::<Test>::NoBatchesToAdd
+		);
+
+		// batches without supply cannot be added
+		let mut invalid_batches = new_batches.clone().into_inner();
+		invalid_batches[0].total_supply = 0;
+		assert_noop!(
+			CarbonCredits::extend_batch_group(
+				RawOrigin::Root.into(),
+				project_id,
+				group_id,
+				invalid_batches.try_into().unwrap()
+			),
+			Error::<Test>::BatchSupplyIsZero
+		);
+
+		// batches with minted credits cannot be added
+		let mut invalid_batches = new_batches.clone().into_inner();
+		invalid_batches[0].minted = 10;
+		assert_noop!(
+			CarbonCredits::extend_batch_group(
+				RawOrigin::Root.into(),
+				project_id,
+				group_id,
+				invalid_batches.try_into().unwrap()
+			),
+			Error::<Test>::BatchAlreadyIssued
+		);
+
+		// batches cannot reuse the uuid of a batch in the group
+		let mut invalid_batches = new_batches.clone().into_inner();
+		invalid_batches[0].uuid = "batch_uuid".as_bytes().to_vec().try_into().unwrap();
+		assert_noop!(
+			CarbonCredits::extend_batch_group(
+				RawOrigin::Root.into(),
+				project_id,
+				group_id,
+				invalid_batches.try_into().unwrap()
+			),
+			Error::<Test>::DuplicateBatchUuid
+		);
+
+		assert_ok!(CarbonCredits::extend_batch_group(
+			RawOrigin::Root.into(),
+			project_id,
+			group_id,
+			new_batches
+		));
+
+		assert_eq!(
+			last_event(),
+			CarbonCreditsEvent::BatchGroupExtended {
+				project_id,
+				group_id,
+				added_supply: 50,
+				total_supply: 150
+			}
+			.into()
+		);
+
+		let stored_data = Projects::<Test>::get(project_id).unwrap();
+		let group_data = stored_data.batch_groups.get(&group_id).unwrap();
+		assert_eq!(group_data.asset_id, 0);
+		assert_eq!(group_data.batches.len(), 2);
+		assert_eq!(group_data.total_supply, 150);
+		assert_eq!(group_data.minted, 100);
+		assert_eq!(group_data.batches[1].issuance_year, 2022);
+		assert_ok!(CarbonCredits::check_group_supply(group_data));
+
+		// the new supply can be minted using the existing asset
+		assert_ok!(CarbonCredits::mint(
+			RawOrigin::Signed(authorised_account).into(),
+			project_id,
+			group_id,
+			50,
+			false
+		));
+		assert_eq!(Assets::total_issuance(0), 150);
+	});
+}
+
+#[test]
+fn split_batch_group_works() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 1;
+		let authorised_account = 10;
+		let project_id = 0;
+		let group_id = 0;
+		let new_group_id = 1;
+
+		create_and_approve_project_batch(originator_account, authorised_account);
+
+		// mint all of 2020 and half of 2021 vintage
+		assert_ok!(CarbonCredits::mint(
+			RawOrigin::Signed(authorised_account).into(),
+			project_id,
+			group_id,
+			150,
+			false
+		));
+
+		// only ForceOrigin can split a group
+		assert_noop!(
+			CarbonCredits::split_batch_group(
+				RawOrigin::Signed(originator_account).into(),
+				project_id,
+				group_id,
+				1
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_noop!(
+			CarbonCredits::split_batch_group(RawOrigin::Root.into(), project_id, group_id, 5),
+			Error::<Test>::BatchNotFound
+		);
+
+		// the 2020 vintage is fully minted
+		assert_noop!(
+			CarbonCredits::split_batch_group(RawOrigin::Root.into(), project_id, group_id, 0),
+			Error::<Test>::NoCreditsAvailableToSplit
+		);
+
+		assert_ok!(CarbonCredits::split_batch_group(
+			RawOrigin::Root.into(),
+			project_id,
+			group_id,
+			1
+		));
+
+		assert_eq!(
+			last_event(),
+			CarbonCreditsEvent::BatchGroupSplit {
+				project_id,
+				group_id,
+				new_group_id,
+				asset_id: 1,
+				amount: 50
+			}
+			.into()
+		);
+
+		let stored_data = Projects::<Test>::get(project_id).unwrap();
+
+		// the original group keeps the minted credits
+		let group_data = stored_data.batch_groups.get(&group_id).unwrap();
+		assert_eq!(group_data.total_supply, 150);
+		assert_eq!(group_data.minted, 150);
+		assert_eq!(group_data.batches.len(), 2);
+		assert_eq!(group_data.batches[1].total_supply, 50);
+		assert_ok!(CarbonCredits::check_group_supply(group_data));
+
+		// the new group holds the unminted credits under a new asset
+		let new_group_data = stored_data.batch_groups.get(&new_group_id).unwrap();
+		assert_eq!(new_group_data.asset_id, 1);
+		assert_eq!(new_group_data.total_supply, 50);
+		assert_eq!(new_group_data.minted, 0);
+		assert_eq!(new_group_data.batches.len(), 1);
+		assert_eq!(new_group_data.batches[0].issuance_year, 2021);
+		assert_ok!(CarbonCredits::check_group_supply(new_group_data));
+		assert_eq!(CarbonCredits::asset_id_lookup(1), Some((project_id, new_group_id)));
+
+		// the original group is exhausted, the new group can be minted
+		assert_noop!(
+			CarbonCredits::mint(
+				RawOrigin::Signed(authorised_account).into(),
+				project_id,
+				group_id,
+				1,
+				false
+			),
+			Error::<Test>::AmountGreaterThanSupply
+		);
+		assert_ok!(CarbonCredits::mint(
+			RawOrigin::Signed(authorised_account).into(),
+			project_id,
+			new_group_id,
+			50,
+			false
+		));
+		assert_eq!(Assets::total_issuance(1), 50);
+
+		// the only batch of a group cannot be split
+		assert_noop!(
+			CarbonCredits::split_batch_group(RawOrigin::Root.into(), project_id, new_group_id, 0),
+			Error::<Test>::NoCreditsAvailableToSplit
+		);
+	});
+}
+
+#[test]
+fn project_deposit_works() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 1;
+		let authorised_account = 10;
+		let project_id = 0;
+
+		let mut creation_params = get_default_creation_params::<Test>();
+		assert_ok!(CarbonCredits::create(
+			RawOrigin::Signed(originator_account).into(),
+			creation_params.clone()
+		));
+
+		// the deposit is scaled by the size of the project data
+		let stored_data = Projects::<Test>::get(project_id).unwrap();
+		let deposit = CarbonCredits::calculate_project_deposit(&stored_data);
+		assert!(deposit > 100);
+		assert_eq!(ProjectDeposits::<Test>::get(project_id), deposit);
+		assert_eq!(Balances::reserved_balance(originator_account), deposit);
+
+		// resubmitting a larger project reserves the difference
+		creation_params.description = "a much longer description of the project"
+			.as_bytes()
+			.to_vec()
+			.try_into()
+			.unwrap();
+		assert_ok!(CarbonCredits::resubmit(
+			RawOrigin::Signed(originator_account).into(),
+			project_id,
+			creation_params
+		));
+
+		let stored_data = Projects::<Test>::get(project_id).unwrap();
+		let new_deposit = CarbonCredits::calculate_project_deposit(&stored_data);
+		assert!(new_deposit > deposit);
+		assert_eq!(ProjectDeposits::<Test>::get(project_id), new_deposit);
+		assert_eq!(Balances::reserved_balance(originator_account), new_deposit);
+
+		// the deposit is returned on approval
+		add_authorised_account(authorised_account);
+		assert_ok!(CarbonCredits::approve_project(
+			RawOrigin::Signed(authorised_account).into(),
+			project_id,
+			true
+		));
+
+		assert_eq!(ProjectDeposits::<Test>::get(project_id), 0);
+		assert_eq!(Balances::reserved_balance(originator_account), 0);
+		assert_eq!(Balances::free_balance(originator_account), 1_000_000);
+		assert!(System::events().iter().any(|record| record.event ==
+			CarbonCreditsEvent::ProjectDepositReleased {
+				project_id,
+				who: originator_account,
+				amount: new_deposit
+			}
+			.into()));
+	});
+}
+
+#[test]
+fn create_fails_without_deposit_balance() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 4;
+
+		assert_ok!(KYCMembership::add_member(RawOrigin::Root.into(), originator_account));
+
+		assert_noop!(
+			CarbonCredits::create(
+				RawOrigin::Signed(originator_account).into(),
+				get_default_creation_params::<Test>()
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn create_fails_below_project_kyc_level() {
+	new_test_ext().execute_with(|| {
+		// account 7 is only verified at the basic level
+		let originator_account = 7;
+
+		assert_noop!(
+			CarbonCredits::create(
+				RawOrigin::Signed(originator_account).into(),
+				get_default_creation_params::<Test>()
+			),
+			Error::<Test>::KYCAuthorisationFailed
+		);
+	});
+}
+
+#[test]
+fn retire_fails_for_blocked_jurisdiction() {
+	new_test_ext().execute_with(|| {
+		// account 7 belongs to a blocked jurisdiction
+		let blocked_account = 7;
+
+		assert_noop!(
+			CarbonCredits::retire(RawOrigin::Signed(blocked_account).into(), 0, 0, 1),
+			Error::<Test>::JurisdictionBlocked
+		);
+	});
+}
+
+#[test]
+fn force_slash_project_deposit_works() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 1;
+		let project_id = 0;
+
+		assert_ok!(CarbonCredits::create(
+			RawOrigin::Signed(originator_account).into(),
+			get_default_creation_params::<Test>()
+		));
+		let deposit = ProjectDeposits::<Test>::get(project_id);
+
+		// only ForceOrigin can slash the deposit
+		assert_noop!(
+			CarbonCredits::force_slash_project_deposit(
+				RawOrigin::Signed(originator_account).into(),
+				project_id
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(CarbonCredits::force_slash_project_deposit(RawOrigin::Root.into(), project_id));
+
+		assert_eq!(
+			last_event(),
+			CarbonCreditsEvent::ProjectDepositSlashed {
+				project_id,
+				who: originator_account,
+				amount: deposit
+			}
+			.into()
+		);
+		assert_eq!(Balances::reserved_balance(originator_account), 0);
+		assert_eq!(Balances::free_balance(originator_account), 1_000_000 - deposit);
+
+		// the spam project is removed
+		assert!(Projects::<Test>::get(project_id).is_none());
+		assert_noop!(
+			CarbonCredits::force_slash_project_deposit(RawOrigin::Root.into(), project_id),
+			Error::<Test>::ProjectNotFound
+		);
+	});
+}
+
+#[test]
+fn withdraw_project_works() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 1;
+		let authorised_account = 10;
+		let project_id = 0;
+
+		assert_ok!(CarbonCredits::create(
+			RawOrigin::Signed(originator_account).into(),
+			get_default_creation_params::<Test>()
+		));
+		assert!(Balances::reserved_balance(originator_account) > 0);
+
+		// only originator can withdraw
+		assert_noop!(
+			CarbonCredits::withdraw_project(RawOrigin::Signed(3).into(), project_id),
+			Error::<Test>::NotAuthorised
+		);
+
+		// a rejected project can be withdrawn
+		add_authorised_account(authorised_account);
+		assert_ok!(CarbonCredits::approve_project(
+			RawOrigin::Signed(authorised_account).into(),
+			project_id,
+			false
+		));
+
+		assert_ok!(CarbonCredits::withdraw_project(
+			RawOrigin::Signed(originator_account).into(),
+			project_id
+		));
+
+		assert_eq!(last_event(), CarbonCreditsEvent::ProjectWithdrawn { project_id }.into());
+
+		// ensure storage is cleaned and the deposit is returned
+		assert_eq!(CarbonCredits::get_project_details(project_id), None);
+		assert_eq!(ProjectDeposits::<Test>::get(project_id), 0);
+		assert_eq!(Balances::reserved_balance(originator_account), 0);
+		assert_eq!(Balances::free_balance(originator_account), 1_000_000);
+
+		assert_noop!(
+			CarbonCredits::withdraw_project(
+				RawOrigin::Signed(originator_account).into(),
+				project_id
+			),
+			Error::<Test>::ProjectNotFound
+		);
+
+		// a project with forward credits cannot be withdrawn
+		let project_id = 1;
+		assert_ok!(CarbonCredits::create(
+			RawOrigin::Signed(originator_account).into(),
+			get_default_creation_params::<Test>()
+		));
+		ForwardAssetIds::<Test>::insert(project_id, 0, 100);
+		assert_noop!(
+			CarbonCredits::withdraw_project(
+				RawOrigin::Signed(originator_account).into(),
+				project_id
+			),
+			Error::<Test>::CannotModifyApprovedProject
+		);
+
+		// approved projects cannot be withdrawn
+		let project_id = 2;
+		assert_ok!(CarbonCredits::create(
+			RawOrigin::Signed(originator_account).into(),
+			get_default_creation_params::<Test>()
+		));
+		assert_ok!(CarbonCredits::approve_project(
+			RawOrigin::Signed(authorised_account).into(),
+			project_id,
+			true
+		));
+		assert_noop!(
+			CarbonCredits::withdraw_project(
+				RawOrigin::Signed(originator_account).into(),
+				project_id
+			),
+			Error::<Test>::CannotModifyApprovedProject
+		);
+
+		// the assets of a project rejected after approval still exist
+		assert_ok!(CarbonCredits::approve_project(
+			RawOrigin::Signed(authorised_account).into(),
+			project_id,
+			false
+		));
+		assert_noop!(
+			CarbonCredits::withdraw_project(
+				RawOrigin::Signed(originator_account).into(),
+				project_id
+			),
+			Error::<Test>::CannotModifyApprovedProject
+		);
+	});
+}
+
+#[test]
+fn project_revisions_works() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 1;
+		let authorised_account = 10;
+		let project_id = 0;
+
+		create_and_approve_project(originator_account, authorised_account);
+
+		let mut creation_params = get_default_creation_params::<Test>();
+		let mut documents_hashes = vec![];
+
+		// update the documents three times, only the last two revisions are kept
+		for document in ["document_v2", "document_v3", "document_v4"] {
+			let stored_data = Projects::<Test>::get(project_id).unwrap();
+			documents_hashes.push(CarbonCredits::project_documents_hash(&stored_data));
+
+			creation_params.documents =
+				vec![document.as_bytes().to_vec().try_into().unwrap()].try_into().unwrap();
+			assert_ok!(CarbonCredits::update_project_details(
+				RawOrigin::Signed(originator_account).into(),
+				project_id,
+				creation_params.clone()
+			));
+		}
+
+		assert!(System::events().iter().any(|record| record.event ==
+			CarbonCreditsEvent::ProjectRevisionRecorded {
+				project_id,
+				version: 3,
+				previous_documents_hash: documents_hashes[2]
+			}
+			.into()));
+
+		assert_eq!(CarbonCredits::project_revisions(project_id).len(), 2);
+		assert_eq!(CarbonCredits::get_project_revision(project_id, 1), None);
+
+		let revision = CarbonCredits::get_project_revision(project_id, 2).unwrap();
+		assert_eq!(revision.previous_documents_hash, documents_hashes[1]);
+		assert_eq!(revision.editor, originator_account);
+		assert_eq!(revision.block, 1);
+
+		let revision = CarbonCredits::get_project_revision(project_id, 3).unwrap();
+		assert_eq!(revision.previous_documents_hash, documents_hashes[2]);
+
+		// the hash of the current documents differs from all previous versions
+		let stored_data = Projects::<Test>::get(project_id).unwrap();
+		assert!(!documents_hashes.contains(&CarbonCredits::project_documents_hash(&stored_data)));
+	});
+}
+
+#[test]
+fn add_monitoring_report_works() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 1;
+		let authorised_account = 10;
+		let project_id = 0;
+		let ipfs_hash: IpfsLinkOf<Test> = "report_link".as_bytes().to_vec().try_into().unwrap();
+
+		create_and_approve_project(originator_account, authorised_account);
+
+		// only originator can add reports
+		assert_noop!(
+			CarbonCredits::add_monitoring_report(
+				RawOrigin::Signed(3).into(),
+				project_id,
+				ipfs_hash.clone(),
+				1000,
+				2000
+			),
+			Error::<Test>::NotAuthorised
+		);
+
+		assert_noop!(
+			CarbonCredits::add_monitoring_report(
+				RawOrigin::Signed(originator_account).into(),
+				project_id,
+				ipfs_hash.clone(),
+				2000,
+				1000
+			),
+			Error::<Test>::InvalidReportingPeriod
+		);
+
+		// reports can only be added to approved projects
+		assert_ok!(CarbonCredits::create(
+			RawOrigin::Signed(originator_account).into(),
+			get_default_creation_params::<Test>()
+		));
+		assert_noop!(
+			CarbonCredits::add_monitoring_report(
+				RawOrigin::Signed(originator_account).into(),
+				1,
+				ipfs_hash.clone(),
+				1000,
+				2000
+			),
+			Error::<Test>::ProjectNotApproved
+		);
+
+		assert_ok!(CarbonCredits::add_monitoring_report(
+			RawOrigin::Signed(originator_account).into(),
+			project_id,
+			ipfs_hash.clone(),
+			1000,
+			2000
+		));
+
+		assert_eq!(
+			last_event(),
+			CarbonCreditsEvent::MonitoringReportAdded {
+				project_id,
+				ipfs_hash: ipfs_hash.clone(),
+				period_start: 1000,
+				period_end: 2000
+			}
+			.into()
+		);
+
+		let reports = CarbonCredits::monitoring_reports(project_id);
+		assert_eq!(reports.len(), 1);
+		assert_eq!(reports[0].ipfs_hash, ipfs_hash);
+		assert_eq!(reports[0].submitter, originator_account);
+
+		// the project details are not modified
+		assert_eq!(Projects::<Test>::get(project_id).unwrap().updated, None);
+		assert!(CarbonCredits::project_revisions(project_id).is_empty());
+
+		assert_ok!(CarbonCredits::add_monitoring_report(
+			RawOrigin::Signed(originator_account).into(),
+			project_id,
+			ipfs_hash.clone(),
+			2000,
+			3000
+		));
+		assert_noop!(
+			CarbonCredits::add_monitoring_report(
+				RawOrigin::Signed(originator_account).into(),
+				project_id,
+				ipfs_hash,
+				3000,
+				4000
+			),
+			Error::<Test>::TooManyMonitoringReports
+		);
+	});
+}
+
+#[test]
+fn create_fails_for_invalid_location() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 1;
+		let mut creation_params = get_default_creation_params::<Test>();
+
+		// a polygon needs at least three vertices
+		creation_params.location.geometry = Geometry::Polygon(
+			vec![
+				Coordinate { latitude: 1_000_000, longitude: 1_000_000 },
+				Coordinate { latitude: 2_000_000, longitude: 2_000_000 },
+			]
+			.try_into()
+			.unwrap(),
+		);
+		assert_noop!(
+			CarbonCredits::create(
+				RawOrigin::Signed(originator_account).into(),
+				creation_params.clone()
+			),
+			Error::<Test>::InvalidLocation
+		);
+
+		// latitude is out of range
+		creation_params.location.geometry =
+			Geometry::Point(Coordinate { latitude: 90_000_001, longitude: 0 });
+		assert_noop!(
+			CarbonCredits::create(
+				RawOrigin::Signed(originator_account).into(),
+				creation_params.clone()
+			),
+			Error::<Test>::InvalidLocation
+		);
+
+		// country must be an uppercase ISO code
+		creation_params.location = get_default_location::<Test>();
+		creation_params.location.country = Some(*b"br");
+		assert_noop!(
+			CarbonCredits::create(
+				RawOrigin::Signed(originator_account).into(),
+				creation_params.clone()
+			),
+			Error::<Test>::InvalidLocation
+		);
+
+		// country must be an assigned ISO code
+		creation_params.location.country = Some(*b"XX");
+		assert_noop!(
+			CarbonCredits::create(
+				RawOrigin::Signed(originator_account).into(),
+				creation_params.clone()
+			),
+			Error::<Test>::InvalidLocation
+		);
+
+		creation_params.location.country = None;
+		assert_ok!(CarbonCredits::create(
+			RawOrigin::Signed(originator_account).into(),
+			creation_params
+		));
+	});
+}
+
+#[test]
+fn get_overlapping_projects_works() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 1;
+		let mut creation_params = get_default_creation_params::<Test>();
+
+		// project 0 uses the default boundary
+		assert_ok!(CarbonCredits::create(
+			RawOrigin::Signed(originator_account).into(),
+			creation_params.clone()
+		));
+
+		// project 1 partially overlaps the default boundary
+		creation_params.location.geometry = Geometry::Polygon(
+			vec![
+				Coordinate { latitude: 1_500_000, longitude: 1_500_000 },
+				Coordinate { latitude: 1_500_000, longitude: 3_000_000 },
+				Coordinate { latitude: 3_000_000, longitude: 3_000_000 },
+			]
+			.try_into()
+			.unwrap(),
+		);
+		assert_ok!(CarbonCredits::create(
+			RawOrigin::Signed(originator_account).into(),
+			creation_params.clone()
+		));
+
+		// project 2 is a point inside the default boundary
+		creation_params.location.geometry =
+			Geometry::Point(Coordinate { latitude: 1_200_000, longitude: 1_100_000 });
+		assert_ok!(CarbonCredits::create(
+			RawOrigin::Signed(originator_account).into(),
+			creation_params.clone()
+		));
+
+		// project 3 is far away from all other projects
+		creation_params.location.geometry = Geometry::Polygon(
+			vec![
+				Coordinate { latitude: -10_000_000, longitude: -10_000_000 },
+				Coordinate { latitude: -10_000_000, longitude: -9_000_000 },
+				Coordinate { latitude: -9_000_000, longitude: -9_000_000 },
+				Coordinate { latitude: -9_000_000, longitude: -10_000_000 },
+			]
+			.try_into()
+			.unwrap(),
+		);
+		assert_ok!(CarbonCredits::create(
+			RawOrigin::Signed(originator_account).into(),
+			creation_params
+		));
+
+		let mut overlapping = CarbonCredits::get_overlapping_projects(0);
+		overlapping.sort();
+		assert_eq!(overlapping, vec![1, 2]);
+
+		// the point lies outside of project 1
+		assert_eq!(CarbonCredits::get_overlapping_projects(1), vec![0]);
+		assert_eq!(CarbonCredits::get_overlapping_projects(2), vec![0]);
+		assert!(CarbonCredits::get_overlapping_projects(3).is_empty());
+	});
+}
+
+#[test]
+fn forward_credits_works() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 1;
+		let authorised_account = 10;
+		let project_id = 0;
+		let group_id = 0;
+		let asset_id = 0;
+		let forward_asset_id = 1;
+
+		// the 2021 batch is a future vintage
+		let mut batches = get_multiple_batch_list::<Test>().into_inner();
+		batches[1].status = BatchStatus::Projected;
+		let mut creation_params = get_default_creation_params::<Test>();
+		creation_params.batch_groups = vec![BatchGroupOf::<Test> {
+			name: "batch_group_name".as_bytes().to_vec().try_into().unwrap(),
+			uuid: "batch_group_uuid".as_bytes().to_vec().try_into().unwrap(),
+			asset_id: 0_u32,
+			total_supply: 200_u32.into(),
+			minted: 0_u32.into(),
+			retired: 0_u32.into(),
+			batches: batches.try_into().unwrap(),
+		}]
+		.try_into()
+		.unwrap();
+
+		assert_ok!(CarbonCredits::create(
+			RawOrigin::Signed(originator_account).into(),
+			creation_params
+		));
+		add_authorised_account(authorised_account);
+		assert_ok!(CarbonCredits::approve_project(
+			RawOrigin::Signed(authorised_account).into(),
+			project_id,
+			true
+		));
+
+		// only the projected batch can be minted as forward credits
+		assert_noop!(
+			CarbonCredits::mint_forward(
+				RawOrigin::Signed(authorised_account).into(),
+				project_id,
+				group_id,
+				101
+			),
+			Error::<Test>::AmountGreaterThanSupply
+		);
+		assert_ok!(CarbonCredits::mint_forward(
+			RawOrigin::Signed(authorised_account).into(),
+			project_id,
+			group_id,
+			60
+		));
+		assert_eq!(
+			last_event(),
+			CarbonCreditsEvent::ForwardCreditMinted {
+				project_id,
+				group_id,
+				asset_id: forward_asset_id,
+				recipient: originator_account,
+				amount: 60
+			}
+			.into()
+		);
+		assert_eq!(Assets::balance(forward_asset_id, originator_account), 60);
+		assert_eq!(CarbonCredits::forward_asset_ids(project_id, group_id), Some(forward_asset_id));
+		assert_eq!(
+			<CarbonCredits as CarbonCreditsValidator>::get_project_details(&forward_asset_id),
+			Some((project_id, group_id))
+		);
+		// forward credits cannot be deposited in pools
+		assert_eq!(CarbonCredits::asset_id_lookup(forward_asset_id), None);
+
+		// spot credits are only minted from the verified batch
+		assert_ok!(CarbonCredits::mint(
+			RawOrigin::Signed(authorised_account).into(),
+			project_id,
+			group_id,
+			100,
+			false
+		));
+		assert_noop!(
+			CarbonCredits::mint(
+				RawOrigin::Signed(authorised_account).into(),
+				project_id,
+				group_id,
+				1,
+				false
+			),
+			Error::<Test>::AmountGreaterThanSupply
+		);
+
+		let stored_data = Projects::<Test>::get(project_id).unwrap();
+		let group_data = stored_data.batch_groups.get(&group_id).unwrap();
+		assert_eq!(group_data.minted, 160);
+		assert_eq!(group_data.batches[0].minted, 100);
+		assert_eq!(group_data.batches[1].minted, 60);
+		assert_ok!(CarbonCredits::check_group_supply(group_data));
+
+		// forward credits cannot be converted before verification
+		assert_noop!(
+			CarbonCredits::convert_forward_credits(
+				RawOrigin::Signed(originator_account).into(),
+				project_id,
+				group_id,
+				60
+			),
+			Error::<Test>::InsufficientConvertibleCredits
+		);
+
+		// only ForceOrigin can verify a batch
+		assert_noop!(
+			CarbonCredits::verify_batch(
+				RawOrigin::Signed(authorised_account).into(),
+				project_id,
+				group_id,
+				1
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			CarbonCredits::verify_batch(RawOrigin::Root.into(), project_id, group_id, 0),
+			Error::<Test>::BatchAlreadyVerified
+		);
+		assert_ok!(CarbonCredits::verify_batch(RawOrigin::Root.into(), project_id, group_id, 1));
+		assert_eq!(
+			last_event(),
+			CarbonCreditsEvent::BatchVerified {
+				project_id,
+				group_id,
+				issuance_year: 2021,
+				convertible_amount: 60
+			}
+			.into()
+		);
+		assert_eq!(CarbonCredits::convertible_forward_credits(project_id, group_id), 60);
+
+		assert_noop!(
+			CarbonCredits::convert_forward_credits(
+				RawOrigin::Signed(originator_account).into(),
+				project_id,
+				group_id,
+				61
+			),
+			Error::<Test>::InsufficientConvertibleCredits
+		);
+		assert_ok!(CarbonCredits::convert_forward_credits(
+			RawOrigin::Signed(originator_account).into(),
+			project_id,
+			group_id,
+			60
+		));
+		assert_eq!(
+			last_event(),
+			CarbonCreditsEvent::ForwardCreditsConverted {
+				project_id,
+				group_id,
+				account: originator_account,
+				amount: 60
+			}
+			.into()
+		);
+
+		// the forward credits are converted 1:1 to spot credits
+		assert_eq!(Assets::balance(forward_asset_id, originator_account), 0);
+		assert_eq!(Assets::balance(asset_id, originator_account), 160);
+		assert_eq!(CarbonCredits::convertible_forward_credits(project_id, group_id), 0);
+
+		// the remaining supply of the verified batch is minted as spot credits
+		assert_ok!(CarbonCredits::mint(
+			RawOrigin::Signed(authorised_account).into(),
+			project_id,
+			group_id,
+			40,
+			false
+		));
+		assert_eq!(Assets::balance(asset_id, originator_account), 200);
+	});
+}
+
+#[test]
+fn unconverted_forward_credits_cannot_be_retired() {
+	new_test_ext().execute_with(|| {
+		let originator_account = 1;
+		let authorised_account = 10;
+		let project_id = 0;
+		let group_id = 0;
+
+		// the 2021 batch is a future vintage between two verified vintages
+		let mut batches = get_multiple_batch_list::<Test>().into_inner();
+		batches[1].status = BatchStatus::Projected;
+		let mut last_batch = batches[0].clone();
+		last_batch.uuid = "batch_uuid_3".as_bytes().to_vec().try_into().unwrap();
+		last_batch.issuance_year = 2022;
+		batches.push(last_batch);
+		let mut creation_params = get_default_creation_params::<Test>();
+		creation_params.batch_groups = vec![BatchGroupOf::<Test> {
+			name: "batch_group_name".as_bytes().to_vec().try_into().unwrap(),
+			uuid: "batch_group_uuid".as_bytes().to_vec().try_into().unwrap(),
+			asset_id: 0_u32,
+			total_supply: 300_u32.into(),
+			minted: 0_u32.into(),
+			retired: 0_u32.into(),
+			batches: batches.try_into().unwrap(),
+		}]
+		.try_into()
+		.unwrap();
+
+		assert_ok!(CarbonCredits::create(
+			RawOrigin::Signed(originator_account).into(),
+			creation_params
+		));
+
+		// batches of unapproved projects cannot be verified
+		assert_noop!(
+			CarbonCredits::verify_batch(RawOrigin::Root.into(), project_id, group_id, 1),
+			Error::<Test>::ProjectNotApproved
+		);
+
+		add_authorised_account(authorised_account);
+		assert_ok!(CarbonCredits::approve_project(
+			RawOrigin::Signed(authorised_account).into(),
+			project_id,
+			true
+		));
+		assert_ok!(CarbonCredits::mint_forward(
+			RawOrigin::Signed(authorised_account).into(),
+			project_id,
+			group_id,
+			60
+		));
+		assert_ok!(CarbonCredits::mint(
+			RawOrigin::Signed(authorised_account).into(),
+			project_id,
+			group_id,
+			200,
+			false
+		));
+		assert_ok!(CarbonCredits::verify_batch(RawOrigin::Root.into(), project_id, group_id, 1));
+
+		let uuid: crate::ShortStringOf<Test> =
+			"batch_uuid_2".as_bytes().to_vec().try_into().unwrap();
+		assert_eq!(
+			CarbonCredits::unconverted_forward_credits((project_id, group_id, uuid.clone())),
+			60
+		);
+
+		// the spot credits are retired from the 2020 and 2022 vintages only
+		assert_ok!(CarbonCredits::retire(
+			RawOrigin::Signed(originator_account).into(),
+			project_id,
+			group_id,
+			200
+		));
+		let stored_data = Projects::<Test>::get(project_id).unwrap();
+		let group_data = stored_data.batch_groups.get(&group_id).unwrap();
+		assert_eq!(group_data.batches[0].retired, 100);
+		assert_eq!(group_data.batches[1].retired, 0);
+		assert_eq!(group_data.batches[2].retired, 100);
+
+		// once converted the credits of the 2021 vintage can be retired
+		assert_ok!(CarbonCredits::convert_forward_credits(
+			RawOrigin::Signed(originator_account).into(),
+			project_id,
+			group_id,
+			60
+		));
+		assert_eq!(CarbonCredits::unconverted_forward_credits((project_id, group_id, uuid)), 0);
+		assert_ok!(CarbonCredits::retire(
+			RawOrigin::Signed(originator_account).into(),
+			project_id,
+			group_id,
+			60
+		));
+		let stored_data = Projects::<Test>::get(project_id).unwrap();
+		let group_data = stored_data.batch_groups.get(&group_id).unwrap();
+		assert_eq!(group_data.batches[1].retired, 60);
+		assert_ok!(CarbonCredits::check_group_supply(group_data));
+	});
+}
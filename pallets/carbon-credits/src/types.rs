@@ -2,11 +2,23 @@
 // Copyright (C) 2022 BitGreen.
 // This code is licensed under MIT license (see LICENSE.txt for details)
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{pallet_prelude::*, BoundedBTreeMap};
-use primitives::{Batch, BatchGroup, BatchRetireData, RegistryDetails, Royalty, SDGDetails};
+use frame_support::{pallet_prelude::*, traits::Currency, BoundedBTreeMap};
+use primitives::{
+	Batch, BatchGroup, BatchRetireData, Coordinate, CreditType, Location, RegistryDetails, Royalty,
+	SDGDetails,
+};
 
 use crate::pallet;
 
+/// Balance type of the currency used for project deposits
+pub type CurrencyBalanceOf<T> =
+	<<T as pallet::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Imbalance type created when a project deposit is slashed
+pub type NegativeImbalanceOf<T> = <<T as pallet::Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+
 /// AuthorizedAccounts type of pallet
 pub type AuthorizedAccountsListOf<T> = BoundedVec<
 	<T as frame_system::Config>::AccountId,
@@ -26,6 +38,12 @@ pub type IpfsLinkOf<T> = BoundedVec<u8, <T as pallet::Config>::MaxIpfsReferenceL
 /// Type for lists of ipfs links
 pub type IpfsLinkListsOf<T> = BoundedVec<IpfsLinkOf<T>, <T as pallet::Config>::MaxDocumentCount>;
 
+/// List of coordinates of a project boundary
+pub type CoordinateListOf<T> = BoundedVec<Coordinate, <T as pallet::Config>::MaxCoordinatesLength>;
+
+/// Location of a project
+pub type LocationOf<T> = Location<CoordinateListOf<T>, ShortStringOf<T>>;
+
 /// A project can address more than one SDG, this type stores the
 /// list of SDGs the project addresses, upper bound is max number of existing SDGs
 pub type SDGTypesListOf<T> = BoundedVec<SDGDetails<ShortStringOf<T>>, ConstU32<17>>;
@@ -42,6 +60,9 @@ pub type RoyaltyRecipientsOf<T> = BoundedVec<
 // Type of batch used by the pallet
 pub type BatchOf<T> = Batch<ShortStringOf<T>, <T as pallet::Config>::Balance>;
 
+// List of batches used by the pallet
+pub type BatchListOf<T> = BoundedVec<BatchOf<T>, <T as pallet::Config>::MaxGroupSize>;
+
 // Type of group used by the pallet
 pub type BatchGroupOf<T> = BatchGroup<
 	ShortStringOf<T>,
@@ -72,8 +93,8 @@ pub struct ProjectCreateParams<T: pallet::Config> {
 	pub name: ShortStringOf<T>,
 	/// Description of the project
 	pub description: LongStringOf<T>,
-	/// Location co-ordinates of thie project
-	pub location: LongStringOf<T>,
+	/// Location of the project, a point or the boundary of the project area
+	pub location: LocationOf<T>,
 	/// List of ipfs-hashes of images related to the project
 	pub images: IpfsLinkListsOf<T>,
 	/// List of ipfs-hashes of videos related to the project
@@ -84,6 +105,8 @@ pub struct ProjectCreateParams<T: pallet::Config> {
 	pub registry_details: RegistryListOf<T>,
 	/// SDG details
 	pub sdg_details: SDGTypesListOf<T>,
+	/// The type of credits issued by the project
+	pub credit_type: CreditType,
 	/// The royalties to be paid when tokens are purchased
 	pub royalties: Option<RoyaltyRecipientsOf<T>>,
 	/// List of batch groups in the project
@@ -103,8 +126,8 @@ pub struct ProjectDetail<T: pallet::Config> {
 	pub name: ShortStringOf<T>,
 	/// Description of the project
 	pub description: LongStringOf<T>,
-	/// Location co-ordinates of thie project
-	pub location: LongStringOf<T>,
+	/// Location of the project, a point or the boundary of the project area
+	pub location: LocationOf<T>,
 	/// List of ipfs-hashes of images related to the project
 	pub images: IpfsLinkListsOf<T>,
 	/// List of ipfs-hashes of videos related to the project
@@ -115,6 +138,8 @@ pub struct ProjectDetail<T: pallet::Config> {
 	pub registry_details: RegistryListOf<T>,
 	/// SDG details
 	pub sdg_details: SDGTypesListOf<T>,
+	/// The type of credits issued by the project
+	pub credit_type: CreditType,
 	/// The royalties to be paid when tokens are purchased
 	pub royalties: Option<RoyaltyRecipientsOf<T>>,
 	/// groups included in the project
@@ -153,3 +178,48 @@ pub struct RetiredCarbonCreditsData<T: pallet::Config> {
 	/// The total count of credits retired
 	pub count: T::Balance,
 }
+
+/// Record of a change to the documents of a project, the revision stores the hash of the document
+/// set that was replaced
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound(T: pallet::Config))]
+#[derive(frame_support::DebugNoBound)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProjectRevision<T: pallet::Config> {
+	/// The version of the project created by this revision
+	pub version: u32,
+	/// Content hash of the previous images, videos and documents of the project
+	pub previous_documents_hash: T::Hash,
+	/// The account that edited the project
+	pub editor: T::AccountId,
+	/// The 'BlockNumber' of the edit
+	pub block: T::BlockNumber,
+}
+
+/// List of revisions of a project, only the latest `MaxProjectRevisions` are kept
+pub type ProjectRevisionListOf<T> =
+	BoundedVec<ProjectRevision<T>, <T as pallet::Config>::MaxProjectRevisions>;
+
+/// Monitoring report submitted for a project
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound(T: pallet::Config))]
+#[derive(frame_support::DebugNoBound)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MonitoringReport<T: pallet::Config> {
+	/// ipfs-hash of the report
+	pub ipfs_hash: IpfsLinkOf<T>,
+	/// Start of the reporting period as unix timestamp
+	pub period_start: u64,
+	/// End of the reporting period as unix timestamp
+	pub period_end: u64,
+	/// The account that submitted the report
+	pub submitter: T::AccountId,
+	/// The 'BlockNumber' of submission
+	pub submitted: T::BlockNumber,
+}
+
+/// List of monitoring reports of a project
+pub type MonitoringReportListOf<T> =
+	BoundedVec<MonitoringReport<T>, <T as pallet::Config>::MaxMonitoringReports>;
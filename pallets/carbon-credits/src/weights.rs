@@ -6,6 +6,9 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2022-06-16, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! Entries marked `NOT BENCHMARKED` were added after this run and are estimated from their
+//! storage accesses, they must be replaced by re-running the command below.
 
 // Executed Command:
 // ./target/release/bitg-node
@@ -19,7 +22,7 @@
 // --extrinsic=*
 // --execution=wasm
 // --wasm-execution=compiled
-// --output=./pallets/CarbonCredits/src/weights.rs
+// --output=./pallets/carbon-credits/src/weights.rs
 // --template=./.maintain/bitg-weight-template.hbs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
@@ -41,6 +44,15 @@ pub trait WeightInfo {
 	fn force_set_project_storage() -> Weight;
 	fn force_set_next_item_id() -> Weight;
 	fn force_set_retired_carbon_credit() -> Weight;
+	fn extend_batch_group() -> Weight;
+	fn split_batch_group() -> Weight;
+	fn mint_forward() -> Weight;
+	fn verify_batch() -> Weight;
+	fn convert_forward_credits() -> Weight;
+	fn force_slash_project_deposit() -> Weight;
+	fn withdraw_project() -> Weight;
+	fn add_monitoring_report() -> Weight;
+	fn force_remove_project(r: u32, ) -> Weight;
 }
 
 /// Weights for pallet_carbon_credits using the Substrate node and recommended hardware.
@@ -112,6 +124,126 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_ref_time(10_000_000_u64)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// NOT BENCHMARKED
+	// Storage: VCU Projects (r:1 w:1)
+	fn extend_batch_group() -> Weight {
+		Weight::from_ref_time(45_000_000_u64)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCU Projects (r:1 w:1)
+	// Storage: VCU NextAssetId (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Metadata (r:0 w:1)
+	// Storage: VCU AssetIdLookup (r:0 w:1)
+	fn split_batch_group() -> Weight {
+		Weight::from_ref_time(75_000_000_u64)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCU AuthorizedAccounts (r:1 w:0)
+	// Storage: VCU Projects (r:1 w:1)
+	// Storage: VCU ForwardAssetIds (r:1 w:1)
+	// Storage: VCU NextAssetId (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Metadata (r:0 w:1)
+	// Storage: VCU ForwardAssetIdLookup (r:0 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn mint_forward() -> Weight {
+		Weight::from_ref_time(85_000_000_u64)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCU Projects (r:1 w:1)
+	// Storage: VCU ConvertibleForwardCredits (r:1 w:1)
+	// Storage: VCU UnconvertedForwardCredits (r:1 w:1)
+	fn verify_batch() -> Weight {
+		Weight::from_ref_time(30_000_000_u64)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: KYC KycRecords (r:1 w:0)
+	// Storage: VCU Projects (r:1 w:0)
+	// Storage: VCU ForwardAssetIds (r:1 w:0)
+	// Storage: VCU ConvertibleForwardCredits (r:1 w:1)
+	// Storage: VCU UnconvertedForwardCredits (r:1 w:1)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn convert_forward_credits() -> Weight {
+		Weight::from_ref_time(80_000_000_u64)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCU Projects (r:1 w:1)
+	// Storage: VCU ProjectDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:2)
+	// Storage: VCU AssetIdLookup (r:1 w:0)
+	// Storage: VCU ForwardAssetIds (r:1 w:0)
+	// Storage: VCU ProjectRevisions (r:0 w:1)
+	// Storage: VCU MonitoringReports (r:0 w:1)
+	// Storage: VCU ConvertibleForwardCredits (r:0 w:1)
+	fn force_slash_project_deposit() -> Weight {
+		Weight::from_ref_time(45_000_000_u64)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCU Projects (r:1 w:1)
+	// Storage: VCU AssetIdLookup (r:1 w:0)
+	// Storage: VCU ForwardAssetIds (r:1 w:0)
+	// Storage: VCU ProjectDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: VCU ProjectRevisions (r:0 w:1)
+	// Storage: VCU MonitoringReports (r:0 w:1)
+	// Storage: VCU ConvertibleForwardCredits (r:0 w:1)
+	fn withdraw_project() -> Weight {
+		Weight::from_ref_time(45_000_000_u64)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCU Projects (r:1 w:0)
+	// Storage: VCU MonitoringReports (r:1 w:1)
+	fn add_monitoring_report() -> Weight {
+		Weight::from_ref_time(30_000_000_u64)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCU Projects (r:1 w:1)
+	// Storage: VCU AssetIdLookup (r:1 w:1)
+	// Storage: VCU ForwardAssetIds (r:1 w:1)
+	// Storage: VCU ConvertibleForwardCredits (r:0 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Metadata (r:1 w:1)
+	// Storage: VCU RetiredCredits (r:1 w:1)
+	// Storage: VCU ArchivedRetiredCredits (r:0 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:1)
+	// Storage: Uniques ClassAccount (r:0 w:1)
+	// Storage: VCU NextItemId (r:0 w:1)
+	// Storage: VCU ForwardAssetIdLookup (r:0 w:1)
+	// Storage: VCU ProjectDeposits (r:1 w:1)
+	// Storage: VCU ProjectRevisions (r:0 w:1)
+	// Storage: VCU MonitoringReports (r:0 w:1)
+	/// The range of component `r` is `[1, 100]`.
+	fn force_remove_project(r: u32, ) -> Weight {
+		Weight::from_ref_time(90_000_000_u64)
+			// Standard Error: 10_000
+			.saturating_add(Weight::from_ref_time(25_000_000_u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(r as u64)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -182,4 +314,124 @@ impl WeightInfo for () {
 		Weight::from_ref_time(10_000_000_u64)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// NOT BENCHMARKED
+	// Storage: VCU Projects (r:1 w:1)
+	fn extend_batch_group() -> Weight {
+		Weight::from_ref_time(45_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCU Projects (r:1 w:1)
+	// Storage: VCU NextAssetId (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Metadata (r:0 w:1)
+	// Storage: VCU AssetIdLookup (r:0 w:1)
+	fn split_batch_group() -> Weight {
+		Weight::from_ref_time(75_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCU AuthorizedAccounts (r:1 w:0)
+	// Storage: VCU Projects (r:1 w:1)
+	// Storage: VCU ForwardAssetIds (r:1 w:1)
+	// Storage: VCU NextAssetId (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Metadata (r:0 w:1)
+	// Storage: VCU ForwardAssetIdLookup (r:0 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn mint_forward() -> Weight {
+		Weight::from_ref_time(85_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCU Projects (r:1 w:1)
+	// Storage: VCU ConvertibleForwardCredits (r:1 w:1)
+	// Storage: VCU UnconvertedForwardCredits (r:1 w:1)
+	fn verify_batch() -> Weight {
+		Weight::from_ref_time(30_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: KYC KycRecords (r:1 w:0)
+	// Storage: VCU Projects (r:1 w:0)
+	// Storage: VCU ForwardAssetIds (r:1 w:0)
+	// Storage: VCU ConvertibleForwardCredits (r:1 w:1)
+	// Storage: VCU UnconvertedForwardCredits (r:1 w:1)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn convert_forward_credits() -> Weight {
+		Weight::from_ref_time(80_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCU Projects (r:1 w:1)
+	// Storage: VCU ProjectDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:2)
+	// Storage: VCU AssetIdLookup (r:1 w:0)
+	// Storage: VCU ForwardAssetIds (r:1 w:0)
+	// Storage: VCU ProjectRevisions (r:0 w:1)
+	// Storage: VCU MonitoringReports (r:0 w:1)
+	// Storage: VCU ConvertibleForwardCredits (r:0 w:1)
+	fn force_slash_project_deposit() -> Weight {
+		Weight::from_ref_time(45_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCU Projects (r:1 w:1)
+	// Storage: VCU AssetIdLookup (r:1 w:0)
+	// Storage: VCU ForwardAssetIds (r:1 w:0)
+	// Storage: VCU ProjectDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: VCU ProjectRevisions (r:0 w:1)
+	// Storage: VCU MonitoringReports (r:0 w:1)
+	// Storage: VCU ConvertibleForwardCredits (r:0 w:1)
+	fn withdraw_project() -> Weight {
+		Weight::from_ref_time(45_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCU Projects (r:1 w:0)
+	// Storage: VCU MonitoringReports (r:1 w:1)
+	fn add_monitoring_report() -> Weight {
+		Weight::from_ref_time(30_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// NOT BENCHMARKED
+	// Storage: VCU Projects (r:1 w:1)
+	// Storage: VCU AssetIdLookup (r:1 w:1)
+	// Storage: VCU ForwardAssetIds (r:1 w:1)
+	// Storage: VCU ConvertibleForwardCredits (r:0 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Metadata (r:1 w:1)
+	// Storage: VCU RetiredCredits (r:1 w:1)
+	// Storage: VCU ArchivedRetiredCredits (r:0 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:1)
+	// Storage: Uniques ClassAccount (r:0 w:1)
+	// Storage: VCU NextItemId (r:0 w:1)
+	// Storage: VCU ForwardAssetIdLookup (r:0 w:1)
+	// Storage: VCU ProjectDeposits (r:1 w:1)
+	// Storage: VCU ProjectRevisions (r:0 w:1)
+	// Storage: VCU MonitoringReports (r:0 w:1)
+	/// The range of component `r` is `[1, 100]`.
+	fn force_remove_project(r: u32, ) -> Weight {
+		Weight::from_ref_time(90_000_000_u64)
+			// Standard Error: 10_000
+			.saturating_add(Weight::from_ref_time(25_000_000_u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(r as u64)))
+	}
 }
@@ -47,14 +47,10 @@ mod types;
 #[frame_support::pallet]
 pub mod pallet {
 	use crate::{types::*, WeightInfo};
-	use frame_support::{
-		pallet_prelude::*,
-		traits::{fungibles::Transfer, Contains},
-		transactional, PalletId,
-	};
+	use frame_support::{pallet_prelude::*, traits::fungibles::Transfer, transactional, PalletId};
 	use frame_system::pallet_prelude::{OriginFor, *};
 	use orml_traits::MultiCurrency;
-	use primitives::CarbonCreditsValidator;
+	use primitives::{CarbonCreditsValidator, JurisdictionProvider, KycLevel, KycLevelProvider};
 	use sp_runtime::{
 		traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedSub, One, Zero},
 		Percent,
@@ -134,7 +130,23 @@ pub mod pallet {
 		type MaxTxHashLen: Get<u32> + TypeInfo + Clone;
 
 		/// KYC provider config
-		type KYCProvider: Contains<Self::AccountId>;
+		type KYCProvider: KycLevelProvider<Self::AccountId> + JurisdictionProvider<Self::AccountId>;
+
+		/// The KYC level required to create sell orders
+		#[pallet::constant]
+		type SellOrderKycLevel: Get<KycLevel>;
+
+		/// The KYC level required to create buy orders up to `LargeBuyOrderThreshold`
+		#[pallet::constant]
+		type BuyOrderKycLevel: Get<KycLevel>;
+
+		/// The KYC level required to create buy orders above `LargeBuyOrderThreshold`
+		#[pallet::constant]
+		type LargeBuyOrderKycLevel: Get<KycLevel>;
+
+		/// The total amount of a buy order above which `LargeBuyOrderKycLevel` is required
+		#[pallet::constant]
+		type LargeBuyOrderThreshold: Get<CurrencyBalanceOf<Self>>;
 
 		/// The expiry time for buy order
 		type BuyOrderExpiryTime: Get<Self::BlockNumber>;
@@ -259,6 +271,8 @@ pub mod pallet {
 		TxProofMismatch,
 		KYCAuthorisationFailed,
 		DuplicateValidation,
+		/// The buyer or seller belongs to a blocked jurisdiction
+		JurisdictionBlocked,
 	}
 
 	#[pallet::hooks]
@@ -329,7 +343,7 @@ pub mod pallet {
 			price_per_unit: CurrencyBalanceOf<T>,
 		) -> DispatchResult {
 			let seller = ensure_signed(origin.clone())?;
-			Self::check_kyc_approval(&seller)?;
+			Self::check_kyc_approval(&seller, T::SellOrderKycLevel::get())?;
 			// ensure the asset_id can be listed
 			let (project_id, group_id) = T::AssetValidator::get_project_details(&asset_id)
 				.ok_or(Error::<T>::AssetNotPermitted)?;
@@ -401,7 +415,8 @@ pub mod pallet {
 			max_fee: CurrencyBalanceOf<T>,
 		) -> DispatchResult {
 			let buyer = ensure_signed(origin)?;
-			Self::check_kyc_approval(&buyer)?;
+			Self::check_kyc_approval(&buyer, T::BuyOrderKycLevel::get())?;
+			Self::check_jurisdiction(&buyer)?;
 
 			if units.is_zero() {
 				return Ok(())
@@ -416,6 +431,9 @@ pub mod pallet {
 				// ensure the seller and buyer are not the same
 				ensure!(buyer != order.owner, Error::<T>::SellerAndBuyerCannotBeSame);
 
+				// ensure the seller is not in a blocked jurisdiction
+				Self::check_jurisdiction(&order.owner)?;
+
 				// ensure volume remaining can cover the buy order
 				ensure!(units <= order.units, Error::<T>::OrderUnitsOverflow);
 
@@ -450,6 +468,14 @@ pub mod pallet {
 
 				ensure!(max_fee >= total_fee.into(), Error::<T>::FeeExceedsUserLimit);
 
+				// large purchases require a higher kyc level
+				let large_buy_order_threshold: u128 = T::LargeBuyOrderThreshold::get()
+					.try_into()
+					.map_err(|_| Error::<T>::ArithmeticError)?;
+				if total_amount > large_buy_order_threshold {
+					Self::check_kyc_approval(&buyer, T::LargeBuyOrderKycLevel::get())?;
+				}
+
 				// Create buy order
 				let buy_order_id = Self::buy_order_count();
 				let next_buy_order_id =
@@ -673,13 +699,22 @@ pub mod pallet {
 			}
 		}
 
-		/// Checks if given account is kyc approved
-		pub fn check_kyc_approval(account_id: &T::AccountId) -> DispatchResult {
-			if !T::KYCProvider::contains(account_id) {
+		/// Checks if given account is kyc approved at the given level
+		pub fn check_kyc_approval(account_id: &T::AccountId, level: KycLevel) -> DispatchResult {
+			if !T::KYCProvider::has_kyc_level(account_id, level) {
 				Err(Error::<T>::KYCAuthorisationFailed.into())
 			} else {
 				Ok(())
 			}
 		}
+
+		/// Checks that the given account does not belong to a blocked jurisdiction
+		pub fn check_jurisdiction(account_id: &T::AccountId) -> DispatchResult {
+			if T::KYCProvider::is_account_blocked(account_id) {
+				Err(Error::<T>::JurisdictionBlocked.into())
+			} else {
+				Ok(())
+			}
+		}
 	}
 }
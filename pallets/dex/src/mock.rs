@@ -9,7 +9,10 @@ use frame_support::{
 use frame_system as system;
 use frame_system::EnsureRoot;
 use orml_traits::parameter_type_with_key;
-use primitives::{Amount, Balance, CarbonCreditsValidator, CurrencyId};
+use primitives::{
+	Amount, Balance, CarbonCreditsValidator, CountryCode, CurrencyId, JurisdictionProvider,
+	KycLevel, KycLevelProvider,
+};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
@@ -108,6 +111,7 @@ impl pallet_assets::Config for Test {
 	type Extra = ();
 	type ForceOrigin = frame_system::EnsureRoot<u64>;
 	type Freezer = ();
+	type Compliance = ();
 	type MetadataDepositBase = MetadataDepositBase;
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type StringLimit = ConstU32<50>;
@@ -145,14 +149,34 @@ impl CarbonCreditsValidator for DummyValidator {
 }
 
 pub struct MockKycProvider;
-impl Contains<u64> for MockKycProvider {
-	fn contains(value: &u64) -> bool {
+impl KycLevelProvider<u64> for MockKycProvider {
+	fn kyc_level(value: &u64) -> Option<KycLevel> {
 		// special account to test negative kyc
 		if value == &20 {
-			return false
+			return None
+		}
+
+		// special account to test the kyc level of large buy orders
+		if value == &21 {
+			return Some(KycLevel::Basic)
+		}
+
+		Some(KycLevel::Institutional)
+	}
+}
+
+impl JurisdictionProvider<u64> for MockKycProvider {
+	fn country_code(who: &u64) -> Option<CountryCode> {
+		// special account to test blocked jurisdictions
+		if who == &22 {
+			return Some(*b"KP")
 		}
 
-		true
+		Some(*b"DE")
+	}
+
+	fn is_jurisdiction_blocked(country_code: &CountryCode) -> bool {
+		country_code == b"KP"
 	}
 }
 
@@ -168,6 +192,10 @@ parameter_types! {
 	pub const MaxTxHashLen : u32 = 100;
 	#[derive(Clone, scale_info::TypeInfo)]
 	pub const BuyOrderExpiryTime : u32 = 2;
+	pub const SellOrderKycLevel : KycLevel = KycLevel::Basic;
+	pub const BuyOrderKycLevel : KycLevel = KycLevel::Basic;
+	pub const LargeBuyOrderKycLevel : KycLevel = KycLevel::Enhanced;
+	pub const LargeBuyOrderThreshold : u128 = 1000;
 }
 
 impl pallet_dex::Config for Test {
@@ -178,6 +206,10 @@ impl pallet_dex::Config for Test {
 	type AssetBalance = u128;
 	type PalletId = DexPalletId;
 	type KYCProvider = MockKycProvider;
+	type SellOrderKycLevel = SellOrderKycLevel;
+	type BuyOrderKycLevel = BuyOrderKycLevel;
+	type LargeBuyOrderKycLevel = LargeBuyOrderKycLevel;
+	type LargeBuyOrderThreshold = LargeBuyOrderThreshold;
 	type MinPricePerUnit = MinPricePerUnit;
 	type AssetValidator = DummyValidator;
 	type MaxValidators = MaxValidators;
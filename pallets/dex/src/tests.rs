@@ -261,6 +261,67 @@ fn buy_order_should_work() {
 	});
 }
 
+#[test]
+fn large_buy_order_requires_higher_kyc_level() {
+	new_test_ext().execute_with(|| {
+		let asset_id = 0;
+		let seller = 1;
+		// account 21 is only verified at the basic level
+		let basic_buyer = 21;
+		let buyer = 4;
+
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), asset_id, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(seller), asset_id, 1, 1000));
+		assert_ok!(Dex::create_sell_order(RuntimeOrigin::signed(seller), asset_id, 1000, 10));
+
+		// a buy order up to the threshold only needs the basic level
+		assert_ok!(Dex::create_buy_order(RuntimeOrigin::signed(basic_buyer), 0, asset_id, 100, 0));
+
+		// a buy order above the threshold needs the large buy order level
+		assert_noop!(
+			Dex::create_buy_order(RuntimeOrigin::signed(basic_buyer), 0, asset_id, 101, 0),
+			Error::<Test>::KYCAuthorisationFailed
+		);
+		assert_ok!(Dex::create_buy_order(RuntimeOrigin::signed(buyer), 0, asset_id, 101, 0));
+	});
+}
+
+#[test]
+fn buy_order_fails_for_blocked_jurisdiction() {
+	new_test_ext().execute_with(|| {
+		let asset_id = 0;
+		let seller = 1;
+		// account 22 belongs to a blocked jurisdiction
+		let blocked_account = 22;
+		let buyer = 4;
+
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), asset_id, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(seller), asset_id, seller, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(seller), asset_id, blocked_account, 100));
+		assert_ok!(Dex::create_sell_order(RuntimeOrigin::signed(seller), asset_id, 100, 10));
+		assert_ok!(Dex::create_sell_order(
+			RuntimeOrigin::signed(blocked_account),
+			asset_id,
+			100,
+			10
+		));
+
+		// the buyer cannot belong to a blocked jurisdiction
+		assert_noop!(
+			Dex::create_buy_order(RuntimeOrigin::signed(blocked_account), 0, asset_id, 1, 0),
+			Error::<Test>::JurisdictionBlocked
+		);
+
+		// the seller cannot belong to a blocked jurisdiction
+		assert_noop!(
+			Dex::create_buy_order(RuntimeOrigin::signed(buyer), 1, asset_id, 1, 0),
+			Error::<Test>::JurisdictionBlocked
+		);
+
+		assert_ok!(Dex::create_buy_order(RuntimeOrigin::signed(buyer), 0, asset_id, 1, 0));
+	});
+}
+
 #[test]
 fn validate_buy_order_should_work() {
 	new_test_ext().execute_with(|| {
@@ -5,20 +5,36 @@
 //! # KYC Module
 //!
 //! Allows control of membership of a set of `AccountId`s, useful for managing membership of a
-//! collective.
+//! collective. Every member is verified at a `KycLevel`, other pallets can query the level of an
+//! account through the `KycLevelProvider` trait.
+//!
+//! The KYC of a member expires `KycValidityPeriod` blocks after verification unless an authorized
+//! account renews it, expired members are removed at the start of the expiry block.
+//!
+//! Every member is stored as a `KycRecord` in a map, so adding or removing a member does not
+//! rewrite the whole membership.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	traits::{
-		ChangeMembers, Contains, Currency, ExistenceRequirement, Get, InitializeMembers,
-		SortedMembers,
+		BalanceStatus, ChangeMembers, Contains, Currency, ExistenceRequirement, Get,
+		InitializeMembers, ReservableCurrency, SortedMembers,
 	},
-	BoundedVec, PalletId,
+	weights::Weight,
+	BoundedVec, PalletId, RuntimeDebug,
 };
-use sp_runtime::traits::{AccountIdConversion, StaticLookup};
+use primitives::is_valid_country_code;
+pub use primitives::{
+	CountryCode, EntityType, IdentityStatus, IdentityStatusProvider, JurisdictionProvider,
+	KycLevel, KycLevelProvider,
+};
+use scale_info::TypeInfo;
+use sp_runtime::traits::{AccountIdConversion, One, Saturating, StaticLookup};
 use sp_std::prelude::*;
+pub mod migration;
 pub mod weights;
 
 pub use pallet::*;
@@ -26,6 +42,78 @@ pub use weights::WeightInfo;
 
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
+/// The number of consecutive blocks tried when the expiry queue of a block is full
+const MAX_EXPIRY_SCHEDULE_ATTEMPTS: u32 = 100;
+
+/// The identifier of a registered KYC provider
+pub type ProviderId = u32;
+
+/// The jurisdiction and entity attributes of a member, as verified by the KYC provider
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct KycAttributes<Hash> {
+	/// The country the member is resident or incorporated in
+	pub country_code: CountryCode,
+	/// Whether the member is an individual or a company
+	pub entity_type: EntityType,
+	/// Hash of the reference of the verification held by the KYC provider
+	pub provider_reference: Hash,
+}
+
+pub type KycAttributesOf<T> = KycAttributes<<T as frame_system::Config>::Hash>;
+
+/// The KYC details of a member
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct KycRecord<BlockNumber, Hash> {
+	/// The KYC level the member is verified at
+	pub level: KycLevel,
+	/// The block at which the KYC expires, None for members verified before expiry was introduced
+	pub expires_at: Option<BlockNumber>,
+	/// The attributes of the member, None until set by an authorized account
+	pub attributes: Option<KycAttributes<Hash>>,
+	/// The provider that attested the KYC, None for members verified before providers were
+	/// recorded
+	pub provider_id: Option<ProviderId>,
+}
+
+/// A pending KYC application of an account
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct KycApplication<Hash, Balance> {
+	/// The provider the application was submitted to
+	pub provider_id: ProviderId,
+	/// Hash of the encrypted KYC documents shared with the provider off-chain
+	pub payload_hash: Hash,
+	/// The deposit reserved from the applicant
+	pub deposit: Balance,
+}
+
+pub type KycApplicationOf<T, I> =
+	KycApplication<<T as frame_system::Config>::Hash, BalanceOf<T, I>>;
+
+/// The type of an account derived from a verified member
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum DerivedAccountType {
+	/// A proxy account, including pure proxies, controlled by the member
+	Proxy,
+	/// A multisig account the member is a signatory of
+	Multisig,
+	/// A sub-account of the member
+	SubAccount,
+}
+
+/// The link of a derived account to the member it inherits the KYC of
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DerivedAccount<AccountId> {
+	/// The verified member the account is derived from
+	pub parent: AccountId,
+	/// The type of the derived account
+	pub account_type: DerivedAccountType,
+}
+
+pub type DerivedAccountOf<T> = DerivedAccount<<T as frame_system::Config>::AccountId>;
+
+pub type KycRecordOf<T> =
+	KycRecord<<T as frame_system::Config>::BlockNumber, <T as frame_system::Config>::Hash>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -33,7 +121,7 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(8);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -59,32 +147,96 @@ pub mod pallet {
 		/// The receiver of the signal for when the membership has changed.
 		type MembershipChanged: ChangeMembers<Self::AccountId>;
 
-		/// The maximum number of members that this membership can have.
+		/// Whether `MembershipChanged` is signalled on every membership change. The signal carries
+		/// the sorted membership, which is read from storage in full when this is enabled.
+		#[pallet::constant]
+		type NotifyMembershipChanged: Get<bool>;
+
+		/// The maximum number of members in the genesis config, the membership is not capped
+		/// afterwards.
 		///
 		/// This is used for benchmarking. Re-run the benchmarks if this changes.
-		///
-		/// This is enforced in the code; the membership size can not exceed this limit.
+		#[pallet::constant]
 		type MaxMembers: Get<u32>;
 
 		/// Maximum amount of authorised accounts permitted
 		type MaxAuthorizedAccountCount: Get<u32>;
 
 		/// The currency used for the pallet
-		type Currency: Currency<Self::AccountId>;
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The deposit reserved from an applicant until the KYC application is processed
+		#[pallet::constant]
+		type KycApplicationDeposit: Get<BalanceOf<Self, I>>;
+
+		/// The maximum length of the reason given for rejecting a KYC application
+		#[pallet::constant]
+		type MaxRejectionReasonLength: Get<u32>;
+
+		/// The balance of the pallet account below which `AirdropBalanceLow` is emitted after an
+		/// airdrop
+		#[pallet::constant]
+		type AirdropLowBalanceThreshold: Get<BalanceOf<Self, I>>;
 
 		/// The KYC pallet id
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
+		/// The number of blocks a KYC verification is valid for before it must be renewed
+		#[pallet::constant]
+		type KycValidityPeriod: Get<Self::BlockNumber>;
+
+		/// The number of blocks before expiry at which the member is notified with `KycExpiring`
+		#[pallet::constant]
+		type KycExpiryNoticePeriod: Get<Self::BlockNumber>;
+
+		/// The maximum number of memberships that can expire in a single block
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+
+		/// The maximum number of derived accounts linked to a member
+		#[pallet::constant]
+		type MaxDerivedAccounts: Get<u32>;
+
+		/// The on-chain identity judgements the KYC of an account can be granted from, only the
+		/// judgements of registrars trusted for KYC should be considered
+		type IdentityProvider: IdentityStatusProvider<Self::AccountId>;
+
+		/// The KYC level granted to accounts with a verified on-chain identity
+		#[pallet::constant]
+		type IdentityKycLevel: Get<KycLevel>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
 
-	/// The current membership, stored as an ordered Vec.
+	/// The KYC record of every member
+	#[pallet::storage]
+	#[pallet::getter(fn kyc_records)]
+	pub type KycRecords<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, KycRecordOf<T>>;
+
+	/// The number of members
+	#[pallet::storage]
+	#[pallet::getter(fn member_count)]
+	pub type MemberCount<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+	/// The members whose KYC expires at a given block
 	#[pallet::storage]
-	#[pallet::getter(fn members)]
-	pub type Members<T: Config<I>, I: 'static = ()> =
-		StorageValue<_, BoundedVec<T::AccountId, T::MaxMembers>, ValueQuery>;
+	#[pallet::getter(fn expiry_queue)]
+	pub type ExpiryQueue<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<T::AccountId, T::MaxExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The jurisdictions blocked from trading and retiring credits
+	#[pallet::storage]
+	#[pallet::getter(fn blocked_jurisdictions)]
+	pub type BlockedJurisdictions<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, CountryCode, (), OptionQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn authorized_accounts)]
@@ -92,6 +244,97 @@ pub mod pallet {
 	pub type AuthorizedAccounts<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, BoundedVec<T::AccountId, T::MaxAuthorizedAccountCount>, ValueQuery>;
 
+	/// The account of every registered KYC provider, provider ids are never reused
+	#[pallet::storage]
+	#[pallet::getter(fn kyc_providers)]
+	pub type KycProviders<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ProviderId, T::AccountId>;
+
+	/// The provider id of every authorized account
+	#[pallet::storage]
+	#[pallet::getter(fn provider_id)]
+	pub type ProviderIds<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, ProviderId>;
+
+	/// The id of the next registered provider
+	#[pallet::storage]
+	#[pallet::getter(fn next_provider_id)]
+	pub type NextProviderId<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, ProviderId, ValueQuery>;
+
+	/// The members attested by every provider
+	#[pallet::storage]
+	pub type ProviderAttestations<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ProviderId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// The pending KYC application of every applicant
+	#[pallet::storage]
+	#[pallet::getter(fn kyc_applications)]
+	pub type KycApplications<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, KycApplicationOf<T, I>>;
+
+	/// The pending KYC applications submitted to every provider
+	#[pallet::storage]
+	pub type ProviderApplications<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ProviderId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// The block at which every account received the KYC airdrop, kept when the account is
+	/// removed from the members
+	#[pallet::storage]
+	#[pallet::getter(fn airdrop_claimed)]
+	pub type AirdropClaimed<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
+	/// The account that received the KYC airdrop for every verified identity, identified by the
+	/// provider reference of the member attributes
+	#[pallet::storage]
+	#[pallet::getter(fn airdrop_claimed_identities)]
+	pub type AirdropClaimedIdentities<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::Hash, T::AccountId>;
+
+	/// The member every derived account inherits the KYC of
+	#[pallet::storage]
+	#[pallet::getter(fn derived_accounts)]
+	pub type DerivedAccounts<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, DerivedAccountOf<T>>;
+
+	/// The derived accounts linked to every member
+	#[pallet::storage]
+	#[pallet::getter(fn linked_accounts)]
+	pub type LinkedAccounts<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<T::AccountId, T::MaxDerivedAccounts>,
+		ValueQuery,
+	>;
+
+	/// The links proposed by members, waiting to be accepted by the derived account
+	#[pallet::storage]
+	#[pallet::getter(fn pending_derived_links)]
+	pub type PendingDerivedLinks<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, DerivedAccountOf<T>>;
+
+	/// The members whose KYC was granted from their on-chain identity
+	#[pallet::storage]
+	#[pallet::getter(fn identity_members)]
+	pub type IdentityMembers<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn airdrop_amount)]
 	// Amount to airdrop on every kyc success
@@ -124,7 +367,21 @@ pub mod pallet {
 			let mut members = self.members.clone();
 			members.sort();
 			T::MembershipInitialized::initialize_members(&members);
-			<Members<T, I>>::put(members);
+			for who in members.iter() {
+				let expires_at =
+					Pallet::<T, I>::schedule_kyc_expiry(who, T::KycValidityPeriod::get())
+						.expect("Cannot schedule the KYC expiry of the genesis members.");
+				<KycRecords<T, I>>::insert(
+					who,
+					KycRecord {
+						level: KycLevel::Basic,
+						expires_at: Some(expires_at),
+						attributes: None,
+						provider_id: None,
+					},
+				);
+			}
+			<MemberCount<T, I>>::put(members.len() as u32);
 		}
 	}
 
@@ -132,7 +389,7 @@ pub mod pallet {
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
 		/// The given member was added
-		MemberAdded { who: T::AccountId },
+		MemberAdded { who: T::AccountId, level: KycLevel },
 		/// The given member was removed
 		MemberRemoved { who: T::AccountId },
 		/// Two members were swapped; see the transaction for who.
@@ -144,11 +401,61 @@ pub mod pallet {
 		/// Phantom member, never used.
 		Dummy { _phantom_data: PhantomData<(T::AccountId, <T as Config<I>>::RuntimeEvent)> },
 		/// A new AuthorizedAccount has been added
-		AuthorizedAccountAdded { account_id: T::AccountId },
+		AuthorizedAccountAdded { account_id: T::AccountId, provider_id: ProviderId },
 		/// An AuthorizedAccount has been removed
 		AuthorizedAccountRemoved { account_id: T::AccountId },
 		/// User has received airdrop for kyc approval
 		KYCAirdrop { who: T::AccountId, amount: BalanceOf<T, I> },
+		/// The airdrop could not be transferred from the pallet account
+		AirdropFailed { who: T::AccountId, amount: BalanceOf<T, I> },
+		/// The balance of the pallet account is below `AirdropLowBalanceThreshold`
+		AirdropBalanceLow { balance: BalanceOf<T, I> },
+		/// The KYC level of a member was updated
+		KycLevelUpdated { who: T::AccountId, level: KycLevel },
+		/// The KYC of a member expires at the given block
+		KycExpiring { who: T::AccountId, expires_at: T::BlockNumber },
+		/// The KYC of a member has expired, the member was removed
+		KycExpired { who: T::AccountId },
+		/// The KYC of a member was renewed until the given block
+		KycRenewed { who: T::AccountId, expires_at: T::BlockNumber },
+		/// The attributes of a member were updated
+		KycAttributesUpdated { who: T::AccountId, attributes: KycAttributesOf<T> },
+		/// A jurisdiction was blocked
+		JurisdictionBlocked { country_code: CountryCode },
+		/// A jurisdiction was unblocked
+		JurisdictionUnblocked { country_code: CountryCode },
+		/// The given number of memberships attested by the provider were revoked
+		ProviderAttestationsRevoked { provider_id: ProviderId, count: u32 },
+		/// An account applied for KYC to the given provider
+		KycApplied { who: T::AccountId, provider_id: ProviderId, payload_hash: T::Hash },
+		/// A KYC application was approved and the deposit refunded
+		KycApplicationApproved { who: T::AccountId, provider_id: ProviderId, level: KycLevel },
+		/// A KYC application was rejected and the deposit transferred to the pallet account
+		KycApplicationRejected {
+			who: T::AccountId,
+			provider_id: ProviderId,
+			reason: BoundedVec<u8, T::MaxRejectionReasonLength>,
+		},
+		/// A KYC application was cancelled by the applicant and the deposit refunded
+		KycApplicationCancelled { who: T::AccountId, provider_id: ProviderId },
+		/// A member proposed to link a derived account
+		DerivedAccountLinkProposed {
+			parent: T::AccountId,
+			derived: T::AccountId,
+			account_type: DerivedAccountType,
+		},
+		/// A derived account was linked to a member and inherits its KYC
+		DerivedAccountLinked {
+			parent: T::AccountId,
+			derived: T::AccountId,
+			account_type: DerivedAccountType,
+		},
+		/// A derived account was unlinked from a member
+		DerivedAccountUnlinked { parent: T::AccountId, derived: T::AccountId },
+		/// The KYC of an account was granted from its on-chain identity
+		IdentityKycGranted { who: T::AccountId, level: KycLevel },
+		/// The KYC of an account granted from its on-chain identity was revoked
+		IdentityKycRevoked { who: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -165,54 +472,141 @@ pub mod pallet {
 		AuthorizedAccountAlreadyExists,
 		/// No authorization account
 		NotAuthorised,
+		/// Too many memberships expire around the same block
+		TooManyExpiries,
+		/// The country code is not an assigned ISO 3166-1 alpha-2 code
+		InvalidCountryCode,
+		/// The jurisdiction is already blocked
+		JurisdictionAlreadyBlocked,
+		/// The jurisdiction is not blocked
+		JurisdictionNotBlocked,
+		/// The KYC provider does not exist
+		ProviderNotFound,
+		/// Cannot generate a new provider id
+		ProviderIdOverflow,
+		/// The account already has a pending KYC application
+		ApplicationAlreadyExists,
+		/// The KYC application does not exist
+		ApplicationNotFound,
+		/// The account is already linked to a member
+		AlreadyLinked,
+		/// The derived account link does not exist
+		LinkNotFound,
+		/// Too many derived accounts are linked to the member
+		TooManyDerivedAccounts,
+		/// An account cannot be derived from itself
+		CannotLinkSelf,
+		/// The on-chain identity of the account does not change its KYC
+		IdentityUnchanged,
+		/// The member is attested by another provider
+		NotAttestingProvider,
+		/// The given number of existing members is lower than the member count
+		InvalidMemberCountWitness,
+		/// Another member has already proposed a link to the account
+		LinkAlreadyProposed,
+		/// The KYC of the member was granted from its on-chain identity
+		IdentityGrantedKyc,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			// notify the members whose kyc expires at the end of the notice period
+			let expires_at = now.saturating_add(T::KycExpiryNoticePeriod::get());
+			let expiring = ExpiryQueue::<T, I>::get(expires_at);
+			for who in expiring.iter() {
+				Self::deposit_event(Event::KycExpiring { who: who.clone(), expires_at });
+			}
+
+			// the expired members are removed, the queue of a block holds at most
+			// `MaxExpiriesPerBlock` members
+			let expired = ExpiryQueue::<T, I>::take(now);
+			let mut weight = T::DbWeight::get().reads_writes(2, 1);
+			let mut outgoing: Vec<T::AccountId> = Default::default();
+			for who in expired.into_iter() {
+				weight = weight.saturating_add(T::DbWeight::get().reads(1));
+				let expires_now =
+					Self::kyc_records(&who).map_or(false, |record| record.expires_at == Some(now));
+				if !expires_now {
+					continue
+				}
+
+				Self::take_kyc_record(&who);
+				let revoked = Self::revoke_derived_accounts(&who);
+				weight = weight.saturating_add(
+					T::DbWeight::get()
+						.reads_writes(2, 4)
+						.saturating_add(T::DbWeight::get().writes(revoked.into())),
+				);
+
+				Self::deposit_event(Event::KycExpired { who: who.clone() });
+				outgoing.push(who);
+			}
+
+			if !outgoing.is_empty() {
+				let count = outgoing.len() as u32;
+				<MemberCount<T, I>>::mutate(|member_count| {
+					*member_count = member_count.saturating_sub(count)
+				});
+				weight = weight
+					.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+					.saturating_add(Self::notify_members_changed_weight());
+
+				outgoing.sort();
+				Self::notify_members_changed(&[], &outgoing[..]);
+			}
+
+			weight
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
-		/// Add a member `who` to the set.
+		/// Add a member `who` to the set, verified at the given KYC `level`. No airdrop is paid,
+		/// the airdrop is only paid when a KYC application is approved.
 		///
-		/// May only be called from `T::AddOrigin`.
+		/// May only be called from an authorized account.
 		#[pallet::call_index(0)]
-		#[pallet::weight(50_000_000)]
-		pub fn add_member(origin: OriginFor<T>, who: AccountIdLookupOf<T>) -> DispatchResult {
+		#[pallet::weight(
+			Weight::from_ref_time(50_000_000)
+				.saturating_add(Pallet::<T, I>::schedule_kyc_expiry_weight())
+				.saturating_add(Pallet::<T, I>::notify_members_changed_weight())
+		)]
+		pub fn add_member(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			level: KycLevel,
+		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			Self::check_authorized_account(&sender)?;
+			let provider_id = Self::ensure_provider(&sender)?;
 			let who = T::Lookup::lookup(who)?;
 
-			let mut members = <Members<T, I>>::get();
-			let location = members.binary_search(&who).err().ok_or(Error::<T, I>::AlreadyMember)?;
-			members
-				.try_insert(location, who.clone())
-				.map_err(|_| Error::<T, I>::TooManyMembers)?;
-
-			<Members<T, I>>::put(&members);
-
-			T::MembershipChanged::change_members_sorted(&[who.clone()], &[], &members[..]);
-
-			let _ = Self::transfer_kyc_airdrop(who.clone());
-
-			Self::deposit_event(Event::MemberAdded { who });
-			Ok(())
+			Self::do_add_member(who, level, provider_id, None)
 		}
 
 		/// Remove a member `who` from the set.
 		///
-		/// May only be called from `T::RemoveOrigin`.
+		/// May only be called from the provider attesting the member, members without a provider
+		/// can be removed by any provider. A KYC granted from an on-chain identity is only removed
+		/// with `sync_identity_judgement`.
 		#[pallet::call_index(1)]
-		#[pallet::weight(50_000_000)]
+		#[pallet::weight(
+			Weight::from_ref_time(50_000_000)
+				.saturating_add(Pallet::<T, I>::notify_members_changed_weight())
+		)]
 		pub fn remove_member(origin: OriginFor<T>, who: AccountIdLookupOf<T>) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			Self::check_authorized_account(&sender)?;
+			let provider_id = Self::ensure_provider(&sender)?;
 
 			let who = T::Lookup::lookup(who)?;
 
-			let mut members = <Members<T, I>>::get();
-			let location = members.binary_search(&who).ok().ok_or(Error::<T, I>::NotMember)?;
-			members.remove(location);
+			let record = Self::kyc_records(&who).ok_or(Error::<T, I>::NotMember)?;
+			Self::ensure_attesting_provider(&who, &record, provider_id)?;
+			Self::take_kyc_record(&who);
+			<MemberCount<T, I>>::mutate(|count| *count = count.saturating_sub(1));
+			Self::revoke_derived_accounts(&who);
 
-			<Members<T, I>>::put(&members);
-
-			T::MembershipChanged::change_members_sorted(&[], &[who.clone()], &members[..]);
+			Self::notify_members_changed(&[], &[who.clone()]);
 
 			Self::deposit_event(Event::MemberRemoved { who });
 			Ok(())
@@ -220,18 +614,24 @@ pub mod pallet {
 
 		/// Swap out one member `remove` for another `add`.
 		///
-		/// May only be called from `T::SwapOrigin`.
+		/// May only be called from the provider attesting `remove`, members without a provider
+		/// can be swapped by any provider. A KYC granted from an on-chain identity cannot be
+		/// swapped.
 		///
 		/// Prime membership is *not* passed from `remove` to `add`, if extant.
 		#[pallet::call_index(2)]
-		#[pallet::weight(50_000_000)]
+		#[pallet::weight(
+			Weight::from_ref_time(50_000_000)
+				.saturating_add(Pallet::<T, I>::schedule_kyc_expiry_weight())
+				.saturating_add(Pallet::<T, I>::notify_members_changed_weight())
+		)]
 		pub fn swap_member(
 			origin: OriginFor<T>,
 			remove: AccountIdLookupOf<T>,
 			add: AccountIdLookupOf<T>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			Self::check_authorized_account(&sender)?;
+			let provider_id = Self::ensure_provider(&sender)?;
 
 			let remove = T::Lookup::lookup(remove)?;
 			let add = T::Lookup::lookup(add)?;
@@ -240,15 +640,12 @@ pub mod pallet {
 				return Ok(())
 			}
 
-			let mut members = <Members<T, I>>::get();
-			let location = members.binary_search(&remove).ok().ok_or(Error::<T, I>::NotMember)?;
-			let _ = members.binary_search(&add).err().ok_or(Error::<T, I>::AlreadyMember)?;
-			members[location] = add.clone();
-			members.sort();
-
-			<Members<T, I>>::put(&members);
+			let record = Self::kyc_records(&remove).ok_or(Error::<T, I>::NotMember)?;
+			Self::ensure_attesting_provider(&remove, &record, provider_id)?;
+			ensure!(!<KycRecords<T, I>>::contains_key(&add), Error::<T, I>::AlreadyMember);
+			Self::move_kyc_record(&remove, &add)?;
 
-			T::MembershipChanged::change_members_sorted(&[add], &[remove], &members[..]);
+			Self::notify_members_changed(&[add], &[remove]);
 
 			Self::deposit_event(Event::MembersSwapped);
 			Ok(())
@@ -257,20 +654,62 @@ pub mod pallet {
 		/// Change the membership to a new set, disregarding the existing membership. Be nice and
 		/// pass `members` pre-sorted.
 		///
-		/// May only be called from `T::ResetOrigin`.
+		/// Retained members keep their KYC level and expiry, new members are verified at
+		/// `KycLevel::Basic` without an attesting provider. The existing membership is read in
+		/// full, `existing_members` must be at least the current member count and is used to weigh
+		/// the call.
+		///
+		/// May only be called from `T::AddOrigin`, the members of every provider are replaced.
 		#[pallet::call_index(3)]
-		#[pallet::weight(50_000_000)]
-		pub fn reset_members(origin: OriginFor<T>, members: Vec<T::AccountId>) -> DispatchResult {
-			let sender = ensure_signed(origin)?;
-			Self::check_authorized_account(&sender)?;
+		#[pallet::weight(
+			T::DbWeight::get()
+				.reads_writes(3, 4)
+				.saturating_add(T::DbWeight::get().writes(T::MaxDerivedAccounts::get().into()))
+				.saturating_mul((*existing_members).into())
+				.saturating_add(
+					Pallet::<T, I>::schedule_kyc_expiry_weight()
+						.saturating_add(T::DbWeight::get().reads_writes(1, 2))
+						.saturating_mul(members.len() as u64)
+				)
+				.saturating_add(Weight::from_ref_time(50_000_000))
+		)]
+		pub fn reset_members(
+			origin: OriginFor<T>,
+			members: Vec<T::AccountId>,
+			existing_members: u32,
+		) -> DispatchResult {
+			T::AddOrigin::ensure_origin(origin)?;
+			ensure!(
+				Self::member_count() <= existing_members,
+				Error::<T, I>::InvalidMemberCountWitness
+			);
 
-			let mut members: BoundedVec<T::AccountId, T::MaxMembers> =
-				BoundedVec::try_from(members).map_err(|_| Error::<T, I>::TooManyMembers)?;
+			let mut members = members;
 			members.sort();
-			<Members<T, I>>::mutate(|m| {
-				T::MembershipChanged::set_members_sorted(&members[..], m);
-				*m = members;
-			});
+			members.dedup();
+
+			let old_members = Self::members();
+			for removed in old_members.iter().filter(|who| members.binary_search(who).is_err()) {
+				Self::take_kyc_record(removed);
+				Self::revoke_derived_accounts(removed);
+			}
+			for added in members.iter().filter(|who| old_members.binary_search(who).is_err()) {
+				let expires_at = Self::schedule_kyc_expiry(added, Self::next_kyc_expiry())?;
+				Self::insert_kyc_record(
+					added,
+					KycRecord {
+						level: KycLevel::Basic,
+						expires_at: Some(expires_at),
+						attributes: None,
+						provider_id: None,
+					},
+				);
+			}
+			<MemberCount<T, I>>::put(members.len() as u32);
+
+			if T::NotifyMembershipChanged::get() {
+				T::MembershipChanged::set_members_sorted(&members[..], &old_members[..]);
+			}
 
 			Self::deposit_event(Event::MembersReset);
 			Ok(())
@@ -282,29 +721,29 @@ pub mod pallet {
 		///
 		/// Prime membership is passed from the origin account to `new`, if extant.
 		#[pallet::call_index(4)]
-		#[pallet::weight(50_000_000)]
+		#[pallet::weight(
+			Weight::from_ref_time(50_000_000)
+				.saturating_add(Pallet::<T, I>::schedule_kyc_expiry_weight())
+				.saturating_add(Pallet::<T, I>::notify_members_changed_weight())
+		)]
 		pub fn change_key(origin: OriginFor<T>, new: AccountIdLookupOf<T>) -> DispatchResult {
 			let remove = ensure_signed(origin)?;
 			let new = T::Lookup::lookup(new)?;
 
 			if remove != new {
-				let mut members = <Members<T, I>>::get();
-				let location =
-					members.binary_search(&remove).ok().ok_or(Error::<T, I>::NotMember)?;
-				let _ = members.binary_search(&new).err().ok_or(Error::<T, I>::AlreadyMember)?;
-				members[location] = new.clone();
-				members.sort();
-
-				<Members<T, I>>::put(&members);
+				ensure!(<KycRecords<T, I>>::contains_key(&remove), Error::<T, I>::NotMember);
+				ensure!(!<KycRecords<T, I>>::contains_key(&new), Error::<T, I>::AlreadyMember);
+				Self::move_kyc_record(&remove, &new)?;
 
-				T::MembershipChanged::change_members_sorted(&[new], &[remove], &members[..]);
+				Self::notify_members_changed(&[new], &[remove]);
 			}
 
 			Self::deposit_event(Event::KeyChanged);
 			Ok(())
 		}
 
-		/// Add a new account to the list of authorised Accounts
+		/// Add a new account to the list of authorised Accounts, the account is registered as a
+		/// KYC provider with a new provider id
 		/// The caller must be from a permitted origin
 		#[pallet::call_index(5)]
 		#[pallet::weight(50_000_000)]
@@ -313,6 +752,10 @@ pub mod pallet {
 			account_id: T::AccountId,
 		) -> DispatchResult {
 			T::AddOrigin::ensure_origin(origin)?;
+			let provider_id = Self::next_provider_id();
+			let next_provider_id =
+				provider_id.checked_add(1).ok_or(Error::<T, I>::ProviderIdOverflow)?;
+
 			// add the account_id to the list of authorized accounts
 			AuthorizedAccounts::<T, I>::try_mutate(|account_list| -> DispatchResult {
 				ensure!(
@@ -326,11 +769,16 @@ pub mod pallet {
 				Ok(())
 			})?;
 
-			Self::deposit_event(Event::AuthorizedAccountAdded { account_id });
+			NextProviderId::<T, I>::put(next_provider_id);
+			KycProviders::<T, I>::insert(provider_id, &account_id);
+			ProviderIds::<T, I>::insert(&account_id, provider_id);
+
+			Self::deposit_event(Event::AuthorizedAccountAdded { account_id, provider_id });
 			Ok(())
 		}
 
-		/// Remove an account from the list of authorised accounts
+		/// Remove an account from the list of authorised accounts, the provider can no longer
+		/// attest new members but its existing attestations are kept
 		#[pallet::call_index(6)]
 		#[pallet::weight(50_000_000)]
 		pub fn force_remove_authorized_account(
@@ -340,8 +788,10 @@ pub mod pallet {
 			T::AddOrigin::ensure_origin(origin)?;
 			// remove the account_id from the list of authorized accounts if already exists
 			AuthorizedAccounts::<T, I>::try_mutate(|account_list| -> DispatchResult {
-				if let Ok(index) = account_list.binary_search(&account_id) {
+				if let Some(index) = account_list.iter().position(|account| account == &account_id)
+				{
 					account_list.swap_remove(index);
+					ProviderIds::<T, I>::remove(&account_id);
 					Self::deposit_event(Event::AuthorizedAccountRemoved { account_id });
 				}
 
@@ -361,129 +811,937 @@ pub mod pallet {
 			AirdropAmount::<T, I>::set(amount);
 			Ok(())
 		}
-	}
-}
 
-impl<T: Config<I>, I: 'static> Pallet<T, I> {
-	/// Checks if the given account_id is part of authorized account list
-	pub fn check_authorized_account(
-		account_id: &T::AccountId,
-	) -> frame_support::pallet_prelude::DispatchResult {
-		let authorized_accounts = AuthorizedAccounts::<T, I>::get();
-		if !authorized_accounts.contains(account_id) {
-			Err(Error::<T, I>::NotAuthorised.into())
-		} else {
+		/// Update the KYC level of an existing member `who`, the caller becomes the attesting
+		/// provider of the member.
+		///
+		/// May only be called from an authorized account.
+		#[pallet::call_index(8)]
+		#[pallet::weight(50_000_000)]
+		pub fn set_kyc_level(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			level: KycLevel,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let provider_id = Self::ensure_provider(&sender)?;
+			let who = T::Lookup::lookup(who)?;
+
+			<KycRecords<T, I>>::try_mutate(&who, |maybe_record| -> DispatchResult {
+				let record = maybe_record.as_mut().ok_or(Error::<T, I>::NotMember)?;
+				record.level = level;
+				Self::attest_record(&who, record, provider_id)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::KycLevelUpdated { who, level });
 			Ok(())
 		}
-	}
 
-	/// The account ID of the KYC pallet
-	pub fn account_id() -> T::AccountId {
-		T::PalletId::get().into_account_truncating()
-	}
+		/// Renew the KYC of an existing member `who` for another `KycValidityPeriod`, the KYC
+		/// level of the member is unchanged and no airdrop is paid. The caller becomes the
+		/// attesting provider of the member.
+		///
+		/// May only be called from an authorized account.
+		#[pallet::call_index(9)]
+		#[pallet::weight(
+			Weight::from_ref_time(50_000_000)
+				.saturating_add(Pallet::<T, I>::schedule_kyc_expiry_weight())
+		)]
+		pub fn renew_kyc(origin: OriginFor<T>, who: AccountIdLookupOf<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let provider_id = Self::ensure_provider(&sender)?;
+			let who = T::Lookup::lookup(who)?;
 
-	/// Airdrop native tokens to user
-	pub fn transfer_kyc_airdrop(
-		who: T::AccountId,
-	) -> frame_support::pallet_prelude::DispatchResult {
-		// transfer airdrop if the amount is set
-		if let Some(amount) = Self::airdrop_amount() {
-			let airdrop_executed = T::Currency::transfer(
-				&Self::account_id(),
-				&who,
-				amount,
-				ExistenceRequirement::AllowDeath,
-			);
+			let expires_at =
+				<KycRecords<T, I>>::try_mutate(&who, |maybe_record| -> Result<_, DispatchError> {
+					let record = maybe_record.as_mut().ok_or(Error::<T, I>::NotMember)?;
+					Self::attest_record(&who, record, provider_id)?;
+					Self::clear_kyc_expiry(&who, record);
+					let expires_at = Self::schedule_kyc_expiry(&who, Self::next_kyc_expiry())?;
+					record.expires_at = Some(expires_at);
+					Ok(expires_at)
+				})?;
+
+			Self::deposit_event(Event::KycRenewed { who, expires_at });
+			Ok(())
+		}
+
+		/// Set the jurisdiction and entity attributes of an existing member `who`, the caller
+		/// becomes the attesting provider of the member.
+		///
+		/// May only be called from an authorized account.
+		#[pallet::call_index(10)]
+		#[pallet::weight(50_000_000)]
+		pub fn set_kyc_attributes(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			attributes: KycAttributesOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let provider_id = Self::ensure_provider(&sender)?;
+			let who = T::Lookup::lookup(who)?;
+			Self::check_country_code(&attributes.country_code)?;
+
+			<KycRecords<T, I>>::try_mutate(&who, |maybe_record| -> DispatchResult {
+				let record = maybe_record.as_mut().ok_or(Error::<T, I>::NotMember)?;
+				record.attributes = Some(attributes.clone());
+				Self::attest_record(&who, record, provider_id)?;
+				Ok(())
+			})?;
 
-			if airdrop_executed.is_ok() {
-				Self::deposit_event(Event::KYCAirdrop { who, amount });
+			// the identity of an account that received the airdrop cannot claim it again
+			if AirdropClaimed::<T, I>::contains_key(&who) &&
+				!AirdropClaimedIdentities::<T, I>::contains_key(attributes.provider_reference)
+			{
+				AirdropClaimedIdentities::<T, I>::insert(attributes.provider_reference, &who);
 			}
-		}
-		Ok(())
-	}
-}
 
-impl<T: Config<I>, I: 'static> Contains<T::AccountId> for Pallet<T, I> {
-	fn contains(t: &T::AccountId) -> bool {
-		Self::members().binary_search(t).is_ok()
-	}
-}
+			Self::deposit_event(Event::KycAttributesUpdated { who, attributes });
+			Ok(())
+		}
 
-impl<T: Config<I>, I: 'static> SortedMembers<T::AccountId> for Pallet<T, I> {
-	fn sorted_members() -> Vec<T::AccountId> {
-		Self::members().to_vec()
-	}
+		/// Block trading and retirements for the accounts of the given jurisdiction
+		#[pallet::call_index(11)]
+		#[pallet::weight(50_000_000)]
+		pub fn force_block_jurisdiction(
+			origin: OriginFor<T>,
+			country_code: CountryCode,
+		) -> DispatchResult {
+			T::AddOrigin::ensure_origin(origin)?;
+			Self::check_country_code(&country_code)?;
+			ensure!(
+				!BlockedJurisdictions::<T, I>::contains_key(country_code),
+				Error::<T, I>::JurisdictionAlreadyBlocked
+			);
+			BlockedJurisdictions::<T, I>::insert(country_code, ());
+			Self::deposit_event(Event::JurisdictionBlocked { country_code });
+			Ok(())
+		}
 
-	fn count() -> usize {
-		Members::<T, I>::decode_len().unwrap_or(0)
-	}
-}
+		/// Remove the given jurisdiction from the blocked jurisdictions
+		#[pallet::call_index(12)]
+		#[pallet::weight(50_000_000)]
+		pub fn force_unblock_jurisdiction(
+			origin: OriginFor<T>,
+			country_code: CountryCode,
+		) -> DispatchResult {
+			T::AddOrigin::ensure_origin(origin)?;
+			ensure!(
+				BlockedJurisdictions::<T, I>::contains_key(country_code),
+				Error::<T, I>::JurisdictionNotBlocked
+			);
+			BlockedJurisdictions::<T, I>::remove(country_code);
+			Self::deposit_event(Event::JurisdictionUnblocked { country_code });
+			Ok(())
+		}
 
-#[cfg(feature = "runtime-benchmarks")]
-mod benchmark {
-	use super::{Pallet as Membership, *};
-	use frame_benchmarking::v1::{account, benchmarks_instance_pallet, whitelist, BenchmarkError};
-	use frame_support::{assert_ok, traits::EnsureOrigin};
-	use frame_system::RawOrigin;
+		/// Revoke up to `limit` memberships attested by the given provider, the call can be
+		/// repeated until every attestation of the provider is revoked. A compromised provider
+		/// should be removed with `force_remove_authorized_account` first.
+		#[pallet::call_index(13)]
+		#[pallet::weight(
+			T::DbWeight::get()
+				.reads_writes(2, 4)
+				.saturating_mul((*limit).into())
+				.saturating_add(Weight::from_ref_time(50_000_000))
+				.saturating_add(Pallet::<T, I>::notify_members_changed_weight())
+		)]
+		pub fn force_revoke_provider_attestations(
+			origin: OriginFor<T>,
+			provider_id: ProviderId,
+			limit: u32,
+		) -> DispatchResult {
+			T::AddOrigin::ensure_origin(origin)?;
+			ensure!(
+				KycProviders::<T, I>::contains_key(provider_id),
+				Error::<T, I>::ProviderNotFound
+			);
 
-	const SEED: u32 = 0;
+			let mut revoked: Vec<T::AccountId> =
+				ProviderAttestations::<T, I>::iter_key_prefix(provider_id)
+					.take(limit as usize)
+					.collect();
+			for who in revoked.iter() {
+				Self::take_kyc_record(who);
+				Self::revoke_derived_accounts(who);
+			}
+			let count = revoked.len() as u32;
+			<MemberCount<T, I>>::mutate(|member_count| {
+				*member_count = member_count.saturating_sub(count)
+			});
 
-	fn set_members<T: Config<I>, I: 'static>(members: Vec<T::AccountId>, prime: Option<usize>) {
-		let reset_origin = T::ResetOrigin::try_successful_origin()
-			.expect("ResetOrigin has no successful origin required for the benchmark");
-		let prime_origin = T::PrimeOrigin::try_successful_origin()
-			.expect("PrimeOrigin has no successful origin required for the benchmark");
+			revoked.sort();
+			Self::notify_members_changed(&[], &revoked[..]);
 
-		assert_ok!(<Membership<T, I>>::reset_members(reset_origin, members.clone()));
-		if let Some(prime) = prime.map(|i| members[i].clone()) {
-			let prime_lookup = T::Lookup::unlookup(prime);
-			assert_ok!(<Membership<T, I>>::set_prime(prime_origin, prime_lookup));
-		} else {
-			assert_ok!(<Membership<T, I>>::clear_prime(prime_origin));
+			Self::deposit_event(Event::ProviderAttestationsRevoked { provider_id, count });
+			Ok(())
 		}
-	}
 
-	benchmarks_instance_pallet! {
-		add_member {
-			let m in 1 .. (T::MaxMembers::get() - 1);
+		/// Apply for KYC to the given provider, `payload_hash` is the hash of the encrypted KYC
+		/// documents shared with the provider off-chain. `KycApplicationDeposit` is reserved until
+		/// the application is processed.
+		#[pallet::call_index(14)]
+		#[pallet::weight(50_000_000)]
+		pub fn apply_for_kyc(
+			origin: OriginFor<T>,
+			provider_id: ProviderId,
+			payload_hash: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-			let members = (0..m).map(|i| account("member", i, SEED)).collect::<Vec<T::AccountId>>();
-			set_members::<T, I>(members, None);
-			let new_member = account::<T::AccountId>("add", m, SEED);
-			let new_member_lookup = T::Lookup::unlookup(new_member.clone());
-		}: {
-			assert_ok!(<Membership<T, I>>::add_member(
-				T::AddOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
-				new_member_lookup,
-			));
-		} verify {
-			assert!(<Members<T, I>>::get().contains(&new_member));
-			#[cfg(test)] crate::tests::clean();
-		}
+			ensure!(!<KycRecords<T, I>>::contains_key(&who), Error::<T, I>::AlreadyMember);
+			ensure!(
+				!KycApplications::<T, I>::contains_key(&who),
+				Error::<T, I>::ApplicationAlreadyExists
+			);
+			// the provider must still be authorized
+			let provider =
+				Self::kyc_providers(provider_id).ok_or(Error::<T, I>::ProviderNotFound)?;
+			ensure!(
+				Self::provider_id(&provider) == Some(provider_id),
+				Error::<T, I>::ProviderNotFound
+			);
 
-		// the case of no prime or the prime being removed is surely cheaper than the case of
-		// reporting a new prime via `MembershipChanged`.
-		remove_member {
-			let m in 2 .. T::MaxMembers::get();
+			let deposit = T::KycApplicationDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
 
-			let members = (0..m).map(|i| account("member", i, SEED)).collect::<Vec<T::AccountId>>();
-			set_members::<T, I>(members.clone(), Some(members.len() - 1));
+			KycApplications::<T, I>::insert(
+				&who,
+				KycApplication { provider_id, payload_hash, deposit },
+			);
+			ProviderApplications::<T, I>::insert(provider_id, &who, ());
 
-			let to_remove = members.first().cloned().unwrap();
-			let to_remove_lookup = T::Lookup::unlookup(to_remove.clone());
-		}: {
-			assert_ok!(<Membership<T, I>>::remove_member(
-				T::RemoveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
-				to_remove_lookup,
-			));
-		} verify {
-			assert!(!<Members<T, I>>::get().contains(&to_remove));
-			// prime is rejigged
-			assert!(<Prime<T, I>>::get().is_some() && T::MembershipChanged::get_prime().is_some());
-			#[cfg(test)] crate::tests::clean();
+			Self::deposit_event(Event::KycApplied { who, provider_id, payload_hash });
+			Ok(())
 		}
 
-		// we remove a non-prime to make sure it needs to be set again.
+		/// Approve the pending KYC application of `who` at the given KYC `level` with the
+		/// verified `attributes`, the deposit is refunded and the KYC airdrop is paid unless the
+		/// account or its identity already received it. An account derived from a member is
+		/// unlinked and does not receive the airdrop, it shares the identity of the member.
+		///
+		/// May only be called from the provider the application was submitted to.
+		#[pallet::call_index(15)]
+		#[pallet::weight(
+			Weight::from_ref_time(50_000_000)
+				.saturating_add(Pallet::<T, I>::schedule_kyc_expiry_weight())
+				.saturating_add(Pallet::<T, I>::notify_members_changed_weight())
+		)]
+		pub fn approve_kyc_application(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			level: KycLevel,
+			attributes: Option<KycAttributesOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let provider_id = Self::ensure_provider(&sender)?;
+			let who = T::Lookup::lookup(who)?;
+			if let Some(attributes) = &attributes {
+				Self::check_country_code(&attributes.country_code)?;
+			}
+
+			let application = Self::pending_application(&who, provider_id)?;
+			// the link is removed when the derived account becomes a member
+			let derived = DerivedAccounts::<T, I>::contains_key(&who);
+			Self::do_add_member(who.clone(), level, provider_id, attributes)?;
+			Self::take_application(&who, &application);
+			T::Currency::unreserve(&who, application.deposit);
+
+			if !derived {
+				let _ = Self::transfer_kyc_airdrop(who.clone());
+			}
+
+			Self::deposit_event(Event::KycApplicationApproved { who, provider_id, level });
+			Ok(())
+		}
+
+		/// Reject the pending KYC application of `who` with the given `reason`, the deposit is
+		/// transferred to the pallet account.
+		///
+		/// May only be called from the provider the application was submitted to.
+		#[pallet::call_index(16)]
+		#[pallet::weight(50_000_000)]
+		pub fn reject_kyc_application(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			reason: BoundedVec<u8, T::MaxRejectionReasonLength>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let provider_id = Self::ensure_provider(&sender)?;
+			let who = T::Lookup::lookup(who)?;
+
+			let application = Self::pending_application(&who, provider_id)?;
+			Self::take_application(&who, &application);
+			let _ = T::Currency::repatriate_reserved(
+				&who,
+				&Self::account_id(),
+				application.deposit,
+				BalanceStatus::Free,
+			);
+
+			Self::deposit_event(Event::KycApplicationRejected { who, provider_id, reason });
+			Ok(())
+		}
+
+		/// Cancel the pending KYC application of the caller, the deposit is refunded.
+		#[pallet::call_index(17)]
+		#[pallet::weight(50_000_000)]
+		pub fn cancel_kyc_application(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let application =
+				Self::kyc_applications(&who).ok_or(Error::<T, I>::ApplicationNotFound)?;
+			Self::take_application(&who, &application);
+			T::Currency::unreserve(&who, application.deposit);
+
+			Self::deposit_event(Event::KycApplicationCancelled {
+				who,
+				provider_id: application.provider_id,
+			});
+			Ok(())
+		}
+
+		/// Propose to link the `derived` account to the caller, the derived account inherits the
+		/// KYC of the caller once it accepts the link with `accept_derived_link`. A pure proxy or
+		/// multisig accepts the link by dispatching the call through `pallet_proxy` or
+		/// `pallet_multisig`. A link proposed by another member has to be accepted or cancelled
+		/// first.
+		///
+		/// May only be called from a verified member.
+		#[pallet::call_index(18)]
+		#[pallet::weight(50_000_000)]
+		pub fn link_derived_account(
+			origin: OriginFor<T>,
+			derived: AccountIdLookupOf<T>,
+			account_type: DerivedAccountType,
+		) -> DispatchResult {
+			let parent = ensure_signed(origin)?;
+			let derived = T::Lookup::lookup(derived)?;
+
+			ensure!(parent != derived, Error::<T, I>::CannotLinkSelf);
+			ensure!(
+				Self::kyc_records(&parent)
+					.map_or(false, |record| Self::is_record_active(&parent, &record)),
+				Error::<T, I>::NotMember
+			);
+			ensure!(!<KycRecords<T, I>>::contains_key(&derived), Error::<T, I>::AlreadyMember);
+			ensure!(!DerivedAccounts::<T, I>::contains_key(&derived), Error::<T, I>::AlreadyLinked);
+			// the proposal of another member has to be accepted or cancelled first
+			ensure!(
+				Self::pending_derived_links(&derived).map_or(true, |link| link.parent == parent),
+				Error::<T, I>::LinkAlreadyProposed
+			);
+
+			PendingDerivedLinks::<T, I>::insert(
+				&derived,
+				DerivedAccount { parent: parent.clone(), account_type },
+			);
+
+			Self::deposit_event(Event::DerivedAccountLinkProposed {
+				parent,
+				derived,
+				account_type,
+			});
+			Ok(())
+		}
+
+		/// Accept the link to the `parent` member proposed with `link_derived_account`, the
+		/// caller inherits the KYC of the member until it is unlinked or the member is removed.
+		#[pallet::call_index(19)]
+		#[pallet::weight(50_000_000)]
+		pub fn accept_derived_link(
+			origin: OriginFor<T>,
+			parent: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let derived = ensure_signed(origin)?;
+			let parent = T::Lookup::lookup(parent)?;
+
+			let link = Self::pending_derived_links(&derived)
+				.filter(|link| link.parent == parent)
+				.ok_or(Error::<T, I>::LinkNotFound)?;
+			ensure!(
+				Self::kyc_records(&parent)
+					.map_or(false, |record| Self::is_record_active(&parent, &record)),
+				Error::<T, I>::NotMember
+			);
+			ensure!(!<KycRecords<T, I>>::contains_key(&derived), Error::<T, I>::AlreadyMember);
+			ensure!(!DerivedAccounts::<T, I>::contains_key(&derived), Error::<T, I>::AlreadyLinked);
+
+			LinkedAccounts::<T, I>::try_mutate(&parent, |linked| {
+				linked
+					.try_push(derived.clone())
+					.map_err(|_| Error::<T, I>::TooManyDerivedAccounts)
+			})?;
+			PendingDerivedLinks::<T, I>::remove(&derived);
+			DerivedAccounts::<T, I>::insert(&derived, link.clone());
+
+			Self::deposit_event(Event::DerivedAccountLinked {
+				parent,
+				derived,
+				account_type: link.account_type,
+			});
+			Ok(())
+		}
+
+		/// Unlink the `derived` account from its member, or cancel a proposed link.
+		///
+		/// May be called from the member or the derived account.
+		#[pallet::call_index(20)]
+		#[pallet::weight(50_000_000)]
+		pub fn unlink_derived_account(
+			origin: OriginFor<T>,
+			derived: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let derived = T::Lookup::lookup(derived)?;
+
+			if let Some(link) = Self::pending_derived_links(&derived) {
+				ensure!(sender == link.parent || sender == derived, Error::<T, I>::NotAuthorised);
+				PendingDerivedLinks::<T, I>::remove(&derived);
+				return Ok(())
+			}
+
+			let link = Self::derived_accounts(&derived).ok_or(Error::<T, I>::LinkNotFound)?;
+			ensure!(sender == link.parent || sender == derived, Error::<T, I>::NotAuthorised);
+			Self::unlink(&derived, &link.parent);
+			Ok(())
+		}
+
+		/// Apply the judgements of the on-chain identity of `who` to its KYC, as reported by
+		/// `T::IdentityProvider`. A `KnownGood` or `Reasonable` judgement grants the KYC at
+		/// `IdentityKycLevel`, losing that judgement revokes a KYC granted from the identity.
+		/// Memberships attested by a provider are not changed.
+		///
+		/// The identity pallet has no hook on judgement changes, so the grant is applied by this
+		/// call. A revocation does not wait for it: the KYC of an identity member is only in effect
+		/// while its identity is verified, this call then removes the stale record.
+		///
+		/// May be called from any signed account, for example by the registrar after a judgement.
+		#[pallet::call_index(21)]
+		#[pallet::weight(
+			Weight::from_ref_time(50_000_000)
+				.saturating_add(Pallet::<T, I>::notify_members_changed_weight())
+		)]
+		pub fn sync_identity_judgement(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+
+			match T::IdentityProvider::identity_status(&who) {
+				IdentityStatus::Verified => {
+					ensure!(!<KycRecords<T, I>>::contains_key(&who), Error::<T, I>::AlreadyMember);
+
+					// the identity judgement is the source of truth, the membership does not expire
+					let level = T::IdentityKycLevel::get();
+					Self::insert_kyc_record(
+						&who,
+						KycRecord { level, expires_at: None, attributes: None, provider_id: None },
+					);
+					<MemberCount<T, I>>::mutate(|count| *count = count.saturating_add(1));
+					IdentityMembers::<T, I>::insert(&who, ());
+
+					Self::notify_members_changed(&[who.clone()], &[]);
+
+					Self::deposit_event(Event::MemberAdded { who: who.clone(), level });
+					Self::deposit_event(Event::IdentityKycGranted { who, level });
+				},
+				IdentityStatus::Revoked | IdentityStatus::Unverified => {
+					ensure!(
+						IdentityMembers::<T, I>::contains_key(&who),
+						Error::<T, I>::IdentityUnchanged
+					);
+					Self::take_kyc_record(&who).ok_or(Error::<T, I>::NotMember)?;
+					<MemberCount<T, I>>::mutate(|count| *count = count.saturating_sub(1));
+					Self::revoke_derived_accounts(&who);
+
+					Self::notify_members_changed(&[], &[who.clone()]);
+
+					Self::deposit_event(Event::MemberRemoved { who: who.clone() });
+					Self::deposit_event(Event::IdentityKycRevoked { who });
+				},
+			}
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Checks if the given account_id is part of authorized account list
+	pub fn check_authorized_account(
+		account_id: &T::AccountId,
+	) -> frame_support::pallet_prelude::DispatchResult {
+		let authorized_accounts = AuthorizedAccounts::<T, I>::get();
+		if !authorized_accounts.contains(account_id) {
+			Err(Error::<T, I>::NotAuthorised.into())
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Returns the provider id of the given account, the account must be authorized
+	pub fn ensure_provider(
+		account_id: &T::AccountId,
+	) -> Result<ProviderId, frame_support::pallet_prelude::DispatchError> {
+		Self::check_authorized_account(account_id)?;
+		Self::provider_id(account_id).ok_or_else(|| Error::<T, I>::NotAuthorised.into())
+	}
+
+	/// Ensures the record of `who` is attested by the given provider, records without a provider
+	/// can be changed by any provider except the records granted from an on-chain identity, which
+	/// only follow the identity judgements
+	fn ensure_attesting_provider(
+		who: &T::AccountId,
+		record: &KycRecordOf<T>,
+		provider_id: ProviderId,
+	) -> frame_support::pallet_prelude::DispatchResult {
+		if IdentityMembers::<T, I>::contains_key(who) {
+			return Err(Error::<T, I>::IdentityGrantedKyc.into())
+		}
+		match record.provider_id {
+			Some(attested_by) if attested_by != provider_id =>
+				Err(Error::<T, I>::NotAttestingProvider.into()),
+			_ => Ok(()),
+		}
+	}
+
+	/// Moves the attestation of the record of `who` to the given provider, a KYC granted from an
+	/// on-chain identity cannot be attested by a provider
+	fn attest_record(
+		who: &T::AccountId,
+		record: &mut KycRecordOf<T>,
+		provider_id: ProviderId,
+	) -> frame_support::pallet_prelude::DispatchResult {
+		frame_support::ensure!(
+			!IdentityMembers::<T, I>::contains_key(who),
+			Error::<T, I>::IdentityGrantedKyc
+		);
+		if let Some(old_provider_id) = record.provider_id {
+			ProviderAttestations::<T, I>::remove(old_provider_id, who);
+		}
+		ProviderAttestations::<T, I>::insert(provider_id, who, ());
+		record.provider_id = Some(provider_id);
+		Ok(())
+	}
+
+	/// Adds `who` to the members, verified at the given `level` and attested by the provider
+	pub fn do_add_member(
+		who: T::AccountId,
+		level: KycLevel,
+		provider_id: ProviderId,
+		attributes: Option<KycAttributesOf<T>>,
+	) -> frame_support::pallet_prelude::DispatchResult {
+		frame_support::ensure!(
+			!<KycRecords<T, I>>::contains_key(&who),
+			Error::<T, I>::AlreadyMember
+		);
+		let expires_at = Self::schedule_kyc_expiry(&who, Self::next_kyc_expiry())?;
+		Self::insert_kyc_record(
+			&who,
+			KycRecord {
+				level,
+				expires_at: Some(expires_at),
+				attributes,
+				provider_id: Some(provider_id),
+			},
+		);
+		<MemberCount<T, I>>::mutate(|count| *count = count.saturating_add(1));
+
+		Self::notify_members_changed(&[who.clone()], &[]);
+
+		Self::deposit_event(Event::MemberAdded { who, level });
+		Ok(())
+	}
+
+	/// Returns the pending KYC application of `who` submitted to the given provider
+	fn pending_application(
+		who: &T::AccountId,
+		provider_id: ProviderId,
+	) -> Result<KycApplicationOf<T, I>, frame_support::pallet_prelude::DispatchError> {
+		Self::kyc_applications(who)
+			.filter(|application| application.provider_id == provider_id)
+			.ok_or_else(|| Error::<T, I>::ApplicationNotFound.into())
+	}
+
+	/// Removes the KYC application of `who`, the deposit is not released
+	fn take_application(who: &T::AccountId, application: &KycApplicationOf<T, I>) {
+		KycApplications::<T, I>::remove(who);
+		ProviderApplications::<T, I>::remove(application.provider_id, who);
+	}
+
+	/// Returns the accounts with a pending KYC application submitted to the given provider
+	pub fn provider_applications(provider_id: ProviderId) -> Vec<T::AccountId> {
+		ProviderApplications::<T, I>::iter_key_prefix(provider_id).collect()
+	}
+
+	/// Checks that the country code is an assigned ISO 3166-1 alpha-2 code
+	pub fn check_country_code(
+		country_code: &CountryCode,
+	) -> frame_support::pallet_prelude::DispatchResult {
+		if !is_valid_country_code(country_code) {
+			Err(Error::<T, I>::InvalidCountryCode.into())
+		} else {
+			Ok(())
+		}
+	}
+
+	/// The account ID of the KYC pallet
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// The sorted list of members, the membership is read from storage in full
+	pub fn members() -> Vec<T::AccountId> {
+		let mut members: Vec<T::AccountId> = KycRecords::<T, I>::iter_keys().collect();
+		members.sort();
+		members
+	}
+
+	/// Signals `T::MembershipChanged` of a membership change if enabled, the sorted membership is
+	/// only read when a consumer is notified
+	fn notify_members_changed(incoming: &[T::AccountId], outgoing: &[T::AccountId]) {
+		if T::NotifyMembershipChanged::get() {
+			T::MembershipChanged::change_members_sorted(incoming, outgoing, &Self::members()[..]);
+		}
+	}
+
+	/// The weight of reading the membership in `notify_members_changed`
+	pub fn notify_members_changed_weight() -> Weight {
+		if T::NotifyMembershipChanged::get() {
+			T::DbWeight::get().reads(Self::member_count().saturating_add(1).into())
+		} else {
+			Weight::zero()
+		}
+	}
+
+	/// Stores the KYC record of `who` and the attestation of its provider, a derived account
+	/// becoming a member is unlinked from its parent
+	fn insert_kyc_record(who: &T::AccountId, record: KycRecordOf<T>) {
+		if let Some(link) = Self::derived_accounts(who) {
+			Self::unlink(who, &link.parent);
+		}
+		if let Some(provider_id) = record.provider_id {
+			ProviderAttestations::<T, I>::insert(provider_id, who, ());
+		}
+		<KycRecords<T, I>>::insert(who, record);
+	}
+
+	/// Removes the KYC record of `who` with its expiry and the attestation of its provider
+	fn take_kyc_record(who: &T::AccountId) -> Option<KycRecordOf<T>> {
+		let record = <KycRecords<T, I>>::take(who)?;
+		Self::clear_kyc_expiry(who, &record);
+		IdentityMembers::<T, I>::remove(who);
+		if let Some(provider_id) = record.provider_id {
+			ProviderAttestations::<T, I>::remove(provider_id, who);
+		}
+		Some(record)
+	}
+
+	/// Moves the KYC record of `from` to `to` when a member key is replaced
+	fn move_kyc_record(
+		from: &T::AccountId,
+		to: &T::AccountId,
+	) -> frame_support::pallet_prelude::DispatchResult {
+		let identity_member = IdentityMembers::<T, I>::contains_key(from);
+		if let Some(mut record) = Self::take_kyc_record(from) {
+			// an expired record keeps its expiry, the queue of a past block is never drained
+			if let Some(expires_at) = record.expires_at {
+				if expires_at > frame_system::Pallet::<T>::block_number() {
+					record.expires_at = Some(Self::schedule_kyc_expiry(to, expires_at)?);
+				}
+			}
+			Self::insert_kyc_record(to, record);
+			// the new key is bound to the identity judgements of its own identity
+			if identity_member {
+				IdentityMembers::<T, I>::insert(to, ());
+			}
+		}
+		// the derived accounts follow the new key
+		let linked = LinkedAccounts::<T, I>::take(from);
+		for derived in linked.iter() {
+			DerivedAccounts::<T, I>::mutate(derived, |maybe_link| {
+				if let Some(link) = maybe_link {
+					link.parent = to.clone();
+				}
+			});
+		}
+		if !linked.is_empty() {
+			LinkedAccounts::<T, I>::insert(to, linked);
+		}
+		// the new key cannot claim the airdrop again
+		if let Some(claimed_at) = AirdropClaimed::<T, I>::get(from) {
+			AirdropClaimed::<T, I>::insert(to, claimed_at);
+		}
+		Ok(())
+	}
+
+	/// Removes the link of the `derived` account to its `parent`
+	fn unlink(derived: &T::AccountId, parent: &T::AccountId) {
+		DerivedAccounts::<T, I>::remove(derived);
+		LinkedAccounts::<T, I>::mutate_exists(parent, |maybe_linked| {
+			if let Some(linked) = maybe_linked {
+				linked.retain(|account| account != derived);
+				if linked.is_empty() {
+					*maybe_linked = None;
+				}
+			}
+		});
+		Self::deposit_event(Event::DerivedAccountUnlinked {
+			parent: parent.clone(),
+			derived: derived.clone(),
+		});
+	}
+
+	/// Unlinks every derived account of a member that lost its KYC, returns the number of
+	/// unlinked accounts
+	fn revoke_derived_accounts(parent: &T::AccountId) -> u32 {
+		let linked = LinkedAccounts::<T, I>::take(parent);
+		let count = linked.len() as u32;
+		for derived in linked.into_iter() {
+			DerivedAccounts::<T, I>::remove(&derived);
+			Self::deposit_event(Event::DerivedAccountUnlinked { parent: parent.clone(), derived });
+		}
+		count
+	}
+
+	/// The KYC record the account is verified by, derived accounts are verified by the record of
+	/// their parent
+	pub fn verifying_record(who: &T::AccountId) -> Option<KycRecordOf<T>> {
+		Self::kyc_records(who)
+			.or_else(|| Self::derived_accounts(who).and_then(|link| Self::kyc_records(link.parent)))
+	}
+
+	/// The expiry block of a KYC verified or renewed in the current block
+	fn next_kyc_expiry() -> T::BlockNumber {
+		frame_system::Pallet::<T>::block_number().saturating_add(T::KycValidityPeriod::get())
+	}
+
+	/// Schedules the KYC of `who` to expire at `expires_at`, if the expiry queue of that block is
+	/// full the expiry is moved to the next block with capacity. Returns the scheduled block.
+	pub fn schedule_kyc_expiry(
+		who: &T::AccountId,
+		mut expires_at: T::BlockNumber,
+	) -> Result<T::BlockNumber, frame_support::pallet_prelude::DispatchError> {
+		for _ in 0..MAX_EXPIRY_SCHEDULE_ATTEMPTS {
+			// a full queue is left untouched
+			let scheduled =
+				ExpiryQueue::<T, I>::try_mutate(expires_at, |queue| queue.try_push(who.clone()));
+			if scheduled.is_ok() {
+				return Ok(expires_at)
+			}
+			expires_at = expires_at.saturating_add(One::one());
+		}
+		Err(Error::<T, I>::TooManyExpiries.into())
+	}
+
+	/// The worst case weight of `schedule_kyc_expiry`, every attempt reads a queue
+	pub fn schedule_kyc_expiry_weight() -> Weight {
+		T::DbWeight::get().reads_writes(MAX_EXPIRY_SCHEDULE_ATTEMPTS.into(), 1)
+	}
+
+	/// Removes `who` from the expiry queue of the given record, an empty queue is removed
+	fn clear_kyc_expiry(who: &T::AccountId, record: &KycRecordOf<T>) {
+		if let Some(expires_at) = record.expires_at {
+			ExpiryQueue::<T, I>::mutate_exists(expires_at, |maybe_queue| {
+				if let Some(queue) = maybe_queue {
+					queue.retain(|account| account != who);
+					if queue.is_empty() {
+						*maybe_queue = None;
+					}
+				}
+			});
+		}
+	}
+
+	/// Returns true if the KYC record has expired, records without an expiry do not expire
+	pub fn is_kyc_expired(record: &KycRecordOf<T>) -> bool {
+		record
+			.expires_at
+			.map_or(false, |expires_at| expires_at <= frame_system::Pallet::<T>::block_number())
+	}
+
+	/// Returns true if the KYC record of `holder` is in effect, the record has not expired and a
+	/// KYC granted from the on-chain identity ends as soon as the identity is no longer verified
+	pub fn is_record_active(holder: &T::AccountId, record: &KycRecordOf<T>) -> bool {
+		!Self::is_kyc_expired(record) &&
+			(!IdentityMembers::<T, I>::contains_key(holder) ||
+				T::IdentityProvider::identity_status(holder) == IdentityStatus::Verified)
+	}
+
+	/// The KYC record verifying the account if it is in effect, see `verifying_record`
+	pub fn active_record(who: &T::AccountId) -> Option<KycRecordOf<T>> {
+		match Self::kyc_records(who) {
+			Some(record) => Some((who.clone(), record)),
+			None => Self::derived_accounts(who).and_then(|link| {
+				Self::kyc_records(&link.parent).map(|record| (link.parent, record))
+			}),
+		}
+		.filter(|(holder, record)| Self::is_record_active(holder, record))
+		.map(|(_, record)| record)
+	}
+
+	/// The verified identity of the given account, the provider reference of its attributes
+	pub fn kyc_identity(who: &T::AccountId) -> Option<T::Hash> {
+		Self::verifying_record(who)
+			.and_then(|record| record.attributes)
+			.map(|attributes| attributes.provider_reference)
+	}
+
+	/// Airdrop native tokens to user, the airdrop is paid once per account and once per verified
+	/// identity
+	pub fn transfer_kyc_airdrop(
+		who: T::AccountId,
+	) -> frame_support::pallet_prelude::DispatchResult {
+		// transfer airdrop if the amount is set
+		if let Some(amount) = Self::airdrop_amount() {
+			// derived accounts share the identity of their parent
+			if AirdropClaimed::<T, I>::contains_key(&who) ||
+				DerivedAccounts::<T, I>::contains_key(&who)
+			{
+				return Ok(())
+			}
+
+			let identity = Self::kyc_identity(&who);
+			if let Some(identity) = identity {
+				if AirdropClaimedIdentities::<T, I>::contains_key(identity) {
+					return Ok(())
+				}
+			}
+
+			let pallet_account = Self::account_id();
+			let airdrop_executed = T::Currency::transfer(
+				&pallet_account,
+				&who,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			);
+
+			if airdrop_executed.is_err() {
+				Self::deposit_event(Event::AirdropFailed { who, amount });
+				return Ok(())
+			}
+
+			AirdropClaimed::<T, I>::insert(&who, frame_system::Pallet::<T>::block_number());
+			if let Some(identity) = identity {
+				AirdropClaimedIdentities::<T, I>::insert(identity, &who);
+			}
+			Self::deposit_event(Event::KYCAirdrop { who, amount });
+
+			let balance = T::Currency::free_balance(&pallet_account);
+			if balance < T::AirdropLowBalanceThreshold::get() {
+				Self::deposit_event(Event::AirdropBalanceLow { balance });
+			}
+		}
+		Ok(())
+	}
+}
+
+impl<T: Config<I>, I: 'static> Contains<T::AccountId> for Pallet<T, I> {
+	fn contains(t: &T::AccountId) -> bool {
+		Self::active_record(t).is_some()
+	}
+}
+
+impl<T: Config<I>, I: 'static> KycLevelProvider<T::AccountId> for Pallet<T, I> {
+	fn kyc_level(who: &T::AccountId) -> Option<KycLevel> {
+		Self::active_record(who).map(|record| record.level)
+	}
+}
+
+impl<T: Config<I>, I: 'static> JurisdictionProvider<T::AccountId> for Pallet<T, I> {
+	fn country_code(who: &T::AccountId) -> Option<CountryCode> {
+		Self::verifying_record(who)
+			.and_then(|record| record.attributes)
+			.map(|attributes| attributes.country_code)
+	}
+
+	fn is_jurisdiction_blocked(country_code: &CountryCode) -> bool {
+		BlockedJurisdictions::<T, I>::contains_key(country_code)
+	}
+}
+
+impl<T: Config<I>, I: 'static> SortedMembers<T::AccountId> for Pallet<T, I> {
+	fn sorted_members() -> Vec<T::AccountId> {
+		Self::members()
+	}
+
+	fn count() -> usize {
+		Self::member_count() as usize
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmark {
+	use super::{Pallet as Membership, *};
+	use frame_benchmarking::v1::{account, benchmarks_instance_pallet, whitelist, BenchmarkError};
+	use frame_support::{assert_ok, traits::EnsureOrigin};
+	use frame_system::RawOrigin;
+
+	const SEED: u32 = 0;
+
+	fn set_members<T: Config<I>, I: 'static>(members: Vec<T::AccountId>, prime: Option<usize>) {
+		let reset_origin = T::ResetOrigin::try_successful_origin()
+			.expect("ResetOrigin has no successful origin required for the benchmark");
+		let prime_origin = T::PrimeOrigin::try_successful_origin()
+			.expect("PrimeOrigin has no successful origin required for the benchmark");
+
+		assert_ok!(<Membership<T, I>>::reset_members(
+			reset_origin,
+			members.clone(),
+			<Membership<T, I>>::member_count()
+		));
+		if let Some(prime) = prime.map(|i| members[i].clone()) {
+			let prime_lookup = T::Lookup::unlookup(prime);
+			assert_ok!(<Membership<T, I>>::set_prime(prime_origin, prime_lookup));
+		} else {
+			assert_ok!(<Membership<T, I>>::clear_prime(prime_origin));
+		}
+	}
+
+	benchmarks_instance_pallet! {
+		add_member {
+			let m in 1 .. (T::MaxMembers::get() - 1);
+
+			let members = (0..m).map(|i| account("member", i, SEED)).collect::<Vec<T::AccountId>>();
+			set_members::<T, I>(members, None);
+			let new_member = account::<T::AccountId>("add", m, SEED);
+			let new_member_lookup = T::Lookup::unlookup(new_member.clone());
+		}: {
+			assert_ok!(<Membership<T, I>>::add_member(
+				T::AddOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+				new_member_lookup,
+				KycLevel::Basic,
+			));
+		} verify {
+			assert!(<Membership<T, I>>::members().contains(&new_member));
+			#[cfg(test)] crate::tests::clean();
+		}
+
+		// the case of no prime or the prime being removed is surely cheaper than the case of
+		// reporting a new prime via `MembershipChanged`.
+		remove_member {
+			let m in 2 .. T::MaxMembers::get();
+
+			let members = (0..m).map(|i| account("member", i, SEED)).collect::<Vec<T::AccountId>>();
+			set_members::<T, I>(members.clone(), Some(members.len() - 1));
+
+			let to_remove = members.first().cloned().unwrap();
+			let to_remove_lookup = T::Lookup::unlookup(to_remove.clone());
+		}: {
+			assert_ok!(<Membership<T, I>>::remove_member(
+				T::RemoveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+				to_remove_lookup,
+			));
+		} verify {
+			assert!(!<Membership<T, I>>::members().contains(&to_remove));
+			// prime is rejigged
+			assert!(<Prime<T, I>>::get().is_some() && T::MembershipChanged::get_prime().is_some());
+			#[cfg(test)] crate::tests::clean();
+		}
+
+		// we remove a non-prime to make sure it needs to be set again.
 		swap_member {
 			let m in 2 .. T::MaxMembers::get();
 
@@ -500,8 +1758,8 @@ mod benchmark {
 				add_lookup,
 			));
 		} verify {
-			assert!(!<Members<T, I>>::get().contains(&remove));
-			assert!(<Members<T, I>>::get().contains(&add));
+			assert!(!<Membership<T, I>>::members().contains(&remove));
+			assert!(<Membership<T, I>>::members().contains(&add));
 			// prime is rejigged
 			assert!(<Prime<T, I>>::get().is_some() && T::MembershipChanged::get_prime().is_some());
 			#[cfg(test)] crate::tests::clean();
@@ -518,10 +1776,11 @@ mod benchmark {
 			assert_ok!(<Membership<T, I>>::reset_members(
 				T::ResetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
 				new_members.clone(),
+				m,
 			));
 		} verify {
 			new_members.sort();
-			assert_eq!(<Members<T, I>>::get(), new_members);
+			assert_eq!(<Membership<T, I>>::members(), new_members);
 			// prime is rejigged
 			assert!(<Prime<T, I>>::get().is_some() && T::MembershipChanged::get_prime().is_some());
 			#[cfg(test)] crate::tests::clean();
@@ -541,8 +1800,8 @@ mod benchmark {
 		}: {
 			assert_ok!(<Membership<T, I>>::change_key(RawOrigin::Signed(prime.clone()).into(), add_lookup));
 		} verify {
-			assert!(!<Members<T, I>>::get().contains(&prime));
-			assert!(<Members<T, I>>::get().contains(&add));
+			assert!(!<Membership<T, I>>::members().contains(&prime));
+			assert!(<Membership<T, I>>::members().contains(&add));
 			// prime is rejigged
 			assert_eq!(<Prime<T, I>>::get().unwrap(), add);
 			#[cfg(test)] crate::tests::clean();
@@ -593,12 +1852,12 @@ mod tests {
 	use sp_core::H256;
 	use sp_runtime::{
 		testing::Header,
-		traits::{BlakeTwo256, IdentityLookup},
+		traits::{BadOrigin, BlakeTwo256, IdentityLookup},
 	};
 
 	use frame_support::{
 		assert_noop, assert_ok, bounded_vec, ord_parameter_types, parameter_types,
-		traits::{ConstU32, ConstU64, GenesisBuild},
+		traits::{ConstBool, ConstU128, ConstU32, ConstU64, GenesisBuild, Hooks},
 	};
 
 	type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -636,6 +1895,8 @@ mod tests {
 		pub static Members: Vec<u64> = vec![];
 		pub static Prime: Option<u64> = None;
 		pub const KycPalletId: PalletId = PalletId(*b"bitg/kyc");
+		pub static IdentityStatuses: Vec<(u64, IdentityStatus)> = vec![];
+		pub const IdentityKycLevel: KycLevel = KycLevel::Enhanced;
 	}
 
 	impl frame_system::Config for Test {
@@ -700,73 +1961,650 @@ mod tests {
 		}
 	}
 
+	pub struct TestIdentityProvider;
+	impl IdentityStatusProvider<u64> for TestIdentityProvider {
+		fn identity_status(who: &u64) -> IdentityStatus {
+			IdentityStatuses::get()
+				.into_iter()
+				.find(|(account, _)| account == who)
+				.map_or(IdentityStatus::Unverified, |(_, status)| status)
+		}
+	}
+
 	impl Config for Test {
 		type RuntimeEvent = RuntimeEvent;
 		type AddOrigin = frame_system::EnsureRoot<u64>;
 		type MembershipInitialized = TestChangeMembers;
 		type MembershipChanged = TestChangeMembers;
+		type NotifyMembershipChanged = ConstBool<true>;
 		type MaxMembers = ConstU32<10>;
 		type MaxAuthorizedAccountCount = ConstU32<10>;
 		type PalletId = KycPalletId;
 		type Currency = Balances;
+		type KycApplicationDeposit = ConstU128<5>;
+		type MaxRejectionReasonLength = ConstU32<64>;
+		type AirdropLowBalanceThreshold = ConstU128<50>;
+		type KycValidityPeriod = ConstU64<100>;
+		type KycExpiryNoticePeriod = ConstU64<10>;
+		type MaxExpiriesPerBlock = ConstU32<2>;
+		type MaxDerivedAccounts = ConstU32<2>;
+		type IdentityProvider = TestIdentityProvider;
+		type IdentityKycLevel = IdentityKycLevel;
 		type WeightInfo = ();
 	}
 
-	pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
-		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
-		// We use default for brevity, but you can configure as desired if needed.
-		pallet_membership::GenesisConfig::<Test> {
-			members: bounded_vec![10, 20, 30],
-			..Default::default()
-		}
-		.assimilate_storage(&mut t)
-		.unwrap();
-		t.into()
+	pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+		// We use default for brevity, but you can configure as desired if needed.
+		pallet_membership::GenesisConfig::<Test> {
+			members: bounded_vec![10, 20, 30],
+			..Default::default()
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+		let mut ext: sp_io::TestExternalities = t.into();
+		// set to block 1 to test events
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+
+	/// helper function to get the kyc expiry of a member
+	fn kyc_expiry(who: u64) -> Option<u64> {
+		Membership::kyc_records(who).and_then(|record| record.expires_at)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	pub(crate) fn new_bench_ext() -> sp_io::TestExternalities {
+		frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	pub(crate) fn clean() {
+		Members::set(vec![]);
+		Prime::set(None);
+	}
+
+	#[test]
+	fn query_membership_works() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(Membership::members(), vec![10, 20, 30]);
+			assert_eq!(MEMBERS.with(|m| m.borrow().clone()), vec![10, 20, 30]);
+		});
+	}
+
+	#[test]
+	fn add_member_works() {
+		new_test_ext().execute_with(|| {
+			let authorised_account = 1;
+			assert_ok!(Membership::force_add_authorized_account(
+				RawOrigin::Root.into(),
+				authorised_account,
+			));
+			assert_noop!(
+				Membership::add_member(RuntimeOrigin::signed(5), 15, KycLevel::Basic),
+				crate::Error::<Test, _>::NotAuthorised
+			);
+			assert_noop!(
+				Membership::add_member(
+					RuntimeOrigin::signed(authorised_account),
+					10,
+					KycLevel::Basic
+				),
+				Error::<Test, _>::AlreadyMember
+			);
+			assert_ok!(Membership::add_member(
+				RuntimeOrigin::signed(authorised_account),
+				15,
+				KycLevel::Basic
+			));
+			assert_eq!(Membership::members(), vec![10, 15, 20, 30]);
+			assert_eq!(MEMBERS.with(|m| m.borrow().clone()), Membership::members().to_vec());
+		});
+	}
+
+	#[test]
+	fn add_member_is_not_capped_by_max_members() {
+		new_test_ext().execute_with(|| {
+			let authorised_account = 1;
+			assert_ok!(Membership::force_add_authorized_account(
+				RawOrigin::Root.into(),
+				authorised_account,
+			));
+			let max_members = <Test as Config>::MaxMembers::get() as u64;
+			for who in 100..(100 + max_members) {
+				assert_ok!(Membership::add_member(
+					RuntimeOrigin::signed(authorised_account),
+					who,
+					KycLevel::Basic
+				));
+			}
+			assert_eq!(Membership::member_count() as u64, max_members + 3);
+			assert!(Membership::contains(&(100 + max_members - 1)));
+		});
+	}
+
+	#[test]
+	fn reset_members_requires_governance_and_member_count_witness() {
+		new_test_ext().execute_with(|| {
+			let provider_a = 1;
+			let provider_b = 2;
+			assert_ok!(Membership::force_add_authorized_account(
+				RawOrigin::Root.into(),
+				provider_a
+			));
+			assert_ok!(Membership::force_add_authorized_account(
+				RawOrigin::Root.into(),
+				provider_b
+			));
+			assert_ok!(Membership::add_member(
+				RuntimeOrigin::signed(provider_a),
+				40,
+				KycLevel::Enhanced
+			));
+
+			// a provider cannot wipe the members of another provider
+			assert_noop!(
+				Membership::reset_members(RuntimeOrigin::signed(provider_b), vec![50], 4),
+				BadOrigin
+			);
+			assert_eq!(Membership::kyc_level(&40), Some(KycLevel::Enhanced));
+
+			// the witness cannot be lower than the member count
+			assert_noop!(
+				Membership::reset_members(RawOrigin::Root.into(), vec![40, 50], 3),
+				Error::<Test, _>::InvalidMemberCountWitness
+			);
+
+			assert_ok!(Membership::reset_members(RawOrigin::Root.into(), vec![50, 40], 4));
+			assert_eq!(Membership::member_count(), 2);
+			assert_eq!(Membership::members(), vec![40, 50]);
+			assert_eq!(Members::get(), vec![40, 50]);
+			// the retained member keeps its record, the new member has no attesting provider
+			assert_eq!(Membership::kyc_level(&40), Some(KycLevel::Enhanced));
+			assert_eq!(KycRecords::<Test>::get(50).unwrap().provider_id, None);
+			assert_eq!(kyc_expiry(50), Some(101));
+		});
+	}
+
+	#[test]
+	fn kyc_airdrop_is_only_paid_on_approval() {
+		new_test_ext().execute_with(|| {
+			let authorised_account = 1;
+			assert_ok!(Membership::force_add_authorized_account(
+				RawOrigin::Root.into(),
+				authorised_account,
+			));
+
+			// set the airdrop amount
+			let airdrop_amount = 10;
+			assert_ok!(Membership::force_set_kyc_airdrop(
+				RawOrigin::Root.into(),
+				Some(airdrop_amount),
+			));
+
+			// set some balance to the pallet account
+			let kyc_pallet_account: u64 = PalletId(*b"bitg/kyc").into_account_truncating();
+			Balances::make_free_balance_be(&kyc_pallet_account, 100);
+
+			// no airdrop is paid when a member is added directly
+			let balance_before_kyc = Balances::free_balance(&15);
+			assert_ok!(Membership::add_member(
+				RuntimeOrigin::signed(authorised_account),
+				15,
+				KycLevel::Basic
+			));
+			assert_eq!(Membership::members(), vec![10, 15, 20, 30]);
+			assert_eq!(MEMBERS.with(|m| m.borrow().clone()), Membership::members().to_vec());
+			assert_eq!(Balances::free_balance(&15), balance_before_kyc);
+
+			// the airdrop is paid when the application is approved
+			Balances::make_free_balance_be(&16, 100);
+			assert_ok!(Membership::apply_for_kyc(RuntimeOrigin::signed(16), 0, H256::zero()));
+			assert_ok!(Membership::approve_kyc_application(
+				RuntimeOrigin::signed(authorised_account),
+				16,
+				KycLevel::Basic,
+				None
+			));
+			System::assert_has_event(
+				Event::<Test>::KYCAirdrop { who: 16, amount: airdrop_amount }.into(),
+			);
+			assert_eq!(Balances::free_balance(&16), 100 + airdrop_amount);
+		});
+	}
+
+	#[test]
+	fn kyc_airdrop_is_not_paid_to_derived_accounts() {
+		new_test_ext().execute_with(|| {
+			let provider = 1;
+			let parent = 10;
+			let derived = 15;
+			assert_ok!(Membership::force_add_authorized_account(RawOrigin::Root.into(), provider));
+			assert_ok!(Membership::force_set_kyc_airdrop(RawOrigin::Root.into(), Some(10)));
+			let kyc_pallet_account: u64 = PalletId(*b"bitg/kyc").into_account_truncating();
+			Balances::make_free_balance_be(&kyc_pallet_account, 100);
+			Balances::make_free_balance_be(&derived, 100);
+
+			assert_ok!(Membership::link_derived_account(
+				RuntimeOrigin::signed(parent),
+				derived,
+				DerivedAccountType::SubAccount
+			));
+			assert_ok!(Membership::accept_derived_link(RuntimeOrigin::signed(derived), parent));
+
+			// the derived account is verified on its own without receiving the airdrop
+			assert_ok!(Membership::apply_for_kyc(RuntimeOrigin::signed(derived), 0, H256::zero()));
+			assert_ok!(Membership::approve_kyc_application(
+				RuntimeOrigin::signed(provider),
+				derived,
+				KycLevel::Basic,
+				None
+			));
+			assert!(Membership::derived_accounts(derived).is_none());
+			assert!(Membership::kyc_records(derived).is_some());
+			assert_eq!(Balances::free_balance(&derived), 100);
+			assert_eq!(Membership::airdrop_claimed(derived), None);
+		});
+	}
+
+	#[test]
+	fn kyc_airdrop_is_paid_once() {
+		new_test_ext().execute_with(|| {
+			let provider = 1;
+			assert_ok!(Membership::force_add_authorized_account(RawOrigin::Root.into(), provider));
+			let airdrop_amount = 10;
+			assert_ok!(Membership::force_set_kyc_airdrop(
+				RawOrigin::Root.into(),
+				Some(airdrop_amount),
+			));
+			let kyc_pallet_account: u64 = PalletId(*b"bitg/kyc").into_account_truncating();
+			Balances::make_free_balance_be(&kyc_pallet_account, 75);
+			for applicant in [15, 16, 17, 18, 19] {
+				Balances::make_free_balance_be(&applicant, 100);
+			}
+
+			let verify = |who: u64, attributes: Option<KycAttributesOf<Test>>| {
+				assert_ok!(Membership::apply_for_kyc(RuntimeOrigin::signed(who), 0, H256::zero()));
+				assert_ok!(Membership::approve_kyc_application(
+					RuntimeOrigin::signed(provider),
+					who,
+					KycLevel::Basic,
+					attributes
+				));
+			};
+
+			verify(15, None);
+			assert_eq!(Balances::free_balance(&15), 100 + airdrop_amount);
+			assert_eq!(Membership::airdrop_claimed(15), Some(1));
+
+			// the airdrop is not paid again after re-verification
+			assert_ok!(Membership::remove_member(RuntimeOrigin::signed(provider), 15));
+			verify(15, None);
+			assert_eq!(Balances::free_balance(&15), 100 + airdrop_amount);
+
+			// the airdrop is not paid again to a new key of the member
+			assert_ok!(Membership::change_key(RuntimeOrigin::signed(15), 16));
+			assert_ok!(Membership::remove_member(RuntimeOrigin::signed(provider), 16));
+			verify(16, None);
+			assert_eq!(Balances::free_balance(&16), 100);
+			assert!(Membership::airdrop_claimed(16).is_some());
+
+			// the airdrop is paid once per verified identity
+			let attributes = KycAttributes {
+				country_code: *b"DE",
+				entity_type: EntityType::Individual,
+				provider_reference: H256::repeat_byte(1),
+			};
+			verify(17, Some(attributes.clone()));
+			assert_eq!(Balances::free_balance(&17), 100 + airdrop_amount);
+			assert_eq!(Membership::airdrop_claimed_identities(H256::repeat_byte(1)), Some(17));
+			System::reset_events();
+			verify(18, Some(attributes));
+			assert_eq!(Balances::free_balance(&18), 100);
+			assert!(!System::events().iter().any(|record| matches!(
+				record.event,
+				RuntimeEvent::Membership(Event::KYCAirdrop { .. })
+			)));
+
+			// a warning is emitted when the pallet account runs low
+			assert_eq!(Balances::free_balance(&kyc_pallet_account), 55);
+			assert_ok!(Membership::apply_for_kyc(RuntimeOrigin::signed(19), 0, H256::zero()));
+			Balances::make_free_balance_be(&kyc_pallet_account, 15);
+			assert_ok!(Membership::approve_kyc_application(
+				RuntimeOrigin::signed(provider),
+				19,
+				KycLevel::Basic,
+				None
+			));
+			System::assert_has_event(Event::<Test>::AirdropBalanceLow { balance: 5 }.into());
+
+			// the failure is reported when the pallet account cannot pay the airdrop
+			Balances::make_free_balance_be(&20, 100);
+			assert_ok!(Membership::remove_member(RuntimeOrigin::signed(provider), 20));
+			assert_ok!(Membership::apply_for_kyc(RuntimeOrigin::signed(20), 0, H256::zero()));
+			assert_ok!(Membership::approve_kyc_application(
+				RuntimeOrigin::signed(provider),
+				20,
+				KycLevel::Basic,
+				None
+			));
+			System::assert_has_event(
+				Event::<Test>::AirdropFailed { who: 20, amount: airdrop_amount }.into(),
+			);
+			assert_eq!(Membership::airdrop_claimed(20), None);
+		});
+	}
+
+	#[test]
+	fn kyc_application_works() {
+		new_test_ext().execute_with(|| {
+			let provider_one = 1;
+			let provider_two = 2;
+			assert_ok!(Membership::force_add_authorized_account(
+				RawOrigin::Root.into(),
+				provider_one,
+			));
+			assert_ok!(Membership::force_add_authorized_account(
+				RawOrigin::Root.into(),
+				provider_two,
+			));
+			let kyc_pallet_account: u64 = PalletId(*b"bitg/kyc").into_account_truncating();
+			let payload_hash = H256::repeat_byte(1);
+			for applicant in [15, 16, 17] {
+				Balances::make_free_balance_be(&applicant, 100);
+			}
+
+			// the provider must exist and members cannot apply
+			assert_noop!(
+				Membership::apply_for_kyc(RuntimeOrigin::signed(15), 5, payload_hash),
+				Error::<Test, _>::ProviderNotFound
+			);
+			assert_noop!(
+				Membership::apply_for_kyc(RuntimeOrigin::signed(10), 0, payload_hash),
+				Error::<Test, _>::AlreadyMember
+			);
+
+			// the deposit is reserved from the applicant
+			assert_ok!(Membership::apply_for_kyc(RuntimeOrigin::signed(15), 0, payload_hash));
+			System::assert_last_event(
+				Event::<Test>::KycApplied { who: 15, provider_id: 0, payload_hash }.into(),
+			);
+			assert_eq!(Balances::reserved_balance(&15), 5);
+			assert_eq!(
+				Membership::kyc_applications(15),
+				Some(KycApplication { provider_id: 0, payload_hash, deposit: 5 })
+			);
+			assert_noop!(
+				Membership::apply_for_kyc(RuntimeOrigin::signed(15), 1, payload_hash),
+				Error::<Test, _>::ApplicationAlreadyExists
+			);
+			assert_ok!(Membership::apply_for_kyc(RuntimeOrigin::signed(16), 0, payload_hash));
+			assert_ok!(Membership::apply_for_kyc(RuntimeOrigin::signed(17), 1, payload_hash));
+
+			// providers see their pending applications
+			let mut pending = Membership::provider_applications(0);
+			pending.sort();
+			assert_eq!(pending, vec![15, 16]);
+			assert_eq!(Membership::provider_applications(1), vec![17]);
+
+			// only the provider the application was submitted to can process it
+			assert_noop!(
+				Membership::approve_kyc_application(
+					RuntimeOrigin::signed(provider_two),
+					15,
+					KycLevel::Basic,
+					None
+				),
+				Error::<Test, _>::ApplicationNotFound
+			);
+			assert_noop!(
+				Membership::approve_kyc_application(
+					RuntimeOrigin::signed(5),
+					15,
+					KycLevel::Basic,
+					None
+				),
+				Error::<Test, _>::NotAuthorised
+			);
+
+			// the deposit is refunded on approval
+			assert_ok!(Membership::approve_kyc_application(
+				RuntimeOrigin::signed(provider_one),
+				15,
+				KycLevel::Enhanced,
+				None
+			));
+			System::assert_last_event(
+				Event::<Test>::KycApplicationApproved {
+					who: 15,
+					provider_id: 0,
+					level: KycLevel::Enhanced,
+				}
+				.into(),
+			);
+			assert_eq!(Membership::kyc_level(&15), Some(KycLevel::Enhanced));
+			assert_eq!(Membership::kyc_records(15).unwrap().provider_id, Some(0));
+			assert_eq!(Membership::kyc_applications(15), None);
+			assert_eq!(Balances::reserved_balance(&15), 0);
+			assert_eq!(Balances::free_balance(&15), 100);
+
+			// the deposit is transferred to the pallet account on rejection
+			let reason: BoundedVec<u8, ConstU32<64>> =
+				b"documents expired".to_vec().try_into().unwrap();
+			assert_ok!(Membership::reject_kyc_application(
+				RuntimeOrigin::signed(provider_one),
+				16,
+				reason.clone()
+			));
+			System::assert_last_event(
+				Event::<Test>::KycApplicationRejected { who: 16, provider_id: 0, reason }.into(),
+			);
+			assert!(!Membership::contains(&16));
+			assert_eq!(Balances::total_balance(&16), 95);
+			assert_eq!(Balances::free_balance(&kyc_pallet_account), 5);
+			assert!(Membership::provider_applications(0).is_empty());
+
+			// the deposit is refunded when the applicant cancels
+			assert_noop!(
+				Membership::cancel_kyc_application(RuntimeOrigin::signed(16)),
+				Error::<Test, _>::ApplicationNotFound
+			);
+			assert_ok!(Membership::cancel_kyc_application(RuntimeOrigin::signed(17)));
+			System::assert_last_event(
+				Event::<Test>::KycApplicationCancelled { who: 17, provider_id: 1 }.into(),
+			);
+			assert_eq!(Balances::free_balance(&17), 100);
+			assert!(Membership::provider_applications(1).is_empty());
+
+			// removed providers cannot receive applications
+			assert_ok!(Membership::force_remove_authorized_account(
+				RawOrigin::Root.into(),
+				provider_two,
+			));
+			assert_noop!(
+				Membership::apply_for_kyc(RuntimeOrigin::signed(17), 1, payload_hash),
+				Error::<Test, _>::ProviderNotFound
+			);
+		});
 	}
 
-	#[cfg(feature = "runtime-benchmarks")]
-	pub(crate) fn new_bench_ext() -> sp_io::TestExternalities {
-		frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
-	}
+	#[test]
+	fn force_remove_authorized_account_works() {
+		new_test_ext().execute_with(|| {
+			// the authorized accounts are kept in insertion order
+			for account_id in [3, 1, 2] {
+				assert_ok!(Membership::force_add_authorized_account(
+					RawOrigin::Root.into(),
+					account_id
+				));
+			}
 
-	#[cfg(feature = "runtime-benchmarks")]
-	pub(crate) fn clean() {
-		Members::set(vec![]);
-		Prime::set(None);
+			assert_ok!(Membership::force_remove_authorized_account(RawOrigin::Root.into(), 1));
+			System::assert_last_event(
+				Event::<Test>::AuthorizedAccountRemoved { account_id: 1 }.into(),
+			);
+			assert_eq!(Membership::authorized_accounts().into_inner(), vec![3, 2]);
+			assert_eq!(Membership::provider_id(1), None);
+
+			assert_ok!(Membership::force_remove_authorized_account(RawOrigin::Root.into(), 2));
+			assert_eq!(Membership::authorized_accounts().into_inner(), vec![3]);
+			assert_eq!(Membership::provider_id(2), None);
+		});
 	}
 
 	#[test]
-	fn query_membership_works() {
+	fn kyc_levels_work() {
 		new_test_ext().execute_with(|| {
-			assert_eq!(Membership::members(), vec![10, 20, 30]);
-			assert_eq!(MEMBERS.with(|m| m.borrow().clone()), vec![10, 20, 30]);
+			let authorised_account = 1;
+			assert_ok!(Membership::force_add_authorized_account(
+				RawOrigin::Root.into(),
+				authorised_account,
+			));
+
+			// genesis members are verified at the basic level
+			assert_eq!(Membership::kyc_level(&10), Some(KycLevel::Basic));
+			assert_eq!(Membership::kyc_level(&15), None);
+			assert!(!Membership::has_kyc_level(&15, KycLevel::Basic));
+
+			assert_ok!(Membership::add_member(
+				RuntimeOrigin::signed(authorised_account),
+				15,
+				KycLevel::Enhanced
+			));
+			assert_eq!(Membership::kyc_level(&15), Some(KycLevel::Enhanced));
+			assert!(Membership::has_kyc_level(&15, KycLevel::Basic));
+			assert!(Membership::has_kyc_level(&15, KycLevel::Enhanced));
+			assert!(!Membership::has_kyc_level(&15, KycLevel::Institutional));
+
+			// only authorized accounts can update the level of a member
+			assert_noop!(
+				Membership::set_kyc_level(RuntimeOrigin::signed(5), 15, KycLevel::Institutional),
+				Error::<Test, _>::NotAuthorised
+			);
+			assert_noop!(
+				Membership::set_kyc_level(
+					RuntimeOrigin::signed(authorised_account),
+					25,
+					KycLevel::Institutional
+				),
+				Error::<Test, _>::NotMember
+			);
+			assert_ok!(Membership::set_kyc_level(
+				RuntimeOrigin::signed(authorised_account),
+				15,
+				KycLevel::Institutional
+			));
+			assert!(Membership::has_kyc_level(&15, KycLevel::Institutional));
+
+			// the level follows the member on key change
+			assert_ok!(Membership::change_key(RuntimeOrigin::signed(15), 40));
+			assert_eq!(Membership::kyc_level(&15), None);
+			assert_eq!(Membership::kyc_level(&40), Some(KycLevel::Institutional));
+
+			// the level is removed with the member
+			assert_ok!(Membership::remove_member(RuntimeOrigin::signed(authorised_account), 40));
+			assert_eq!(Membership::kyc_level(&40), None);
+			assert_eq!(KycRecords::<Test>::get(40), None);
 		});
 	}
 
 	#[test]
-	fn add_member_works() {
+	fn kyc_expiry_and_renewal_works() {
 		new_test_ext().execute_with(|| {
 			let authorised_account = 1;
 			assert_ok!(Membership::force_add_authorized_account(
 				RawOrigin::Root.into(),
 				authorised_account,
 			));
+
+			// the expiry queue of a block is bounded, the third genesis member expires a block
+			// later
+			assert_eq!(kyc_expiry(10), Some(100));
+			assert_eq!(kyc_expiry(20), Some(100));
+			assert_eq!(kyc_expiry(30), Some(101));
+
+			System::set_block_number(5);
+			assert_ok!(Membership::add_member(
+				RuntimeOrigin::signed(authorised_account),
+				15,
+				KycLevel::Basic
+			));
+			assert_eq!(kyc_expiry(15), Some(105));
+			assert_eq!(Membership::expiry_queue(105).into_inner(), vec![15]);
+
+			// the member is notified before expiry
+			System::set_block_number(95);
+			Membership::on_initialize(95);
+			System::assert_has_event(
+				Event::<Test>::KycExpiring { who: 15, expires_at: 105 }.into(),
+			);
+			assert!(Membership::contains(&15));
+
+			// only authorized accounts can renew the kyc of a member
 			assert_noop!(
-				Membership::add_member(RuntimeOrigin::signed(5), 15),
-				crate::Error::<Test, _>::NotAuthorised
+				Membership::renew_kyc(RuntimeOrigin::signed(5), 10),
+				Error::<Test, _>::NotAuthorised
 			);
 			assert_noop!(
-				Membership::add_member(RuntimeOrigin::signed(authorised_account), 10),
-				Error::<Test, _>::AlreadyMember
+				Membership::renew_kyc(RuntimeOrigin::signed(authorised_account), 25),
+				Error::<Test, _>::NotMember
 			);
-			assert_ok!(Membership::add_member(RuntimeOrigin::signed(authorised_account), 15));
-			assert_eq!(Membership::members(), vec![10, 15, 20, 30]);
-			assert_eq!(MEMBERS.with(|m| m.borrow().clone()), Membership::members().to_vec());
+
+			// renewing moves the member to the new expiry block
+			assert_ok!(Membership::renew_kyc(RuntimeOrigin::signed(authorised_account), 10));
+			System::assert_last_event(
+				Event::<Test>::KycRenewed { who: 10, expires_at: 195 }.into(),
+			);
+			assert_eq!(kyc_expiry(10), Some(195));
+			assert_eq!(Membership::expiry_queue(100).into_inner(), vec![20]);
+			assert_eq!(Membership::expiry_queue(195).into_inner(), vec![10]);
+
+			// the expired members are removed with their derived accounts
+			assert_ok!(Membership::link_derived_account(
+				RuntimeOrigin::signed(20),
+				21,
+				DerivedAccountType::Proxy
+			));
+			assert_ok!(Membership::accept_derived_link(RuntimeOrigin::signed(21), 20));
+			System::set_block_number(100);
+			Membership::on_initialize(100);
+			System::assert_has_event(Event::<Test>::KycExpired { who: 20 }.into());
+			assert_eq!(KycRecords::<Test>::get(20), None);
+			assert!(!Membership::contains(&21));
+			assert!(!ExpiryQueue::<Test>::contains_key(100));
+			assert_eq!(Membership::member_count(), 3);
+			assert_eq!(Membership::members(), vec![10, 15, 30]);
+			assert_eq!(Members::get(), vec![10, 15, 30]);
+
+			System::set_block_number(105);
+			Membership::on_initialize(105);
+			System::assert_has_event(Event::<Test>::KycExpired { who: 15 }.into());
+			assert!(!Membership::contains(&15));
+			assert_eq!(Membership::kyc_level(&15), None);
+			assert!(!ExpiryQueue::<Test>::contains_key(105));
+			assert_eq!(Membership::member_count(), 2);
+
+			// an expired member has to be verified again
+			assert_noop!(
+				Membership::renew_kyc(RuntimeOrigin::signed(authorised_account), 15),
+				Error::<Test, _>::NotMember
+			);
+			assert_ok!(Membership::add_member(
+				RuntimeOrigin::signed(authorised_account),
+				15,
+				KycLevel::Basic
+			));
+			assert_eq!(kyc_expiry(15), Some(205));
+
+			// removing the member clears the expiry, the empty queue is removed
+			assert_ok!(Membership::remove_member(RuntimeOrigin::signed(authorised_account), 15));
+			assert_eq!(kyc_expiry(15), None);
+			assert!(!ExpiryQueue::<Test>::contains_key(205));
 		});
 	}
 
 	#[test]
-	fn add_member_airdrop_works() {
+	fn kyc_attributes_and_blocked_jurisdictions_work() {
 		new_test_ext().execute_with(|| {
 			let authorised_account = 1;
 			assert_ok!(Membership::force_add_authorized_account(
@@ -774,22 +2612,590 @@ mod tests {
 				authorised_account,
 			));
 
-			// set the airdrop amount
-			let airdrop_amount = 10;
-			assert_ok!(Membership::force_set_kyc_airdrop(
+			let attributes = KycAttributes {
+				country_code: *b"DE",
+				entity_type: EntityType::Company,
+				provider_reference: H256::repeat_byte(1),
+			};
+
+			// only authorized accounts can set the attributes of a member
+			assert_noop!(
+				Membership::set_kyc_attributes(RuntimeOrigin::signed(5), 10, attributes.clone()),
+				Error::<Test, _>::NotAuthorised
+			);
+			assert_noop!(
+				Membership::set_kyc_attributes(
+					RuntimeOrigin::signed(authorised_account),
+					25,
+					attributes.clone()
+				),
+				Error::<Test, _>::NotMember
+			);
+			assert_noop!(
+				Membership::set_kyc_attributes(
+					RuntimeOrigin::signed(authorised_account),
+					10,
+					KycAttributes { country_code: *b"de", ..attributes.clone() }
+				),
+				Error::<Test, _>::InvalidCountryCode
+			);
+
+			assert_eq!(Membership::country_code(&10), None);
+			assert_ok!(Membership::set_kyc_attributes(
+				RuntimeOrigin::signed(authorised_account),
+				10,
+				attributes.clone()
+			));
+			System::assert_last_event(
+				Event::<Test>::KycAttributesUpdated { who: 10, attributes: attributes.clone() }
+					.into(),
+			);
+			assert_eq!(Membership::kyc_records(10).unwrap().attributes, Some(attributes));
+			assert_eq!(Membership::country_code(&10), Some(*b"DE"));
+			assert!(!Membership::is_account_blocked(&10));
+
+			// only governance can block jurisdictions
+			assert_noop!(
+				Membership::force_block_jurisdiction(
+					RuntimeOrigin::signed(authorised_account),
+					*b"DE"
+				),
+				BadOrigin
+			);
+			assert_noop!(
+				Membership::force_block_jurisdiction(RawOrigin::Root.into(), *b"D1"),
+				Error::<Test, _>::InvalidCountryCode
+			);
+			assert_ok!(Membership::force_block_jurisdiction(RawOrigin::Root.into(), *b"DE"));
+			System::assert_last_event(
+				Event::<Test>::JurisdictionBlocked { country_code: *b"DE" }.into(),
+			);
+			assert_noop!(
+				Membership::force_block_jurisdiction(RawOrigin::Root.into(), *b"DE"),
+				Error::<Test, _>::JurisdictionAlreadyBlocked
+			);
+			assert!(Membership::is_account_blocked(&10));
+			// members without attributes are not blocked
+			assert!(!Membership::is_account_blocked(&20));
+
+			assert_ok!(Membership::force_unblock_jurisdiction(RawOrigin::Root.into(), *b"DE"));
+			System::assert_last_event(
+				Event::<Test>::JurisdictionUnblocked { country_code: *b"DE" }.into(),
+			);
+			assert_noop!(
+				Membership::force_unblock_jurisdiction(RawOrigin::Root.into(), *b"DE"),
+				Error::<Test, _>::JurisdictionNotBlocked
+			);
+			assert!(!Membership::is_account_blocked(&10));
+		});
+	}
+
+	#[test]
+	fn providers_only_change_their_attestations() {
+		new_test_ext().execute_with(|| {
+			let provider_one = 1;
+			let provider_two = 2;
+			for provider in [provider_one, provider_two] {
+				assert_ok!(Membership::force_add_authorized_account(
+					RawOrigin::Root.into(),
+					provider,
+				));
+			}
+			assert_ok!(Membership::add_member(
+				RuntimeOrigin::signed(provider_one),
+				15,
+				KycLevel::Basic
+			));
+
+			// another provider cannot remove or swap the member
+			assert_noop!(
+				Membership::remove_member(RuntimeOrigin::signed(provider_two), 15),
+				Error::<Test, _>::NotAttestingProvider
+			);
+			assert_noop!(
+				Membership::swap_member(RuntimeOrigin::signed(provider_two), 15, 25),
+				Error::<Test, _>::NotAttestingProvider
+			);
+
+			// changing the level moves the attestation to the provider
+			assert_ok!(Membership::set_kyc_level(
+				RuntimeOrigin::signed(provider_two),
+				15,
+				KycLevel::Enhanced
+			));
+			assert_eq!(Membership::kyc_records(15).unwrap().provider_id, Some(1));
+			assert!(ProviderAttestations::<Test>::contains_key(1, 15));
+			assert!(!ProviderAttestations::<Test>::contains_key(0, 15));
+			assert_noop!(
+				Membership::remove_member(RuntimeOrigin::signed(provider_one), 15),
+				Error::<Test, _>::NotAttestingProvider
+			);
+
+			// setting the attributes moves the attestation back
+			let attributes = KycAttributes {
+				country_code: *b"DE",
+				entity_type: EntityType::Individual,
+				provider_reference: H256::repeat_byte(1),
+			};
+			assert_ok!(Membership::set_kyc_attributes(
+				RuntimeOrigin::signed(provider_one),
+				15,
+				attributes
+			));
+			assert_eq!(Membership::kyc_records(15).unwrap().provider_id, Some(0));
+
+			// revoking the attestations of the provider revokes the changed member
+			assert_ok!(Membership::force_revoke_provider_attestations(
 				RawOrigin::Root.into(),
-				Some(airdrop_amount),
+				0,
+				10
 			));
+			assert!(!Membership::contains(&15));
 
-			// set some balance to the pallet account
-			let kyc_pallet_account: u64 = PalletId(*b"bitg/kyc").into_account_truncating();
-			Balances::make_free_balance_be(&kyc_pallet_account, 100);
+			// members without a provider can be removed by any provider
+			assert_ok!(Membership::remove_member(RuntimeOrigin::signed(provider_two), 10));
+		});
+	}
 
-			let balance_before_kyc = Balances::free_balance(&15);
-			assert_ok!(Membership::add_member(RuntimeOrigin::signed(authorised_account), 15));
+	#[test]
+	fn kyc_providers_and_attestation_revocation_work() {
+		new_test_ext().execute_with(|| {
+			let provider_one = 1;
+			let provider_two = 2;
+			assert_ok!(Membership::force_add_authorized_account(
+				RawOrigin::Root.into(),
+				provider_one,
+			));
+			System::assert_last_event(
+				Event::<Test>::AuthorizedAccountAdded { account_id: provider_one, provider_id: 0 }
+					.into(),
+			);
+			assert_ok!(Membership::force_add_authorized_account(
+				RawOrigin::Root.into(),
+				provider_two,
+			));
+			assert_eq!(Membership::provider_id(provider_one), Some(0));
+			assert_eq!(Membership::provider_id(provider_two), Some(1));
+			assert_eq!(Membership::kyc_providers(1), Some(provider_two));
+
+			// every membership records its attesting provider
+			assert_ok!(Membership::add_member(
+				RuntimeOrigin::signed(provider_one),
+				15,
+				KycLevel::Basic
+			));
+			assert_ok!(Membership::add_member(
+				RuntimeOrigin::signed(provider_one),
+				25,
+				KycLevel::Basic
+			));
+			assert_ok!(Membership::add_member(
+				RuntimeOrigin::signed(provider_two),
+				35,
+				KycLevel::Basic
+			));
+			assert_eq!(Membership::kyc_records(15).unwrap().provider_id, Some(0));
+			assert_eq!(Membership::kyc_records(35).unwrap().provider_id, Some(1));
+			// genesis members have no provider
+			assert_eq!(Membership::kyc_records(10).unwrap().provider_id, None);
+
+			// renewing moves the attestation to the renewing provider
+			assert_ok!(Membership::renew_kyc(RuntimeOrigin::signed(provider_two), 25));
+			assert_eq!(Membership::kyc_records(25).unwrap().provider_id, Some(1));
+
+			// a removed provider can no longer attest members but its attestations are kept
+			assert_ok!(Membership::force_remove_authorized_account(
+				RawOrigin::Root.into(),
+				provider_one,
+			));
+			assert_eq!(Membership::provider_id(provider_one), None);
+			assert_noop!(
+				Membership::add_member(RuntimeOrigin::signed(provider_one), 45, KycLevel::Basic),
+				Error::<Test, _>::NotAuthorised
+			);
+			assert!(Membership::contains(&15));
+
+			// only governance can revoke the attestations of a provider
+			assert_noop!(
+				Membership::force_revoke_provider_attestations(
+					RuntimeOrigin::signed(provider_two),
+					0,
+					10
+				),
+				BadOrigin
+			);
+			assert_noop!(
+				Membership::force_revoke_provider_attestations(RawOrigin::Root.into(), 5, 10),
+				Error::<Test, _>::ProviderNotFound
+			);
+
+			assert_eq!(Membership::member_count(), 6);
+			assert_ok!(Membership::force_revoke_provider_attestations(
+				RawOrigin::Root.into(),
+				0,
+				10
+			));
+			System::assert_last_event(
+				Event::<Test>::ProviderAttestationsRevoked { provider_id: 0, count: 1 }.into(),
+			);
+			assert!(!Membership::contains(&15));
+			assert_eq!(kyc_expiry(15), None);
+			assert_eq!(Membership::member_count(), 5);
+			assert_eq!(MEMBERS.with(|m| m.borrow().clone()), Membership::members());
+
+			// the attestations of a provider can be revoked in several calls
+			assert_ok!(Membership::force_revoke_provider_attestations(
+				RawOrigin::Root.into(),
+				1,
+				1
+			));
+			assert_eq!(Membership::member_count(), 4);
+			assert_ok!(Membership::force_revoke_provider_attestations(
+				RawOrigin::Root.into(),
+				1,
+				1
+			));
+			assert_eq!(Membership::member_count(), 3);
+			assert!(!Membership::contains(&25));
+			assert!(!Membership::contains(&35));
+			assert_eq!(Membership::members(), vec![10, 20, 30]);
+		});
+	}
+
+	#[test]
+	fn derived_link_cannot_be_accepted_after_parent_expiry() {
+		new_test_ext().execute_with(|| {
+			let provider = 1;
+			let parent = 10;
+			let proxy = 11;
+			assert_ok!(Membership::force_add_authorized_account(RawOrigin::Root.into(), provider));
+			assert_ok!(Membership::link_derived_account(
+				RuntimeOrigin::signed(parent),
+				proxy,
+				DerivedAccountType::Proxy
+			));
+
+			// the kyc of the parent expires before the link is accepted
+			System::set_block_number(100);
+			assert_noop!(
+				Membership::accept_derived_link(RuntimeOrigin::signed(proxy), parent),
+				Error::<Test, _>::NotMember
+			);
+
+			// the link can be accepted once the parent is renewed
+			assert_ok!(Membership::renew_kyc(RuntimeOrigin::signed(provider), parent));
+			assert_ok!(Membership::accept_derived_link(RuntimeOrigin::signed(proxy), parent));
+			assert!(Membership::contains(&proxy));
+		});
+	}
+
+	#[test]
+	fn derived_accounts_inherit_kyc() {
+		new_test_ext().execute_with(|| {
+			let parent = 10;
+			let proxy = 11;
+			let multisig = 12;
+			assert!(!Membership::contains(&proxy));
+
+			// only verified members can link accounts
+			assert_noop!(
+				Membership::link_derived_account(
+					RuntimeOrigin::signed(3),
+					proxy,
+					DerivedAccountType::Proxy
+				),
+				Error::<Test, _>::NotMember
+			);
+			assert_noop!(
+				Membership::link_derived_account(
+					RuntimeOrigin::signed(parent),
+					parent,
+					DerivedAccountType::Proxy
+				),
+				Error::<Test, _>::CannotLinkSelf
+			);
+			assert_noop!(
+				Membership::link_derived_account(
+					RuntimeOrigin::signed(parent),
+					20,
+					DerivedAccountType::Proxy
+				),
+				Error::<Test, _>::AlreadyMember
+			);
+
+			// the link is only effective once accepted by the derived account
+			assert_ok!(Membership::link_derived_account(
+				RuntimeOrigin::signed(parent),
+				proxy,
+				DerivedAccountType::Proxy
+			));
+			System::assert_last_event(
+				Event::<Test>::DerivedAccountLinkProposed {
+					parent,
+					derived: proxy,
+					account_type: DerivedAccountType::Proxy,
+				}
+				.into(),
+			);
+			assert!(!Membership::contains(&proxy));
+			// another member cannot replace the proposal
+			assert_noop!(
+				Membership::link_derived_account(
+					RuntimeOrigin::signed(20),
+					proxy,
+					DerivedAccountType::Proxy
+				),
+				Error::<Test, _>::LinkAlreadyProposed
+			);
+			assert_noop!(
+				Membership::accept_derived_link(RuntimeOrigin::signed(proxy), 20),
+				Error::<Test, _>::LinkNotFound
+			);
+			assert_ok!(Membership::accept_derived_link(RuntimeOrigin::signed(proxy), parent));
+			System::assert_last_event(
+				Event::<Test>::DerivedAccountLinked {
+					parent,
+					derived: proxy,
+					account_type: DerivedAccountType::Proxy,
+				}
+				.into(),
+			);
+			assert!(Membership::contains(&proxy));
+			assert_eq!(Membership::kyc_level(&proxy), Some(KycLevel::Basic));
+			assert_eq!(Membership::linked_accounts(parent).to_vec(), vec![proxy]);
+			// derived accounts are not members
+			assert_eq!(Membership::members(), vec![10, 20, 30]);
+			assert_noop!(
+				Membership::link_derived_account(
+					RuntimeOrigin::signed(20),
+					proxy,
+					DerivedAccountType::Proxy
+				),
+				Error::<Test, _>::AlreadyLinked
+			);
+
+			// the number of derived accounts is bounded
+			assert_ok!(Membership::link_derived_account(
+				RuntimeOrigin::signed(parent),
+				multisig,
+				DerivedAccountType::Multisig
+			));
+			assert_ok!(Membership::accept_derived_link(RuntimeOrigin::signed(multisig), parent));
+			assert_ok!(Membership::link_derived_account(
+				RuntimeOrigin::signed(parent),
+				13,
+				DerivedAccountType::SubAccount
+			));
+			assert_noop!(
+				Membership::accept_derived_link(RuntimeOrigin::signed(13), parent),
+				Error::<Test, _>::TooManyDerivedAccounts
+			);
+			assert_noop!(
+				Membership::unlink_derived_account(RuntimeOrigin::signed(20), 13),
+				Error::<Test, _>::NotAuthorised
+			);
+			assert_ok!(Membership::unlink_derived_account(RuntimeOrigin::signed(13), 13));
+			assert_eq!(Membership::pending_derived_links(13), None);
+
+			// the derived account or the parent can unlink
+			assert_noop!(
+				Membership::unlink_derived_account(RuntimeOrigin::signed(20), multisig),
+				Error::<Test, _>::NotAuthorised
+			);
+			assert_ok!(Membership::unlink_derived_account(
+				RuntimeOrigin::signed(multisig),
+				multisig
+			));
+			System::assert_last_event(
+				Event::<Test>::DerivedAccountUnlinked { parent, derived: multisig }.into(),
+			);
+			assert!(!Membership::contains(&multisig));
+			assert_noop!(
+				Membership::unlink_derived_account(RuntimeOrigin::signed(parent), multisig),
+				Error::<Test, _>::LinkNotFound
+			);
+
+			// the links follow a key change of the parent
+			assert_ok!(Membership::change_key(RuntimeOrigin::signed(parent), 40));
+			assert_eq!(Membership::derived_accounts(proxy).unwrap().parent, 40);
+			assert_eq!(Membership::linked_accounts(40).to_vec(), vec![proxy]);
+			assert!(Membership::linked_accounts(parent).is_empty());
+			assert!(Membership::contains(&proxy));
+
+			// removing the parent revokes the derived accounts
+			assert_ok!(Membership::force_add_authorized_account(RawOrigin::Root.into(), 1));
+			assert_ok!(Membership::remove_member(RuntimeOrigin::signed(1), 40));
+			System::assert_has_event(
+				Event::<Test>::DerivedAccountUnlinked { parent: 40, derived: proxy }.into(),
+			);
+			assert!(!Membership::contains(&proxy));
+			assert_eq!(Membership::derived_accounts(proxy), None);
+			assert!(Membership::linked_accounts(40).is_empty());
+		});
+	}
+
+	#[test]
+	fn identity_judgements_grant_and_revoke_kyc() {
+		new_test_ext().execute_with(|| {
+			let who = 15;
+
+			// an account without a conclusive judgement is unchanged
+			assert_noop!(
+				Membership::sync_identity_judgement(RuntimeOrigin::signed(1), who),
+				Error::<Test, _>::IdentityUnchanged
+			);
+
+			// a good judgement grants the configured level without expiry
+			IdentityStatuses::set(vec![(who, IdentityStatus::Verified)]);
+			assert_ok!(Membership::sync_identity_judgement(RuntimeOrigin::signed(1), who));
+			System::assert_last_event(
+				Event::<Test>::IdentityKycGranted { who, level: KycLevel::Enhanced }.into(),
+			);
+			assert!(Membership::contains(&who));
+			assert_eq!(Membership::kyc_level(&who), Some(KycLevel::Enhanced));
+			assert_eq!(kyc_expiry(who), None);
+			assert_eq!(Membership::identity_members(who), Some(()));
 			assert_eq!(Membership::members(), vec![10, 15, 20, 30]);
 			assert_eq!(MEMBERS.with(|m| m.borrow().clone()), Membership::members().to_vec());
-			assert_eq!(Balances::free_balance(&15), balance_before_kyc + airdrop_amount);
+			assert_noop!(
+				Membership::sync_identity_judgement(RuntimeOrigin::signed(1), who),
+				Error::<Test, _>::AlreadyMember
+			);
+
+			// providers cannot remove or swap a kyc granted from the identity
+			assert_ok!(Membership::force_add_authorized_account(RawOrigin::Root.into(), 1));
+			assert_noop!(
+				Membership::remove_member(RuntimeOrigin::signed(1), who),
+				Error::<Test, _>::IdentityGrantedKyc
+			);
+			assert_noop!(
+				Membership::swap_member(RuntimeOrigin::signed(1), who, 16),
+				Error::<Test, _>::IdentityGrantedKyc
+			);
+			assert_noop!(
+				Membership::set_kyc_level(RuntimeOrigin::signed(1), who, KycLevel::Basic),
+				Error::<Test, _>::IdentityGrantedKyc
+			);
+
+			// losing the judgement revokes the kyc at once, the sync removes the record
+			IdentityStatuses::set(vec![(who, IdentityStatus::Unverified)]);
+			assert!(!Membership::contains(&who));
+			assert_eq!(Membership::kyc_level(&who), None);
+			assert_ok!(Membership::sync_identity_judgement(RuntimeOrigin::signed(1), who));
+			System::assert_last_event(Event::<Test>::IdentityKycRevoked { who }.into());
+			assert_eq!(Membership::identity_members(who), None);
+			assert_eq!(Membership::member_count(), 3);
+
+			// an erroneous judgement or a cleared identity revokes the kyc
+			IdentityStatuses::set(vec![(who, IdentityStatus::Verified)]);
+			assert_ok!(Membership::sync_identity_judgement(RuntimeOrigin::signed(1), who));
+			assert!(Membership::contains(&who));
+			IdentityStatuses::set(vec![(who, IdentityStatus::Revoked)]);
+			assert!(!Membership::contains(&who));
+			assert_ok!(Membership::sync_identity_judgement(RuntimeOrigin::signed(1), who));
+			System::assert_last_event(Event::<Test>::IdentityKycRevoked { who }.into());
+			assert!(!Membership::contains(&who));
+			assert_eq!(Membership::identity_members(who), None);
+			assert_eq!(Membership::member_count(), 3);
+
+			// memberships attested by a provider are not revoked by the identity
+			for status in [IdentityStatus::Revoked, IdentityStatus::Unverified] {
+				IdentityStatuses::set(vec![(10, status)]);
+				assert_noop!(
+					Membership::sync_identity_judgement(RuntimeOrigin::signed(1), 10),
+					Error::<Test, _>::IdentityUnchanged
+				);
+				assert!(Membership::contains(&10));
+			}
+		});
+	}
+
+	#[test]
+	fn migrations_move_members_to_records() {
+		use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+		parameter_types! {
+			pub const MigrationKycLevel: KycLevel = KycLevel::Institutional;
+		}
+
+		new_test_ext().execute_with(|| {
+			// reset to the v4 layout
+			let _ = KycRecords::<Test>::clear(u32::MAX, None);
+			let _ = ExpiryQueue::<Test>::clear(u32::MAX, None);
+			MemberCount::<Test>::kill();
+			let members: BoundedVec<u64, ConstU32<10>> = bounded_vec![10, 20, 30];
+			migration::v5::Members::<Test, ()>::put(members);
+			StorageVersion::new(4).put::<Membership>();
+
+			migration::v5::MigrateToV5::<Test, MigrationKycLevel>::on_runtime_upgrade();
+
+			assert_eq!(Membership::on_chain_storage_version(), 5);
+			assert!(!migration::v5::Members::<Test, ()>::exists());
+			assert_eq!(Membership::member_count(), 3);
+			// the members expire after a validity period, spread over the expiry queues
+			let expiries = [(10, 101), (20, 101), (30, 102)];
+			for (who, expires_at) in expiries {
+				assert_eq!(
+					migration::v5::KycRecords::<Test, ()>::get(who),
+					Some(migration::KycRecordV5 {
+						level: KycLevel::Institutional,
+						expires_at: Some(expires_at)
+					})
+				);
+			}
+			assert_eq!(Membership::expiry_queue(101).into_inner(), vec![10, 20]);
+			assert_eq!(Membership::expiry_queue(102).into_inner(), vec![30]);
+
+			// running the migration again does nothing
+			migration::v5::MigrateToV5::<Test, MigrationKycLevel>::on_runtime_upgrade();
+			assert_eq!(Membership::member_count(), 3);
+
+			// the v6 migration adds empty attributes to the records
+			migration::v6::MigrateToV6::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Membership::on_chain_storage_version(), 6);
+			for (who, expires_at) in expiries {
+				assert_eq!(
+					migration::v6::KycRecords::<Test, ()>::get(who),
+					Some(migration::KycRecordV6 {
+						level: KycLevel::Institutional,
+						expires_at: Some(expires_at),
+						attributes: None
+					})
+				);
+			}
+
+			// the v7 migration registers the authorized accounts as providers
+			let authorized_accounts: BoundedVec<u64, ConstU32<10>> = bounded_vec![1, 2];
+			AuthorizedAccounts::<Test>::put(authorized_accounts);
+			migration::v7::MigrateToV7::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Membership::on_chain_storage_version(), 7);
+			assert_eq!(Membership::members(), vec![10, 20, 30]);
+			for (who, expires_at) in expiries {
+				assert_eq!(
+					Membership::kyc_records(who),
+					Some(KycRecord {
+						level: KycLevel::Institutional,
+						expires_at: Some(expires_at),
+						attributes: None,
+						provider_id: None
+					})
+				);
+				assert!(Membership::contains(&who));
+			}
+			assert_eq!(Membership::provider_id(1), Some(0));
+			assert_eq!(Membership::provider_id(2), Some(1));
+			assert_eq!(Membership::kyc_providers(1), Some(2));
+			assert_eq!(Membership::next_provider_id(), 2);
+
+			// the v8 migration marks the airdrop of the existing members as claimed
+			migration::v8::MigrateToV8::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Membership::on_chain_storage_version(), 8);
+			for who in [10, 20, 30] {
+				assert!(Membership::airdrop_claimed(who).is_some());
+			}
 		});
 	}
 
@@ -0,0 +1,289 @@
+use super::*;
+
+pub mod v5 {
+	use super::*;
+
+	use frame_support::{
+		pallet_prelude::{StorageVersion, ValueQuery, Weight},
+		storage_alias,
+		traits::{GetStorageVersion, OnRuntimeUpgrade},
+		Blake2_128Concat,
+	};
+
+	/// The membership before v5, stored as an ordered Vec
+	#[storage_alias]
+	pub type Members<T: Config<I>, I: 'static> = StorageValue<
+		Pallet<T, I>,
+		BoundedVec<<T as frame_system::Config>::AccountId, <T as Config<I>>::MaxMembers>,
+		ValueQuery,
+	>;
+
+	/// The KYC records with the v5 layout
+	#[storage_alias]
+	pub type KycRecords<T: Config<I>, I: 'static> = StorageMap<
+		Pallet<T, I>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		KycRecordV5Of<T>,
+	>;
+
+	/// Migrate the members to KYC records verified at `Level`, this should be the highest level
+	/// required by the consumers of the pallet so existing members keep their permissions
+	pub struct MigrateToV5<T, Level, I = ()>(sp_std::marker::PhantomData<(T, Level, I)>);
+	impl<T: Config<I>, Level: Get<KycLevel>, I: 'static> OnRuntimeUpgrade for MigrateToV5<T, Level, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() >= 5 {
+				log::info!("MIGRATION : kyc migration V5 already executed, skipping!");
+				return T::DbWeight::get().reads(1)
+			}
+
+			log::info!("MIGRATION : About to execute kyc migration V5!");
+
+			// existing members are verified at the given level for a full validity period, the
+			// expiries fill the queues of consecutive blocks from the end of that period
+			let level = Level::get();
+			let members = Members::<T, I>::take();
+			let count = members.len() as u32;
+			let mut next_expiry = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::KycValidityPeriod::get());
+			for who in members.into_iter() {
+				let expires_at = match Pallet::<T, I>::schedule_kyc_expiry(&who, next_expiry) {
+					Ok(expires_at) => {
+						next_expiry = expires_at;
+						Some(expires_at)
+					},
+					Err(_) => {
+						log::error!(
+							"MIGRATION : Cannot schedule the KYC expiry of {:?}, it does not expire!",
+							who
+						);
+						None
+					},
+				};
+				KycRecords::<T, I>::insert(who, KycRecordV5 { level, expires_at });
+			}
+			MemberCount::<T, I>::put(count);
+
+			StorageVersion::new(5).put::<Pallet<T, I>>();
+
+			log::info!("MIGRATION : KYC migration V5 complete, {} members!", count);
+
+			let count = count as u64;
+			T::DbWeight::get()
+				.reads_writes(count.saturating_add(2), count.saturating_mul(2).saturating_add(3))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			// new version must be set.
+			assert!(Pallet::<T, I>::on_chain_storage_version() >= 5);
+			assert_eq!(KycRecords::<T, I>::iter_keys().count() as u32, MemberCount::<T, I>::get());
+			Ok(())
+		}
+	}
+}
+
+pub mod v6 {
+	use super::*;
+
+	use frame_support::{
+		pallet_prelude::{StorageVersion, Weight},
+		storage_alias,
+		traits::{GetStorageVersion, OnRuntimeUpgrade},
+		Blake2_128Concat,
+	};
+
+	/// The KYC records with the v6 layout
+	#[storage_alias]
+	pub type KycRecords<T: Config<I>, I: 'static> = StorageMap<
+		Pallet<T, I>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		KycRecordV6Of<T>,
+	>;
+
+	pub struct MigrateToV6<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV6<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() >= 6 {
+				log::info!("MIGRATION : kyc migration V6 already executed, skipping!");
+				return T::DbWeight::get().reads(1)
+			}
+
+			log::info!("MIGRATION : About to execute kyc migration V6!");
+
+			let mut count: u64 = 0;
+
+			// existing members have no attributes until set by an authorized account
+			KycRecords::<T, I>::translate::<KycRecordV5Of<T>, _>(
+				|_key, old| -> Option<KycRecordV6Of<T>> {
+					count += 1;
+					Some(KycRecordV6 {
+						level: old.level,
+						expires_at: old.expires_at,
+						attributes: None,
+					})
+				},
+			);
+
+			StorageVersion::new(6).put::<Pallet<T, I>>();
+
+			log::info!("MIGRATION : KYC migration V6 complete, {} members!", count);
+
+			T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			// new version must be set.
+			assert!(Pallet::<T, I>::on_chain_storage_version() >= 6);
+			assert_eq!(KycRecords::<T, I>::iter_keys().count() as u32, MemberCount::<T, I>::get());
+			Ok(())
+		}
+	}
+}
+
+pub mod v7 {
+	use super::*;
+
+	use frame_support::{
+		pallet_prelude::{StorageVersion, Weight},
+		traits::{GetStorageVersion, OnRuntimeUpgrade},
+	};
+
+	pub struct MigrateToV7<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV7<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() >= 7 {
+				log::info!("MIGRATION : kyc migration V7 already executed, skipping!");
+				return T::DbWeight::get().reads(1)
+			}
+
+			log::info!("MIGRATION : About to execute kyc migration V7!");
+
+			let mut count: u64 = 0;
+
+			// the provider of existing members is unknown
+			KycRecords::<T, I>::translate::<KycRecordV6Of<T>, _>(
+				|_key, old| -> Option<KycRecordOf<T>> {
+					count += 1;
+					Some(KycRecord {
+						level: old.level,
+						expires_at: old.expires_at,
+						attributes: old.attributes,
+						provider_id: None,
+					})
+				},
+			);
+
+			// register the existing authorized accounts as providers
+			let authorized_accounts = AuthorizedAccounts::<T, I>::get();
+			let mut provider_id = NextProviderId::<T, I>::get();
+			for account_id in authorized_accounts.iter() {
+				KycProviders::<T, I>::insert(provider_id, account_id);
+				ProviderIds::<T, I>::insert(account_id, provider_id);
+				provider_id = provider_id.saturating_add(1);
+			}
+			NextProviderId::<T, I>::put(provider_id);
+
+			StorageVersion::new(7).put::<Pallet<T, I>>();
+
+			log::info!(
+				"MIGRATION : KYC migration V7 complete, {} members, {} providers!",
+				count,
+				authorized_accounts.len()
+			);
+
+			let providers = authorized_accounts.len() as u64;
+			T::DbWeight::get().reads_writes(
+				count.saturating_add(3),
+				count.saturating_add(providers.saturating_mul(2)).saturating_add(2),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			// new version must be set.
+			assert!(Pallet::<T, I>::on_chain_storage_version() >= 7);
+			for account_id in AuthorizedAccounts::<T, I>::get().iter() {
+				assert!(ProviderIds::<T, I>::contains_key(account_id));
+			}
+			Ok(())
+		}
+	}
+}
+
+pub mod v8 {
+	use super::*;
+
+	use frame_support::{
+		pallet_prelude::{StorageVersion, Weight},
+		traits::{GetStorageVersion, OnRuntimeUpgrade},
+	};
+
+	pub struct MigrateToV8<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV8<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() >= 8 {
+				log::info!("MIGRATION : kyc migration V8 already executed, skipping!");
+				return T::DbWeight::get().reads(1)
+			}
+
+			log::info!("MIGRATION : About to execute kyc migration V8!");
+
+			let mut count: u64 = 0;
+
+			// existing members may have received the airdrop when they were added. The records
+			// were migrated from the v4 membership in v5, which was bounded by `MaxMembers`, so
+			// at most that many records are read.
+			let max_members = T::MaxMembers::get();
+			if MemberCount::<T, I>::get() > max_members {
+				log::error!(
+					"MIGRATION : More than {} members, the airdrop is only marked for the first!",
+					max_members
+				);
+			}
+			let now = frame_system::Pallet::<T>::block_number();
+			for who in KycRecords::<T, I>::iter_keys().take(max_members as usize) {
+				count += 1;
+				AirdropClaimed::<T, I>::insert(who, now);
+			}
+
+			StorageVersion::new(8).put::<Pallet<T, I>>();
+
+			log::info!("MIGRATION : KYC migration V8 complete, {} members!", count);
+
+			T::DbWeight::get().reads_writes(count.saturating_add(3), count.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			// new version must be set.
+			assert!(Pallet::<T, I>::on_chain_storage_version() >= 8);
+			for who in KycRecords::<T, I>::iter_keys() {
+				assert!(AirdropClaimed::<T, I>::contains_key(who));
+			}
+			Ok(())
+		}
+	}
+}
+
+/// The KYC details of a member before v7, without the attesting provider
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct KycRecordV6<BlockNumber, Hash> {
+	pub level: KycLevel,
+	pub expires_at: Option<BlockNumber>,
+	pub attributes: Option<KycAttributes<Hash>>,
+}
+
+pub type KycRecordV6Of<T> =
+	KycRecordV6<<T as frame_system::Config>::BlockNumber, <T as frame_system::Config>::Hash>;
+
+/// The KYC details of a member before v6, without attributes
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct KycRecordV5<BlockNumber> {
+	pub level: KycLevel,
+	pub expires_at: Option<BlockNumber>,
+}
+
+pub type KycRecordV5Of<T> = KycRecordV5<<T as frame_system::Config>::BlockNumber>;
@@ -7,7 +7,8 @@
 
 use std::sync::Arc;
 
-use bitgreen_runtime::{opaque::Block, AccountId, Balance, Index as Nonce};
+use bitgreen_runtime::{opaque::Block, AccountId, Balance, Index as Nonce, Runtime};
+use pallet_carbon_credits_pool::PoolConfigOf;
 use sc_client_api::AuxStore;
 pub use sc_rpc::{DenyUnsafe, SubscriptionTaskExecutor};
 use sc_transaction_pool_api::TransactionPool;
@@ -42,9 +43,18 @@ where
 		+ 'static,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+	C::Api: pallet_carbon_credits_pool_rpc::CarbonCreditsPoolRuntimeApi<
+		Block,
+		AccountId,
+		u32,
+		u32,
+		Balance,
+		PoolConfigOf<Runtime>,
+	>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 {
+	use pallet_carbon_credits_pool_rpc::{CarbonCreditsPool, CarbonCreditsPoolApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -52,6 +62,7 @@ where
 	let FullDeps { client, pool, deny_unsafe } = deps;
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(CarbonCreditsPool::new(client).into_rpc())?;
 	Ok(module)
 }
@@ -75,6 +75,29 @@ pub struct Royalty<AccountId> {
 	pub percent_of_fees: Percent,
 }
 
+/// The verification status of a batch of credits
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, TypeInfo, Default, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BatchStatus {
+	/// The credits have been verified by the registry and are minted as spot credits
+	#[default]
+	Verified,
+	/// The credits are expected to be issued in the future, they are minted as forward credits
+	/// that can be converted 1:1 to spot credits once the batch is verified
+	Projected,
+}
+
+/// The type of climate impact represented by the credits of a project
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, TypeInfo, Default, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CreditType {
+	/// The credits represent emissions reduced or avoided by the project
+	#[default]
+	Avoidance,
+	/// The credits represent carbon removed from the atmosphere by the project
+	Removal,
+}
+
 /// Credits in a project are represented in terms of batches, these batches are usually seperated in
 /// terms of 'vintages'. The vintage refers to the `age` of the credit. So a batch could hold
 /// 500credits with 2020 vintage. We use `issuance_year` to represent the vintage of the credit,
@@ -90,6 +113,9 @@ pub struct Royalty<AccountId> {
 ///    - `minted` is always less than or equal to `total_supply`
 ///     - `retired` is always less than or equal to `minted`
 ///
+/// A batch with `Projected` status represents future credits, these are minted as forward credits
+/// and are counted in `minted` of the batch. Projected batches cannot be retired.
+///
 ///  Example : For a project that has a supply of 100 tokens, minted and retired 100 tokens, the
 /// struct will look as follows   Batch {
 ///         ...,
@@ -117,6 +143,8 @@ pub struct Batch<StringType, Balance> {
 	pub minted: Balance,
 	/// The amount of tokens minted for this VCU
 	pub retired: Balance,
+	/// The verification status of the batch
+	pub status: BatchStatus,
 }
 
 /// The details of a retired batch of VCU
@@ -134,6 +162,10 @@ pub struct BatchRetireData<StringType, Balance> {
 }
 
 /// Representation of a group of credits. Groups are collections of batches of credits
+///
+/// Conditions :
+///    - `total_supply`, `minted` and `retired` of the group are always the sum of the respective
+///      values of its batches
 #[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, Default, Debug, MaxEncodedLen)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BatchGroup<StringType, AssetId, Balance, Batch, MaxBatches: Get<u32>> {
@@ -176,3 +208,169 @@ pub trait CarbonCreditsValidator {
 	/// Returns ProjectId and GroupId if the given AssetId represents a CarbonCredit Project
 	fn get_project_details(asset_id: &Self::AssetId) -> Option<(Self::ProjectId, Self::GroupId)>;
 }
+
+/// A latitude/longitude pair in fixed point representation, the values are stored as micro degrees
+/// (degrees * 10^6), for example a latitude of 45.123456 is stored as 45_123_456
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, TypeInfo, Default, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Coordinate {
+	/// Latitude in micro degrees, in the range -90_000_000..=90_000_000
+	pub latitude: i32,
+	/// Longitude in micro degrees, in the range -180_000_000..=180_000_000
+	pub longitude: i32,
+}
+
+impl Coordinate {
+	/// Maximum absolute value of latitude
+	pub const MAX_LATITUDE: i32 = 90_000_000;
+	/// Maximum absolute value of longitude
+	pub const MAX_LONGITUDE: i32 = 180_000_000;
+
+	/// Returns true if the coordinate is within the valid latitude/longitude range
+	pub fn is_valid(&self) -> bool {
+		(-Self::MAX_LATITUDE..=Self::MAX_LATITUDE).contains(&self.latitude) &&
+			(-Self::MAX_LONGITUDE..=Self::MAX_LONGITUDE).contains(&self.longitude)
+	}
+}
+
+/// The geographical shape of a project, either a single point or the boundary of the project area
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Geometry<CoordinateList> {
+	/// A single location
+	Point(Coordinate),
+	/// A polygon represented by the list of its vertices, the polygon is implicitly closed, the
+	/// last vertex connects to the first
+	Polygon(CoordinateList),
+}
+
+/// The location of a project
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Location<CoordinateList, StringType> {
+	/// The point or boundary of the project
+	pub geometry: Geometry<CoordinateList>,
+	/// ISO 3166-1 alpha-2 code of the country of the project, for example `*b"BR"`
+	pub country: Option<[u8; 2]>,
+	/// Name of the administrative region of the project
+	pub region: Option<StringType>,
+}
+
+/// The officially assigned ISO 3166-1 alpha-2 country codes, sorted in ascending order
+pub const ISO_COUNTRY_CODES: [[u8; 2]; 249] = [
+	*b"AD", *b"AE", *b"AF", *b"AG", *b"AI", *b"AL", *b"AM", *b"AO", *b"AQ", *b"AR", *b"AS", *b"AT",
+	*b"AU", *b"AW", *b"AX", *b"AZ", *b"BA", *b"BB", *b"BD", *b"BE", *b"BF", *b"BG", *b"BH", *b"BI",
+	*b"BJ", *b"BL", *b"BM", *b"BN", *b"BO", *b"BQ", *b"BR", *b"BS", *b"BT", *b"BV", *b"BW", *b"BY",
+	*b"BZ", *b"CA", *b"CC", *b"CD", *b"CF", *b"CG", *b"CH", *b"CI", *b"CK", *b"CL", *b"CM", *b"CN",
+	*b"CO", *b"CR", *b"CU", *b"CV", *b"CW", *b"CX", *b"CY", *b"CZ", *b"DE", *b"DJ", *b"DK", *b"DM",
+	*b"DO", *b"DZ", *b"EC", *b"EE", *b"EG", *b"EH", *b"ER", *b"ES", *b"ET", *b"FI", *b"FJ", *b"FK",
+	*b"FM", *b"FO", *b"FR", *b"GA", *b"GB", *b"GD", *b"GE", *b"GF", *b"GG", *b"GH", *b"GI", *b"GL",
+	*b"GM", *b"GN", *b"GP", *b"GQ", *b"GR", *b"GS", *b"GT", *b"GU", *b"GW", *b"GY", *b"HK", *b"HM",
+	*b"HN", *b"HR", *b"HT", *b"HU", *b"ID", *b"IE", *b"IL", *b"IM", *b"IN", *b"IO", *b"IQ", *b"IR",
+	*b"IS", *b"IT", *b"JE", *b"JM", *b"JO", *b"JP", *b"KE", *b"KG", *b"KH", *b"KI", *b"KM", *b"KN",
+	*b"KP", *b"KR", *b"KW", *b"KY", *b"KZ", *b"LA", *b"LB", *b"LC", *b"LI", *b"LK", *b"LR", *b"LS",
+	*b"LT", *b"LU", *b"LV", *b"LY", *b"MA", *b"MC", *b"MD", *b"ME", *b"MF", *b"MG", *b"MH", *b"MK",
+	*b"ML", *b"MM", *b"MN", *b"MO", *b"MP", *b"MQ", *b"MR", *b"MS", *b"MT", *b"MU", *b"MV", *b"MW",
+	*b"MX", *b"MY", *b"MZ", *b"NA", *b"NC", *b"NE", *b"NF", *b"NG", *b"NI", *b"NL", *b"NO", *b"NP",
+	*b"NR", *b"NU", *b"NZ", *b"OM", *b"PA", *b"PE", *b"PF", *b"PG", *b"PH", *b"PK", *b"PL", *b"PM",
+	*b"PN", *b"PR", *b"PS", *b"PT", *b"PW", *b"PY", *b"QA", *b"RE", *b"RO", *b"RS", *b"RU", *b"RW",
+	*b"SA", *b"SB", *b"SC", *b"SD", *b"SE", *b"SG", *b"SH", *b"SI", *b"SJ", *b"SK", *b"SL", *b"SM",
+	*b"SN", *b"SO", *b"SR", *b"SS", *b"ST", *b"SV", *b"SX", *b"SY", *b"SZ", *b"TC", *b"TD", *b"TF",
+	*b"TG", *b"TH", *b"TJ", *b"TK", *b"TL", *b"TM", *b"TN", *b"TO", *b"TR", *b"TT", *b"TV", *b"TW",
+	*b"TZ", *b"UA", *b"UG", *b"UM", *b"US", *b"UY", *b"UZ", *b"VA", *b"VC", *b"VE", *b"VG", *b"VI",
+	*b"VN", *b"VU", *b"WF", *b"WS", *b"YE", *b"YT", *b"ZA", *b"ZM", *b"ZW",
+];
+
+/// Returns true if the given country code is an assigned ISO 3166-1 alpha-2 code
+pub fn is_valid_country_code(code: &[u8; 2]) -> bool {
+	ISO_COUNTRY_CODES.binary_search(code).is_ok()
+}
+
+/// Returns true if the polygon is a valid boundary, it needs at least three valid vertices
+pub fn is_valid_polygon(polygon: &[Coordinate]) -> bool {
+	polygon.len() >= 3 && polygon.iter().all(|x| x.is_valid())
+}
+
+/// Returns the sign of the cross product of (b - a) x (c - a)
+fn orientation(a: &Coordinate, b: &Coordinate, c: &Coordinate) -> i64 {
+	let value = (b.longitude as i64 - a.longitude as i64) * (c.latitude as i64 - a.latitude as i64) -
+		(b.latitude as i64 - a.latitude as i64) * (c.longitude as i64 - a.longitude as i64);
+	value.signum()
+}
+
+/// Returns true if `c` is within the bounding box of the segment a-b
+fn within_segment_box(a: &Coordinate, b: &Coordinate, c: &Coordinate) -> bool {
+	c.longitude >= a.longitude.min(b.longitude) &&
+		c.longitude <= a.longitude.max(b.longitude) &&
+		c.latitude >= a.latitude.min(b.latitude) &&
+		c.latitude <= a.latitude.max(b.latitude)
+}
+
+/// Returns true if the segments p1-p2 and q1-q2 intersect, touching segments are intersecting
+fn segments_intersect(p1: &Coordinate, p2: &Coordinate, q1: &Coordinate, q2: &Coordinate) -> bool {
+	let o1 = orientation(p1, p2, q1);
+	let o2 = orientation(p1, p2, q2);
+	let o3 = orientation(q1, q2, p1);
+	let o4 = orientation(q1, q2, p2);
+
+	if o1 != o2 && o3 != o4 {
+		return true
+	}
+
+	// collinear cases
+	(o1 == 0 && within_segment_box(p1, p2, q1)) ||
+		(o2 == 0 && within_segment_box(p1, p2, q2)) ||
+		(o3 == 0 && within_segment_box(q1, q2, p1)) ||
+		(o4 == 0 && within_segment_box(q1, q2, p2))
+}
+
+/// Iterate over the edges of a polygon, including the closing edge
+fn edges(polygon: &[Coordinate]) -> impl Iterator<Item = (&Coordinate, &Coordinate)> {
+	polygon.iter().zip(polygon.iter().cycle().skip(1)).take(polygon.len())
+}
+
+/// Returns true if the point lies inside the polygon or on its boundary
+pub fn polygon_contains(polygon: &[Coordinate], point: &Coordinate) -> bool {
+	let mut inside = false;
+
+	for (a, b) in edges(polygon) {
+		// points on the boundary are treated as inside
+		if orientation(a, b, point) == 0 && within_segment_box(a, b, point) {
+			return true
+		}
+
+		// ray casting towards increasing longitude
+		if (a.latitude > point.latitude) != (b.latitude > point.latitude) {
+			let cross = (b.longitude as i64 - a.longitude as i64) *
+				(point.latitude as i64 - a.latitude as i64) -
+				(point.longitude as i64 - a.longitude as i64) *
+					(b.latitude as i64 - a.latitude as i64);
+			// the crossing is to the right of the point if the cross product has the same sign as
+			// the direction of the edge
+			if (cross > 0) == (b.latitude > a.latitude) {
+				inside = !inside;
+			}
+		}
+	}
+
+	inside
+}
+
+/// Returns true if the two polygons share any area or boundary
+pub fn polygons_overlap(a: &[Coordinate], b: &[Coordinate]) -> bool {
+	if a.is_empty() || b.is_empty() {
+		return false
+	}
+
+	// any intersecting edges
+	for (a1, a2) in edges(a) {
+		for (b1, b2) in edges(b) {
+			if segments_intersect(a1, a2, b1, b2) {
+				return true
+			}
+		}
+	}
+
+	// one polygon is fully contained in the other
+	polygon_contains(a, &b[0]) || polygon_contains(b, &a[0])
+}
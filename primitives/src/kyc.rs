@@ -0,0 +1,76 @@
+use super::*;
+
+/// The KYC levels an account can be verified at, a higher level includes the checks of the
+/// lower levels
+#[derive(
+	Clone, Copy, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, TypeInfo, Debug, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KycLevel {
+	/// Identity verification of an individual
+	Basic,
+	/// Identity verification with proof of address and source of funds
+	Enhanced,
+	/// Verification of a company and its beneficial owners
+	Institutional,
+}
+
+/// Trait to query the KYC level of an account
+pub trait KycLevelProvider<AccountId> {
+	/// Returns the KYC level of the account, None if the account is not verified
+	fn kyc_level(who: &AccountId) -> Option<KycLevel>;
+
+	/// Returns true if the account is verified at the given level or higher
+	fn has_kyc_level(who: &AccountId, level: KycLevel) -> bool {
+		Self::kyc_level(who).map_or(false, |account_level| account_level >= level)
+	}
+}
+
+/// ISO 3166-1 alpha-2 country code of a KYC verified account, for example `*b"DE"`
+pub type CountryCode = [u8; 2];
+
+/// The type of entity verified by a KYC provider
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, TypeInfo, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EntityType {
+	Individual,
+	Company,
+}
+
+/// Trait to query the jurisdiction of an account and whether trading is blocked for it
+pub trait JurisdictionProvider<AccountId> {
+	/// Returns the country code of the account, None if the account has no jurisdiction recorded
+	fn country_code(who: &AccountId) -> Option<CountryCode>;
+
+	/// Returns true if the given jurisdiction is blocked
+	fn is_jurisdiction_blocked(country_code: &CountryCode) -> bool;
+
+	/// Returns true if the account belongs to a blocked jurisdiction
+	fn is_account_blocked(who: &AccountId) -> bool {
+		Self::country_code(who)
+			.map_or(false, |country_code| Self::is_jurisdiction_blocked(&country_code))
+	}
+}
+
+/// The KYC status of an account derived from the judgements of its on-chain identity
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, TypeInfo, Debug, MaxEncodedLen)]
+pub enum IdentityStatus {
+	/// A registrar judged the identity `KnownGood` or `Reasonable`
+	Verified,
+	/// The identity was cleared or judged `Erroneous`
+	Revoked,
+	/// The identity has no conclusive judgement
+	Unverified,
+}
+
+/// Trait to query the KYC status of an account from its on-chain identity
+pub trait IdentityStatusProvider<AccountId> {
+	/// Returns the status of the identity of the account
+	fn identity_status(who: &AccountId) -> IdentityStatus;
+}
+
+impl<AccountId> IdentityStatusProvider<AccountId> for () {
+	fn identity_status(_who: &AccountId) -> IdentityStatus {
+		IdentityStatus::Unverified
+	}
+}
@@ -14,6 +14,9 @@ use sp_std::convert::TryFrom;
 mod carbon_credits;
 pub use carbon_credits::*;
 
+mod kyc;
+pub use kyc::*;
+
 pub const BBB_TOKEN: u32 = 1;
 
 /// Amounts
@@ -21,7 +21,9 @@ use frame_support::{
 	dispatch::DispatchClass,
 	pallet_prelude::ConstU32,
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU128, Contains, Currency, Nothing, PrivilegeCmp},
+	traits::{
+		AsEnsureOriginWithArg, ConstBool, ConstU128, Contains, Currency, Nothing, PrivilegeCmp,
+	},
 	weights::{
 		constants::WEIGHT_PER_SECOND, ConstantMultiplier, Weight, WeightToFeeCoefficient,
 		WeightToFeeCoefficients, WeightToFeePolynomial,
@@ -44,7 +46,8 @@ pub use primitives::{
 		AVERAGE_ON_INITIALIZE_RATIO, DAYS, HOURS, MAXIMUM_BLOCK_WEIGHT, NORMAL_DISPATCH_RATIO,
 		SLOT_DURATION,
 	},
-	AccountId, Address, Amount, Balance, BlockNumber, Hash, Header, Index, Signature,
+	AccountId, Address, Amount, Balance, BlockNumber, Hash, Header, IdentityStatus,
+	IdentityStatusProvider, Index, KycLevel, Signature,
 };
 use scale_info::TypeInfo;
 use smallvec::smallvec;
@@ -111,7 +114,16 @@ pub type Executive = frame_executive::Executive<
 	Runtime,
 	AllPalletsWithSystem,
 	// Migrations
-	(pallet_parachain_staking::migration::v3::MigrateToV3<Runtime>,),
+	(
+		pallet_parachain_staking::migration::v3::MigrateToV3<Runtime>,
+		pallet_carbon_credits::migration::v2::MigrateToV2<Runtime>,
+		pallet_carbon_credits_pool::migration::v1::MigrateToV1<Runtime>,
+		pallet_carbon_credits_pool::migration::v2::MigrateToV2<Runtime>,
+		pallet_kyc::migration::v5::MigrateToV5<Runtime, ProjectKycLevel>,
+		pallet_kyc::migration::v6::MigrateToV6<Runtime>,
+		pallet_kyc::migration::v7::MigrateToV7<Runtime>,
+		pallet_kyc::migration::v8::MigrateToV8<Runtime>,
+	),
 >;
 
 pub type NegativeImbalance<T> = <pallet_balances::Pallet<T> as Currency<
@@ -474,6 +486,16 @@ impl pallet_assets::FrozenBalance<u32, AccountId, u128> for TestFreezer {
 	fn died(_asset: u32, _who: &AccountId) {}
 }
 
+/// The pallet accounts allowed to receive carbon credits without KYC
+pub struct CarbonCreditsTransferWhitelist;
+impl Contains<AccountId> for CarbonCreditsTransferWhitelist {
+	fn contains(who: &AccountId) -> bool {
+		let dex_account: AccountId = DexPalletId::get().into_account_truncating();
+		let pool_account: AccountId = CarbonCreditsPoolsPalletId::get().into_account_truncating();
+		*who == dex_account || *who == pool_account
+	}
+}
+
 impl pallet_assets::Config for Runtime {
 	type ApprovalDeposit = APPROVALDEPOSIT;
 	type AssetAccountDeposit = ASSETACCOUNTDEPOSIT;
@@ -486,6 +508,8 @@ impl pallet_assets::Config for Runtime {
 	type Extra = ();
 	type ForceOrigin = EnsureRoot<AccountId>;
 	type Freezer = TestFreezer;
+	type Compliance =
+		pallet_carbon_credits::KycTransferCompliance<Runtime, CarbonCreditsTransferWhitelist>;
 	type MetadataDepositBase = METADATADEPOSITBASE;
 	type MetadataDepositPerByte = METADATADEPOSITPERBYTE;
 	type StringLimit = STRINGLIMIT;
@@ -494,19 +518,83 @@ impl pallet_assets::Config for Runtime {
 
 parameter_types! {
 	pub const KYCPalletId: PalletId = PalletId(*b"bitg/kyc");
+	pub const KycValidityPeriod: BlockNumber = 365 * DAYS;
+	pub const KycExpiryNoticePeriod: BlockNumber = 30 * DAYS;
+	pub const KycApplicationDeposit: Balance = UNIT;
+	pub const KycAirdropLowBalanceThreshold: Balance = 100 * UNIT;
+	pub const IdentityKycLevel: KycLevel = KycLevel::Basic;
+	/// The registrars of `pallet_identity` whose judgements grant the KYC
+	pub const KycTrustedRegistrars: &'static [pallet_identity::RegistrarIndex] = &[0];
+}
+
+/// Grants the KYC from the judgements of the trusted registrars of `pallet_identity`, the
+/// judgements of other registrars are ignored
+pub struct IdentityJudgements;
+impl IdentityStatusProvider<AccountId> for IdentityJudgements {
+	fn identity_status(who: &AccountId) -> IdentityStatus {
+		use pallet_identity::Judgement;
+
+		let registration = match Identity::identity(who) {
+			Some(registration) => registration,
+			// the identity was cleared or never set
+			None => return IdentityStatus::Revoked,
+		};
+		let judgements = registration
+			.judgements
+			.iter()
+			.filter(|(index, _)| KycTrustedRegistrars::get().contains(index))
+			.map(|(_, judgement)| judgement);
+		if judgements.clone().any(|judgement| matches!(judgement, Judgement::Erroneous)) {
+			IdentityStatus::Revoked
+		} else if judgements
+			.clone()
+			.any(|judgement| matches!(judgement, Judgement::KnownGood | Judgement::Reasonable))
+		{
+			IdentityStatus::Verified
+		} else {
+			IdentityStatus::Unverified
+		}
+	}
 }
 
 impl pallet_kyc::Config for Runtime {
 	type AddOrigin = EnsureRoot<AccountId>;
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type KycApplicationDeposit = KycApplicationDeposit;
+	type MaxRejectionReasonLength = ConstU32<256>;
+	type AirdropLowBalanceThreshold = KycAirdropLowBalanceThreshold;
 	type PalletId = KYCPalletId;
 	type MaxMembers = ConstU32<100_000>;
 	type MembershipChanged = ();
 	type MembershipInitialized = ();
+	type NotifyMembershipChanged = ConstBool<false>;
 	type MaxAuthorizedAccountCount = ConstU32<100>;
+	type KycValidityPeriod = KycValidityPeriod;
+	type KycExpiryNoticePeriod = KycExpiryNoticePeriod;
+	type MaxExpiriesPerBlock = ConstU32<100>;
+	type MaxDerivedAccounts = ConstU32<10>;
+	type IdentityProvider = IdentityJudgements;
+	type IdentityKycLevel = IdentityKycLevel;
 	type WeightInfo = ();
 }
+/// Verifies the accounts used in the carbon credits benchmarks through `pallet_kyc`
+#[cfg(feature = "runtime-benchmarks")]
+pub struct CarbonCreditsBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_carbon_credits::BenchmarkHelper<AccountId> for CarbonCreditsBenchmarkHelper {
+	fn set_kyc_level(who: &AccountId, level: KycLevel) {
+		let provider: AccountId = frame_benchmarking::account("kyc_provider", 0, 0);
+		if KYC::provider_id(&provider).is_none() {
+			KYC::force_add_authorized_account(RuntimeOrigin::root(), provider.clone())
+				.expect("root can register a KYC provider");
+		}
+		let provider_id = KYC::provider_id(&provider).expect("the provider is registered");
+		KYC::do_add_member(who.clone(), level, provider_id, None)
+			.expect("the account can be verified");
+	}
+}
+
 parameter_types! {
   pub MarketplaceEscrowAccount : AccountId =  PalletId(*b"bitg/mkp").into_account_truncating();
   pub const CarbonCreditsPalletId: PalletId = PalletId(*b"bitg/vcu");
@@ -520,6 +608,12 @@ parameter_types! {
   #[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
   pub const MaxGroupSize : u32 = 10;
   pub const MaxCoordinatesLength : u32 = 10;
+  pub const MaxProjectRevisions : u32 = 50;
+  pub const MaxMonitoringReports : u32 = 100;
+  pub const ProjectDepositBase : Balance = deposit(1, 0);
+  pub const ProjectDepositPerByte : Balance = deposit(0, 1);
+  pub const ProjectKycLevel : KycLevel = KycLevel::Institutional;
+  pub const RetireKycLevel : KycLevel = KycLevel::Basic;
 }
 
 impl pallet_carbon_credits::Config for Runtime {
@@ -528,7 +622,11 @@ impl pallet_carbon_credits::Config for Runtime {
 	type ProjectId = u32;
 	type GroupId = u32;
 	type Balance = u128;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = CarbonCreditsBenchmarkHelper;
 	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type DepositSlashed = Treasury;
 	type ForceOrigin = EnsureRoot<AccountId>;
 	type ItemId = u32;
 	type KYCProvider = KYC;
@@ -536,6 +634,8 @@ impl pallet_carbon_credits::Config for Runtime {
 	type MaxAuthorizedAccountCount = MaxAuthorizedAccountCount;
 	type MaxDocumentCount = MaxDocumentCount;
 	type MaxGroupSize = MaxGroupSize;
+	type MaxMonitoringReports = MaxMonitoringReports;
+	type MaxProjectRevisions = MaxProjectRevisions;
 	type MaxIpfsReferenceLength = MaxIpfsReferenceLength;
 	type MaxLongStringLength = MaxLongStringLength;
 	type MaxRoyaltyRecipients = MaxRoyaltyRecipients;
@@ -544,6 +644,10 @@ impl pallet_carbon_credits::Config for Runtime {
 	type NFTHandler = Uniques;
 	type PalletId = CarbonCreditsPalletId;
 	type MaxCoordinatesLength = MaxCoordinatesLength;
+	type ProjectDepositBase = ProjectDepositBase;
+	type ProjectDepositPerByte = ProjectDepositPerByte;
+	type ProjectKycLevel = ProjectKycLevel;
+	type RetireKycLevel = RetireKycLevel;
 	type WeightInfo = ();
 }
 
@@ -552,8 +656,13 @@ parameter_types! {
 	pub const MaxAssetSymbolLength : u32 = 10;
 	pub const MaxIssuanceYearCount : u32 = 20;
 	pub const MaxProjectIdList : u32 = 100;
+	pub const MaxPoolProjects : u32 = 1000;
+	pub const MaxRetireProjects : u32 = 100;
 	pub const MaxRegistryListCount : u32 = 10;
+	pub const MaxRedemptionFee : Percent = Percent::from_percent(10);
+	pub const MaxPoolFee : Percent = Percent::from_percent(10);
 	pub const MinPoolId : u32 = 10000;
+	pub PoolTreasuryAccount : AccountId = TreasuryPalletId::get().into_account_truncating();
 }
 
 impl pallet_carbon_credits_pool::Config for Runtime {
@@ -563,11 +672,16 @@ impl pallet_carbon_credits_pool::Config for Runtime {
 	type MaxAssetSymbolLength = MaxAssetSymbolLength;
 	type MaxIssuanceYearCount = MaxIssuanceYearCount;
 	type MaxProjectIdList = MaxProjectIdList;
+	type MaxPoolProjects = MaxPoolProjects;
+	type MaxRetireProjects = MaxRetireProjects;
+	type MaxPoolFee = MaxPoolFee;
+	type MaxRedemptionFee = MaxRedemptionFee;
 	type MaxRegistryListCount = MaxRegistryListCount;
 	type MinPoolId = MinPoolId;
 	type PalletId = CarbonCreditsPoolsPalletId;
 	type PoolId = u32;
-	type WeightInfo = ();
+	type TreasuryAccount = PoolTreasuryAccount;
+	type WeightInfo = pallet_carbon_credits_pool::weights::SubstrateWeight<Runtime>;
 }
 
 // TODO : Ensure sensible values
@@ -781,6 +895,10 @@ parameter_types! {
 	pub const MinPricePerUnit : u32 = 1;
 	pub const MaxPaymentFee : Percent = Percent::from_percent(10);
 	pub const MaxPurchaseFee : Balance = 10 * UNIT;
+	pub const SellOrderKycLevel : KycLevel = KycLevel::Basic;
+	pub const BuyOrderKycLevel : KycLevel = KycLevel::Basic;
+	pub const LargeBuyOrderKycLevel : KycLevel = KycLevel::Enhanced;
+	pub const LargeBuyOrderThreshold : Balance = 10_000 * UNIT;
 	#[derive(Clone, scale_info::TypeInfo)]
 	pub const MaxValidators : u32 = 10;
 	#[derive(Clone, scale_info::TypeInfo)]
@@ -799,6 +917,10 @@ impl pallet_dex::Config for Runtime {
 	type AssetValidator = CarbonCredits;
 	type MinPricePerUnit = MinPricePerUnit;
 	type KYCProvider = KYC;
+	type SellOrderKycLevel = SellOrderKycLevel;
+	type BuyOrderKycLevel = BuyOrderKycLevel;
+	type LargeBuyOrderKycLevel = LargeBuyOrderKycLevel;
+	type LargeBuyOrderThreshold = LargeBuyOrderThreshold;
 	type MinUnitsToCreateSellOrder = MinUnitsToCreateSellOrder;
 	type ForceOrigin = EnsureRoot<AccountId>;
 	type MaxValidators = MaxValidators;
@@ -1015,6 +1137,7 @@ mod benches {
 		[pallet_parachain_staking, ParachainStaking]
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
 		[pallet_carbon_credits, CarbonCredits]
+		[pallet_carbon_credits_pool, CarbonCreditsPools]
 		[pallet_vesting_contract, VestingContract]
 		[pallet_dex, Dex]
 	);
@@ -1121,6 +1244,43 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_carbon_credits_rpc_runtime_api::CarbonCreditsApi<
+		Block,
+		u32,
+		pallet_carbon_credits::ProjectRevision<Runtime>,
+	> for Runtime {
+		fn project_revision(
+			project_id: u32,
+			version: u32,
+		) -> Option<pallet_carbon_credits::ProjectRevision<Runtime>> {
+			CarbonCredits::get_project_revision(project_id, version)
+		}
+		fn overlapping_projects(project_id: u32) -> Vec<u32> {
+			CarbonCredits::get_overlapping_projects(project_id)
+		}
+	}
+
+	impl pallet_carbon_credits_pool_rpc_runtime_api::CarbonCreditsPoolApi<
+		Block,
+		AccountId,
+		u32,
+		u32,
+		Balance,
+		pallet_carbon_credits_pool::PoolConfigOf<Runtime>,
+	> for Runtime {
+		fn pool_info(
+			pool_id: u32,
+		) -> Option<pallet_carbon_credits_pool::PoolInfoOf<Runtime>> {
+			CarbonCreditsPools::pool_info(pool_id)
+		}
+		fn retire_preview(
+			pool_id: u32,
+			amount: Balance,
+		) -> Option<pallet_carbon_credits_pool::RetirePreviewOf<Runtime>> {
+			CarbonCreditsPools::retire_preview(pool_id, amount)
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)